@@ -1,21 +1,62 @@
-use iced::{Element, Length, Theme};
-use iced::widget::{container, scrollable, Column, Row};
+use iced::{keyboard, window, Element, Length, Subscription, Task, Theme};
+use iced::widget::{container, markdown, operation, scrollable, text_editor, Column, Row};
 
-use std::time::{Duration, Instant};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
 
-use crate::model::{Creature, Universe};
+use crate::model::pm::is_valid_due_date;
+use crate::model::search::search;
+use crate::model::undo::{Command, Snapshot, UndoStack};
+use crate::model::validation::{self, Errors, Field};
+use crate::model::{
+    parse_tags, ActivityKind, ActivityLog, ActivityTarget, Allegiance, Artifact, ArtifactProperty, Asset,
+    AssetKind, Board, BoardColumn, Calendar, Card, CardMove, Chapter, Character, Codex, Creature,
+    CreatureTemplate, DangerLevel, Era, Faction, FantasyDate, FieldDef, FieldKind, FieldValue, History,
+    Holder, Location, LocationKind, Manuscript, ManuscriptKind, Membership, Project, Rarity, Relationship,
+    Scene, Stance, Term, TimelineEvent, Universe, Workspace,
+};
+use crate::forms::{self, Form, FormField};
+use crate::storage::Store;
 use crate::{pages, ui};
 
 pub const APP_NAME: &str = "Titan Architect Studio";
 pub const APP_ACRONYM: &str = "TAS";
 
 pub fn run() -> iced::Result {
-    iced::application(AppState::default, update, view)
+    iced::application(AppState::load, update, view)
         .title(title)
         .theme(app_theme)
+        .subscription(subscription)
+        // The window closes from `update`, once the open scene is written back.
+        .exit_on_close_request(false)
         .run()
 }
 
+pub fn subscription(_state: &AppState) -> Subscription<Message> {
+    Subscription::batch([
+        keyboard::listen().filter_map(shortcut),
+        window::close_requests().map(Message::CloseWindow),
+    ])
+}
+
+/// Global shortcuts: Ctrl/Cmd+K focuses search, Escape closes it,
+/// Ctrl/Cmd+Z undoes and Ctrl/Cmd+Shift+Z redoes.
+fn shortcut(event: keyboard::Event) -> Option<Message> {
+    let keyboard::Event::KeyPressed { key, modifiers, .. } = event else {
+        return None;
+    };
+
+    match key.as_ref() {
+        keyboard::Key::Character("k") if modifiers.command() => Some(Message::SearchFocus),
+        keyboard::Key::Character(c) if modifiers.command() && c.eq_ignore_ascii_case("z") => {
+            Some(if modifiers.shift() { Message::Redo } else { Message::Undo })
+        }
+        keyboard::Key::Named(keyboard::key::Named::Escape) => Some(Message::SearchClose),
+        _ => None,
+    }
+}
+
 pub fn title(_state: &AppState) -> String {
     format!("{APP_NAME} ({APP_ACRONYM})")
 }
@@ -30,10 +71,14 @@ pub enum Route {
     Workspaces,
     UniverseList,
     UniverseDetail { universe_id: String },
-    Bestiary { universe_id: String },
+    /// A universe's bestiary, locations, characters, factions, Runomicon or artifacts.
+    Codex { codex: Codex, universe_id: String },
+    CreatureDetail { creature_id: String },
     Timeline { universe_id: String },
     Forge,
+    Manuscript { manuscript_id: String },
     PmTools,
+    Board { board_id: String },
     Assets,
     Account,
 }
@@ -45,10 +90,11 @@ impl Route {
             Route::Workspaces => "Workspaces",
             Route::UniverseList
             | Route::UniverseDetail { .. }
-            | Route::Bestiary { .. }
+            | Route::Codex { .. }
+            | Route::CreatureDetail { .. }
             | Route::Timeline { .. } => "Universe",
-            Route::Forge => "The Forge",
-            Route::PmTools => "PM Tools",
+            Route::Forge | Route::Manuscript { .. } => "The Forge",
+            Route::PmTools | Route::Board { .. } => "PM Tools",
             Route::Assets => "Assets",
             Route::Account => "Account",
         }
@@ -57,318 +103,3706 @@ impl Route {
 
 /// Temporary in-memory editor state for a creature.
 ///
-/// NOTE: This is intentionally UI-only state. Persistence will come later.
+/// NOTE: This is intentionally UI-only state. It only reaches the project (and the store) on save.
 #[derive(Debug, Clone)]
 pub struct CreatureEditor {
-    pub index: Option<usize>,
+    /// `None` while creating; the id is assigned on save.
+    pub id: Option<String>,
+    pub universe_id: String,
 
     pub name: String,
-    pub kind: String,
-    pub habitat: String,
-    pub description: String,
-    pub danger: String,
+    pub tags: Vec<String>,
+    /// Tag being typed; added on Enter or by picking a suggestion.
+    pub tag_input: String,
+    pub habitat: text_editor::Content,
+    /// [`Location::id`]s picked as places the creature lives in.
+    pub habitat_locations: Vec<String>,
+    /// Markdown.
+    pub description: text_editor::Content,
+    pub danger: DangerLevel,
+    /// Name typed for a new custom danger level of the universe.
+    pub new_danger_level: String,
+
+    /// The universe's template fields when the editor opened.
+    pub field_defs: Vec<FieldDef>,
+    /// Raw input of every template field except long text ones, keyed by field id.
+    pub field_inputs: BTreeMap<String, String>,
+    pub long_fields: BTreeMap<String, text_editor::Content>,
+    /// Values of fields no longer in the template, carried through unchanged.
+    pub other_fields: BTreeMap<String, FieldValue>,
+
+    /// Why the last move/copy was refused.
+    pub transfer_error: Option<String>,
+
+    /// Destination picked in the "Move / copy to" row.
+    pub transfer_target: Option<String>,
 }
 
 impl CreatureEditor {
-    pub fn create_new() -> Self {
-        Self {
-            index: None,
+    pub fn create_new(universe_id: String, field_defs: &[FieldDef]) -> Self {
+        let mut editor = Self {
+            id: None,
+            universe_id,
             name: String::new(),
-            kind: String::new(),
-            habitat: String::new(),
-            description: String::new(),
-            danger: "Medium".to_string(),
-        }
+            tags: Vec::new(),
+            tag_input: String::new(),
+            habitat: text_editor::Content::new(),
+            habitat_locations: Vec::new(),
+            description: text_editor::Content::new(),
+            danger: DangerLevel::Medium,
+            new_danger_level: String::new(),
+            field_defs: Vec::new(),
+            field_inputs: BTreeMap::new(),
+            long_fields: BTreeMap::new(),
+            other_fields: BTreeMap::new(),
+            transfer_target: None,
+            transfer_error: None,
+        };
+        editor.load_fields(field_defs, &BTreeMap::new());
+        editor
     }
 
-    pub fn from_creature(index: usize, c: &Creature) -> Self {
-        Self {
-            index: Some(index),
+    pub fn from_creature(c: &Creature, field_defs: &[FieldDef]) -> Self {
+        let mut editor = Self {
+            id: Some(c.id.clone()),
+            universe_id: c.universe_id.clone(),
             name: c.name.clone(),
-            kind: c.kind.clone(),
-            habitat: c.habitat.clone(),
-            description: c.description.clone(),
+            tags: c.tags.clone(),
+            tag_input: String::new(),
+            habitat: text_editor::Content::with_text(&c.habitat),
+            habitat_locations: c.habitat_locations.clone(),
+            description: text_editor::Content::with_text(&c.description),
             danger: c.danger.clone(),
+            new_danger_level: String::new(),
+            field_defs: Vec::new(),
+            field_inputs: BTreeMap::new(),
+            long_fields: BTreeMap::new(),
+            other_fields: BTreeMap::new(),
+            transfer_target: None,
+            transfer_error: None,
+        };
+        editor.load_fields(field_defs, &c.fields);
+        editor
+    }
+
+    fn load_fields(&mut self, field_defs: &[FieldDef], values: &BTreeMap<String, FieldValue>) {
+        self.field_defs = field_defs.to_vec();
+        self.other_fields = values.clone();
+
+        for def in field_defs {
+            let input = self.other_fields.remove(&def.id).map(|v| v.to_input()).unwrap_or_default();
+
+            if def.kind == FieldKind::LongText {
+                self.long_fields.insert(def.id.clone(), text_editor::Content::with_text(&input));
+            } else {
+                self.field_inputs.insert(def.id.clone(), input);
+            }
+        }
+    }
+
+    fn field_input(&self, def: &FieldDef) -> String {
+        match def.kind {
+            FieldKind::LongText => self.long_fields.get(&def.id).map(|c| c.text()).unwrap_or_default(),
+            _ => self.field_inputs.get(&def.id).cloned().unwrap_or_default(),
+        }
+    }
+
+    /// Message shown under a template field whose input can't be saved.
+    pub fn field_error(&self, def: &FieldDef) -> Option<String> {
+        let input = self.field_input(def);
+
+        if def.kind == FieldKind::Enum && !input.is_empty() && !def.options.contains(&input) {
+            return Some("Pick one of the options".to_string());
+        }
+
+        FieldValue::parse(def.kind, &input).err()
+    }
+
+    pub fn has_field_errors(&self) -> bool {
+        self.field_defs.iter().any(|def| self.field_error(def).is_some())
+    }
+
+    /// Adds the typed tag(s) unless already present, ignoring case.
+    pub fn add_tags(&mut self, input: &str) {
+        for tag in parse_tags(input) {
+            if !self.tags.iter().any(|t| t.eq_ignore_ascii_case(&tag)) {
+                self.tags.push(tag);
+            }
         }
     }
+}
+
+impl Form for CreatureEditor {
+    type Entity = Creature;
+
+    const FIELDS: &'static [FormField<Self>] = &[
+        FormField::line(Field::Name, "Name", |ed| &ed.name, |ed| &mut ed.name),
+        FormField::text(Field::Habitat, "Habitat", 64.0, |ed| &ed.habitat, |ed| &mut ed.habitat),
+        FormField::markdown(Field::Description, "Description", 180.0, |ed| &ed.description, |ed| {
+            &mut ed.description
+        }),
+    ];
+
+    fn get(state: &AppState) -> Option<&Self> {
+        state.creature_editor.as_ref()
+    }
+
+    fn slot(state: &mut AppState) -> &mut Option<Self> {
+        &mut state.creature_editor
+    }
+
+    fn create(state: &AppState, universe_id: String) -> Self {
+        Self::create_new(universe_id.clone(), state.project.creature_fields(&universe_id))
+    }
+
+    fn edit(state: &AppState, c: &Creature) -> Self {
+        Self::from_creature(c, state.project.creature_fields(&c.universe_id))
+    }
+
+    fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    fn universe_id(&self) -> &str {
+        &self.universe_id
+    }
+
+    /// Problems with the built-in fields; template fields report through [`CreatureEditor::field_error`].
+    fn errors(&self, project: &Project) -> Errors {
+        validation::creature(
+            project,
+            self.id.as_deref(),
+            &self.universe_id,
+            &self.name,
+            &self.habitat.text(),
+            &self.description.text(),
+            &self.danger,
+        )
+    }
+
+    fn can_save(&self, project: &Project) -> bool {
+        self.errors(project).is_empty() && !self.has_field_errors()
+    }
+
+    /// A tag still sitting in the input is kept too.
+    fn build(mut self) -> Creature {
+        let pending = std::mem::take(&mut self.tag_input);
+        self.add_tags(&pending);
+
+        let mut fields = std::mem::take(&mut self.other_fields);
+        for def in &self.field_defs {
+            if let Ok(Some(value)) = FieldValue::parse(def.kind, &self.field_input(def)) {
+                fields.insert(def.id.clone(), value);
+            }
+        }
 
-    pub fn into_creature(self) -> Creature {
         Creature {
+            id: self.id.unwrap_or_default(),
+            universe_id: self.universe_id,
             name: self.name.trim().to_string(),
-            kind: self.kind.trim().to_string(),
-            habitat: self.habitat.trim().to_string(),
-            description: self.description.trim().to_string(),
-            danger: self.danger.trim().to_string(),
+            tags: self.tags,
+            habitat: self.habitat.text().trim().to_string(),
+            habitat_locations: self.habitat_locations,
+            description: self.description.text().trim().to_string(),
+            danger: self.danger,
+            fields,
+            archived: false,
+            edited_at: 0,
+            history: History::default(),
+        }
+    }
+
+    /// The sheet template is edited in its own panel; only one of the two is open.
+    fn opening(state: &mut AppState) {
+        state.template_editor = None;
+    }
+
+    /// A creature moved or copied elsewhere brings its custom level along.
+    fn saving(state: &mut AppState, c: &Creature) {
+        add_danger_level(state, &c.universe_id, &c.danger);
+    }
+
+    fn forget(&mut self, codex: Codex, id: &str) {
+        if codex == Codex::Location {
+            self.habitat_locations.retain(|l| l != id);
         }
     }
 }
 
+/// Undo notice; `action` is the button offered next to `text`.
 #[derive(Debug, Clone)]
-pub enum Message {
-    Navigate(Route),
+pub struct Toast {
+    pub text: String,
+    pub action: Message,
+}
 
-    Logout,
+/// Temporary in-memory editor state for a universe's description and lore.
+#[derive(Debug, Clone)]
+pub struct UniverseEditor {
+    pub id: String,
+    pub name: String,
+    /// Markdown.
+    pub description: text_editor::Content,
+    /// Markdown.
+    pub lore: text_editor::Content,
+}
 
-    UniverseNameChanged(String),
-    UniverseDescChanged(String),
-    CreateUniverse,
+impl UniverseEditor {
+    pub fn from_universe(u: &Universe) -> Self {
+        Self {
+            id: u.id.clone(),
+            name: u.name.clone(),
+            description: text_editor::Content::with_text(&u.description),
+            lore: text_editor::Content::with_text(&u.lore),
+        }
+    }
 
-    OpenUniverse(String),
-    BackToUniverses,
+    pub fn errors(&self, project: &Project) -> Errors {
+        validation::universe(
+            project,
+            Some(&self.id),
+            &self.name,
+            &self.description.text(),
+            &self.lore.text(),
+        )
+    }
+}
 
-    OpenBestiary(String),
-    OpenTimeline(String),
-    BackToUniverse(String),
+/// Temporary in-memory editor state for a location.
+#[derive(Debug, Clone)]
+pub struct LocationEditor {
+    /// `None` while creating; the id is assigned on save.
+    pub id: Option<String>,
+    pub universe_id: String,
 
-    // Bestiary interactions
-    BestiaryCardClicked(usize),
+    pub name: String,
+    pub kind: LocationKind,
+    pub parent_id: Option<String>,
+    pub climate: String,
+    /// Markdown.
+    pub description: text_editor::Content,
+}
+
+impl LocationEditor {
+    /// A place added inside `parent` defaults to the next smaller kind.
+    pub fn create_new(universe_id: String, parent: Option<&Location>) -> Self {
+        let kind = match parent.map(|p| p.kind) {
+            None => LocationKind::Region,
+            Some(LocationKind::Region) => LocationKind::City,
+            Some(LocationKind::City | LocationKind::Landmark) => LocationKind::Landmark,
+        };
 
-    CreatureEditorOpenCreate,
-    CreatureEditorCancel,
-    CreatureEditorSave,
+        Self {
+            id: None,
+            universe_id,
+            name: String::new(),
+            kind,
+            parent_id: parent.map(|p| p.id.clone()),
+            climate: String::new(),
+            description: text_editor::Content::new(),
+        }
+    }
 
-    CreatureEditorNameChanged(String),
-    CreatureEditorKindChanged(String),
-    CreatureEditorHabitatChanged(String),
-    CreatureEditorDescriptionChanged(String),
-    CreatureEditorDangerChanged(String),
+    pub fn from_location(l: &Location) -> Self {
+        Self {
+            id: Some(l.id.clone()),
+            universe_id: l.universe_id.clone(),
+            name: l.name.clone(),
+            kind: l.kind,
+            parent_id: l.parent_id.clone(),
+            climate: l.climate.clone(),
+            description: text_editor::Content::with_text(&l.description),
+        }
+    }
 }
 
-#[derive(Debug)]
-pub struct AppState {
-    pub route: Route,
+impl Form for LocationEditor {
+    type Entity = Location;
 
-    pub active_workspace: String,
+    const FIELDS: &'static [FormField<Self>] = &[
+        FormField::line(Field::Name, "Name", |ed| &ed.name, |ed| &mut ed.name),
+        FormField::line(
+            Field::Climate,
+            "Climate (e.g. cold and misty, arid highlands)",
+            |ed| &ed.climate,
+            |ed| &mut ed.climate,
+        ),
+        FormField::markdown(Field::Description, "Description", 160.0, |ed| &ed.description, |ed| {
+            &mut ed.description
+        }),
+    ];
 
-    pub new_universe_name: String,
-    pub new_universe_desc: String,
+    fn get(state: &AppState) -> Option<&Self> {
+        state.location_editor.as_ref()
+    }
 
-    pub universes: Vec<Universe>,
-    pub creatures: Vec<Creature>,
+    fn slot(state: &mut AppState) -> &mut Option<Self> {
+        &mut state.location_editor
+    }
 
-    // Bestiary editor
-    pub creature_editor: Option<CreatureEditor>,
-    pub last_bestiary_click: Option<(usize, Instant)>,
-}
+    fn create(_state: &AppState, universe_id: String) -> Self {
+        Self::create_new(universe_id, None)
+    }
 
-impl Default for AppState {
-    fn default() -> Self {
-        let arhelis_id = "arhelis".to_string();
+    fn edit(_state: &AppState, l: &Location) -> Self {
+        Self::from_location(l)
+    }
 
-        Self {
-            route: Route::Overview,
-            active_workspace: "Arhelis".to_string(),
+    fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
 
-            new_universe_name: "".to_string(),
-            new_universe_desc: "".to_string(),
+    fn universe_id(&self) -> &str {
+        &self.universe_id
+    }
 
-            universes: vec![Universe {
-                id: arhelis_id.clone(),
-                name: "Arhelis".to_string(),
-                description: "Fantasy Universe".to_string(),
-                archived: false,
-            }],
-
-            creatures: vec![
-                Creature {
-                    name: "Fog Engulfer".to_string(),
-                    kind: "Elemental · Relicto".to_string(),
-                    habitat: "Pasos de montaña brumosos, valles cerrados al amanecer.".to_string(),
-                    description: "Una criatura compuesta de vapor condensado y huesos de sus víctimas, solo sólida cuando ataca.".to_string(),
-                    danger: "High".to_string(),
-                },
-                Creature {
-                    name: "Oathbound Specter".to_string(),
-                    kind: "Espectro · Constructo · Maldito".to_string(),
-                    habitat: "Ruinas de castillos, antiguos campos de batalla, criptas de familias nobles.".to_string(),
-                    description: "Una armadura vacía animada por el espíritu de un guerrero que murió rompiendo una promesa sagrada.".to_string(),
-                    danger: "Medium".to_string(),
-                },
-                Creature {
-                    name: "Obsidian Widow".to_string(),
-                    kind: "Insectoide · Arácnido Acorazado".to_string(),
-                    habitat: "Cavernas volcánicas, minas profundas y grietas montañosas.".to_string(),
-                    description: "Una araña gigante con un caparazón cristalino que refleja la magia y garras afiladas como diamantes.".to_string(),
-                    danger: "Extreme".to_string(),
-                },
-                Creature {
-                    name: "Silt Siren".to_string(),
-                    kind: "Anfibio · Dracónido Menor".to_string(),
-                    habitat: "Pantanos, deltas de ríos y alcantarillas de grandes ciudades.".to_string(),
-                    description: "Una criatura reptiliana que puede licuar su propia estructura ósea para esconderse en charcos poco profundos.".to_string(),
-                    danger: "Medium".to_string(),
-                },
-                Creature {
-                    name: "The Howling Mycophage".to_string(),
-                    kind: "Híbrido · Necrófago · Bestia Infectada".to_string(),
-                    habitat: "Bosques densos, cuevas húmedas y zonas con poca luz solar.".to_string(),
-                    description: "Un lobo o bestia similar, mutado grotescamente por hongos parásitos que controlan su sistema nervioso.".to_string(),
-                    danger: "High".to_string(),
-                },
-                Creature {
-                    name: "The Vessel-Tearer".to_string(),
-                    kind: "Monstruosidad · Mutante Arcano · Maldito".to_string(),
-                    habitat: "Zonas de catástrofes mágicas recientes, grietas de maná abiertas, ruinas de laboratorios de hechiceros renegados.".to_string(),
-                    description: "Una criatura grotescamente asimétrica cuya carne se ha fusionado con cristales de maná volátil, brillando con una luz enfermiza.".to_string(),
-                    danger: "High".to_string(),
-                },
-            ],
+    fn errors(&self, project: &Project) -> Errors {
+        validation::location(
+            project,
+            self.id.as_deref(),
+            &self.universe_id,
+            &self.name,
+            self.parent_id.as_deref(),
+            &self.climate,
+            &self.description.text(),
+        )
+    }
 
-            creature_editor: None,
-            last_bestiary_click: None,
+    fn build(self) -> Location {
+        Location {
+            id: self.id.unwrap_or_default(),
+            universe_id: self.universe_id,
+            name: self.name.trim().to_string(),
+            kind: self.kind,
+            parent_id: self.parent_id,
+            climate: self.climate.trim().to_string(),
+            description: self.description.text().trim().to_string(),
+            archived: false,
         }
     }
 }
 
-pub fn update(state: &mut AppState, message: Message) {
-    const DOUBLE_CLICK_WINDOW: Duration = Duration::from_millis(420);
+/// Temporary in-memory editor state for a character.
+#[derive(Debug, Clone)]
+pub struct CharacterEditor {
+    /// `None` while creating; the id is assigned on save.
+    pub id: Option<String>,
+    pub universe_id: String,
 
-    match message {
-        Message::Navigate(route) => state.route = route,
+    pub name: String,
+    pub title: String,
+    /// Markdown.
+    pub description: text_editor::Content,
+    pub memberships: Vec<Membership>,
+    pub relationships: Vec<Relationship>,
+}
 
-        Message::Logout => {
-            // Offline app: route back to Overview for now
-            state.route = Route::Overview;
+impl CharacterEditor {
+    pub fn create_new(universe_id: String) -> Self {
+        Self {
+            id: None,
+            universe_id,
+            name: String::new(),
+            title: String::new(),
+            description: text_editor::Content::new(),
+            memberships: Vec::new(),
+            relationships: Vec::new(),
         }
+    }
 
-        Message::UniverseNameChanged(v) => state.new_universe_name = v,
-        Message::UniverseDescChanged(v) => state.new_universe_desc = v,
+    pub fn from_character(c: &Character) -> Self {
+        Self {
+            id: Some(c.id.clone()),
+            universe_id: c.universe_id.clone(),
+            name: c.name.clone(),
+            title: c.title.clone(),
+            description: text_editor::Content::with_text(&c.description),
+            memberships: c.memberships.clone(),
+            relationships: c.relationships.clone(),
+        }
+    }
 
-        Message::CreateUniverse => {
-            let name = state.new_universe_name.trim().to_string();
-            if name.is_empty() {
-                return;
-            }
+    fn to_character(&self) -> Character {
+        Character {
+            id: self.id.clone().unwrap_or_default(),
+            universe_id: self.universe_id.clone(),
+            name: self.name.trim().to_string(),
+            title: self.title.trim().to_string(),
+            description: self.description.text().trim().to_string(),
+            memberships: self
+                .memberships
+                .iter()
+                .map(|m| Membership {
+                    faction_id: m.faction_id.clone(),
+                    rank: m.rank.trim().to_string(),
+                })
+                .collect(),
+            relationships: self
+                .relationships
+                .iter()
+                .map(|r| Relationship {
+                    character_id: r.character_id.clone(),
+                    kind: r.kind.trim().to_string(),
+                })
+                .collect(),
+            archived: false,
+        }
+    }
+}
 
-            let id = name.to_lowercase().replace(' ', "-");
+impl Form for CharacterEditor {
+    type Entity = Character;
 
-            state.universes.push(Universe {
-                id,
-                name,
-                description: state.new_universe_desc.trim().to_string(),
-                archived: false,
-            });
+    const FIELDS: &'static [FormField<Self>] = &[
+        FormField::line(Field::Name, "Name", |ed| &ed.name, |ed| &mut ed.name),
+        FormField::line(
+            Field::Title,
+            "Title or role (e.g. Exiled heir of Marekhan)",
+            |ed| &ed.title,
+            |ed| &mut ed.title,
+        ),
+        FormField::markdown(Field::Description, "Description", 160.0, |ed| &ed.description, |ed| &mut ed.description),
+    ];
 
-            state.new_universe_name.clear();
-            state.new_universe_desc.clear();
-        }
+    fn get(state: &AppState) -> Option<&Self> {
+        state.character_editor.as_ref()
+    }
 
-        Message::OpenUniverse(id) => state.route = Route::UniverseDetail { universe_id: id },
-        Message::BackToUniverses => state.route = Route::UniverseList,
+    fn slot(state: &mut AppState) -> &mut Option<Self> {
+        &mut state.character_editor
+    }
 
-        Message::OpenBestiary(id) => {
-            state.creature_editor = None;
-            state.last_bestiary_click = None;
-            state.route = Route::Bestiary { universe_id: id };
-        }
-        Message::OpenTimeline(id) => state.route = Route::Timeline { universe_id: id },
-        Message::BackToUniverse(id) => {
-            state.creature_editor = None;
-            state.last_bestiary_click = None;
-            state.route = Route::UniverseDetail { universe_id: id };
+    fn create(_state: &AppState, universe_id: String) -> Self {
+        Self::create_new(universe_id)
+    }
+
+    fn edit(_state: &AppState, c: &Character) -> Self {
+        Self::from_character(c)
+    }
+
+    fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    fn universe_id(&self) -> &str {
+        &self.universe_id
+    }
+
+    fn errors(&self, project: &Project) -> Errors {
+        validation::character(project, &self.to_character())
+    }
+
+    fn build(self) -> Character {
+        self.to_character()
+    }
+
+    fn forget(&mut self, codex: Codex, id: &str) {
+        match codex {
+            Codex::Character => self.relationships.retain(|r| r.character_id != id),
+            Codex::Faction => self.memberships.retain(|m| m.faction_id != id),
+            _ => {}
         }
+    }
+}
 
-        // Bestiary interactions
-        Message::BestiaryCardClicked(index) => {
-            let now = Instant::now();
+/// Temporary in-memory editor state for a faction.
+#[derive(Debug, Clone)]
+pub struct FactionEditor {
+    /// `None` while creating; the id is assigned on save.
+    pub id: Option<String>,
+    pub universe_id: String,
 
-            match state.last_bestiary_click.take() {
-                Some((i, t)) if i == index && now.duration_since(t) <= DOUBLE_CLICK_WINDOW => {
-                    if let Some(c) = state.creatures.get(index) {
-                        state.creature_editor = Some(CreatureEditor::from_creature(index, c));
-                    }
-                }
-                _ => {
-                    state.last_bestiary_click = Some((index, now));
-                }
-            }
+    pub name: String,
+    /// Markdown.
+    pub description: text_editor::Content,
+    /// Comma-separated, highest first.
+    pub ranks: String,
+    pub allegiances: Vec<Allegiance>,
+}
+
+impl FactionEditor {
+    pub fn create_new(universe_id: String) -> Self {
+        Self {
+            id: None,
+            universe_id,
+            name: String::new(),
+            description: text_editor::Content::new(),
+            ranks: String::new(),
+            allegiances: Vec::new(),
         }
+    }
 
-        Message::CreatureEditorOpenCreate => {
-            state.creature_editor = Some(CreatureEditor::create_new());
-            state.last_bestiary_click = None;
+    pub fn from_faction(f: &Faction) -> Self {
+        Self {
+            id: Some(f.id.clone()),
+            universe_id: f.universe_id.clone(),
+            name: f.name.clone(),
+            description: text_editor::Content::with_text(&f.description),
+            ranks: f.ranks.join(", "),
+            allegiances: f.allegiances.clone(),
         }
+    }
 
-        Message::CreatureEditorCancel => {
-            state.creature_editor = None;
-            state.last_bestiary_click = None;
+    fn to_faction(&self) -> Faction {
+        Faction {
+            id: self.id.clone().unwrap_or_default(),
+            universe_id: self.universe_id.clone(),
+            name: self.name.trim().to_string(),
+            description: self.description.text().trim().to_string(),
+            ranks: self
+                .ranks
+                .split(',')
+                .map(str::trim)
+                .filter(|r| !r.is_empty())
+                .map(str::to_string)
+                .collect(),
+            allegiances: self.allegiances.clone(),
+            archived: false,
         }
+    }
+}
 
-        Message::CreatureEditorSave => {
-            let Some(editor) = state.creature_editor.take() else {
-                return;
-            };
+impl Form for FactionEditor {
+    type Entity = Faction;
 
-            // Minimal validation: require name
-            if editor.name.trim().is_empty() {
-                state.creature_editor = Some(editor);
-                return;
-            }
+    const FIELDS: &'static [FormField<Self>] = &[
+        FormField::line(Field::Name, "Name", |ed| &ed.name, |ed| &mut ed.name),
+        FormField::line(
+            Field::Ranks,
+            "Ranks, highest first (e.g. Grandmaster, Knight, Squire)",
+            |ed| &ed.ranks,
+            |ed| &mut ed.ranks,
+        ),
+        FormField::markdown(Field::Description, "Description", 160.0, |ed| &ed.description, |ed| &mut ed.description),
+    ];
 
-            let idx = editor.index;
-            let updated = editor.into_creature();
+    fn get(state: &AppState) -> Option<&Self> {
+        state.faction_editor.as_ref()
+    }
 
-            match idx {
-                Some(i) if i < state.creatures.len() => state.creatures[i] = updated,
-                _ => state.creatures.push(updated),
-            }
+    fn slot(state: &mut AppState) -> &mut Option<Self> {
+        &mut state.faction_editor
+    }
 
-            state.last_bestiary_click = None;
-        }
+    fn create(_state: &AppState, universe_id: String) -> Self {
+        Self::create_new(universe_id)
+    }
 
-        Message::CreatureEditorNameChanged(v) => {
-            if let Some(ed) = state.creature_editor.as_mut() {
-                ed.name = v;
-            }
-        }
-        Message::CreatureEditorKindChanged(v) => {
-            if let Some(ed) = state.creature_editor.as_mut() {
-                ed.kind = v;
-            }
+    fn edit(_state: &AppState, f: &Faction) -> Self {
+        Self::from_faction(f)
+    }
+
+    fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    fn universe_id(&self) -> &str {
+        &self.universe_id
+    }
+
+    fn errors(&self, project: &Project) -> Errors {
+        validation::faction(project, &self.to_faction())
+    }
+
+    fn build(self) -> Faction {
+        self.to_faction()
+    }
+
+    fn forget(&mut self, codex: Codex, id: &str) {
+        if codex == Codex::Faction {
+            self.allegiances.retain(|a| a.faction_id != id);
         }
-        Message::CreatureEditorHabitatChanged(v) => {
-            if let Some(ed) = state.creature_editor.as_mut() {
-                ed.habitat = v;
-            }
+    }
+}
+
+/// Temporary in-memory editor state for a Runomicon entry.
+#[derive(Debug, Clone)]
+pub struct TermEditor {
+    /// `None` while creating; the id is assigned on save.
+    pub id: Option<String>,
+    pub universe_id: String,
+
+    pub name: String,
+    pub pronunciation: String,
+    pub category: String,
+    /// Markdown.
+    pub definition: text_editor::Content,
+    pub see_also: Vec<String>,
+    pub glyph_asset_id: Option<String>,
+}
+
+impl TermEditor {
+    pub fn create_new(universe_id: String, category: String) -> Self {
+        Self {
+            id: None,
+            universe_id,
+            name: String::new(),
+            pronunciation: String::new(),
+            category,
+            definition: text_editor::Content::new(),
+            see_also: Vec::new(),
+            glyph_asset_id: None,
         }
-        Message::CreatureEditorDescriptionChanged(v) => {
-            if let Some(ed) = state.creature_editor.as_mut() {
-                ed.description = v;
-            }
+    }
+
+    pub fn from_term(t: &Term) -> Self {
+        Self {
+            id: Some(t.id.clone()),
+            universe_id: t.universe_id.clone(),
+            name: t.name.clone(),
+            pronunciation: t.pronunciation.clone(),
+            category: t.category.clone(),
+            definition: text_editor::Content::with_text(&t.definition),
+            see_also: t.see_also.clone(),
+            glyph_asset_id: t.glyph_asset_id.clone(),
         }
-        Message::CreatureEditorDangerChanged(v) => {
-            if let Some(ed) = state.creature_editor.as_mut() {
-                ed.danger = v;
-            }
+    }
+
+    fn to_term(&self) -> Term {
+        Term {
+            id: self.id.clone().unwrap_or_default(),
+            universe_id: self.universe_id.clone(),
+            name: self.name.trim().to_string(),
+            pronunciation: self.pronunciation.trim().to_string(),
+            category: self.category.trim().to_string(),
+            definition: self.definition.text().trim().to_string(),
+            see_also: self.see_also.clone(),
+            glyph_asset_id: self.glyph_asset_id.clone(),
+            archived: false,
         }
     }
 }
 
-pub fn view(state: &AppState) -> Element<'_, Message> {
-    let t = ui::Tokens::nub_dark();
+impl Form for TermEditor {
+    type Entity = Term;
 
-    let sidebar = ui::sidebar(state, t);
-    let header = ui::header(state, t);
+    const FIELDS: &'static [FormField<Self>] = &[
+        FormField::line(Field::Name, "Term", |ed| &ed.name, |ed| &mut ed.name),
+        FormField::line(
+            Field::Pronunciation,
+            "Pronunciation (e.g. RU-no-mi-kon)",
+            |ed| &ed.pronunciation,
+            |ed| &mut ed.pronunciation,
+        ),
+        FormField::line(Field::Category, "Category", |ed| &ed.category, |ed| &mut ed.category),
+        FormField::markdown(Field::Description, "Definition", 160.0, |ed| &ed.definition, |ed| &mut ed.definition),
+    ];
 
-    let page: Element<'_, Message> = match &state.route {
-        Route::Overview => pages::overview(state, t),
-        Route::Workspaces => pages::workspaces_stub(state, t),
-        Route::UniverseList => pages::universe_list(state, t),
-        Route::UniverseDetail { universe_id } => pages::universe_detail(state, t, universe_id),
-        Route::Bestiary { universe_id } => pages::bestiary(state, t, universe_id),
-        Route::Timeline { universe_id } => pages::timeline_stub(state, t, universe_id),
-        Route::Forge => pages::forge_stub(state, t),
-        Route::PmTools => pages::pm_stub(state, t),
-        Route::Assets => pages::assets_stub(state, t),
+    fn get(state: &AppState) -> Option<&Self> {
+        state.term_editor.as_ref()
+    }
+
+    fn slot(state: &mut AppState) -> &mut Option<Self> {
+        &mut state.term_editor
+    }
+
+    /// New entries start in the category being browsed.
+    fn create(state: &AppState, universe_id: String) -> Self {
+        let category = state.runomicon_categories.get(&universe_id).cloned().unwrap_or_default();
+        Self::create_new(universe_id, category)
+    }
+
+    fn edit(_state: &AppState, term: &Term) -> Self {
+        Self::from_term(term)
+    }
+
+    fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    fn universe_id(&self) -> &str {
+        &self.universe_id
+    }
+
+    fn errors(&self, project: &Project) -> Errors {
+        validation::term(project, &self.to_term())
+    }
+
+    fn build(self) -> Term {
+        self.to_term()
+    }
+
+    fn forget(&mut self, codex: Codex, id: &str) {
+        if codex == Codex::Term {
+            self.see_also.retain(|s| s != id);
+        }
+    }
+}
+
+/// Temporary in-memory editor state for an artifact.
+#[derive(Debug, Clone)]
+pub struct ArtifactEditor {
+    /// `None` while creating; the id is assigned on save.
+    pub id: Option<String>,
+    pub universe_id: String,
+
+    pub name: String,
+    pub rarity: Rarity,
+    pub origin: String,
+    pub holder: Option<Holder>,
+    /// Markdown.
+    pub description: text_editor::Content,
+    /// Markdown.
+    pub history: text_editor::Content,
+    pub properties: Vec<ArtifactProperty>,
+}
+
+impl ArtifactEditor {
+    pub fn create_new(universe_id: String) -> Self {
+        Self {
+            id: None,
+            universe_id,
+            name: String::new(),
+            rarity: Rarity::default(),
+            origin: String::new(),
+            holder: None,
+            description: text_editor::Content::new(),
+            history: text_editor::Content::new(),
+            properties: Vec::new(),
+        }
+    }
+
+    pub fn from_artifact(a: &Artifact) -> Self {
+        Self {
+            id: Some(a.id.clone()),
+            universe_id: a.universe_id.clone(),
+            name: a.name.clone(),
+            rarity: a.rarity,
+            origin: a.origin.clone(),
+            holder: a.holder.clone(),
+            description: text_editor::Content::with_text(&a.description),
+            history: text_editor::Content::with_text(&a.history),
+            properties: a.properties.clone(),
+        }
+    }
+
+    fn to_artifact(&self) -> Artifact {
+        Artifact {
+            id: self.id.clone().unwrap_or_default(),
+            universe_id: self.universe_id.clone(),
+            name: self.name.trim().to_string(),
+            rarity: self.rarity,
+            origin: self.origin.trim().to_string(),
+            holder: self.holder.clone(),
+            description: self.description.text().trim().to_string(),
+            history: self.history.text().trim().to_string(),
+            properties: self
+                .properties
+                .iter()
+                .map(|p| ArtifactProperty {
+                    name: p.name.trim().to_string(),
+                    value: p.value.trim().to_string(),
+                })
+                .collect(),
+            archived: false,
+        }
+    }
+}
+
+impl Form for ArtifactEditor {
+    type Entity = Artifact;
+
+    const FIELDS: &'static [FormField<Self>] = &[
+        FormField::line(Field::Name, "Name", |ed| &ed.name, |ed| &mut ed.name),
+        FormField::line(
+            Field::Origin,
+            "Origin (e.g. forged under Mount Vey in the Second Age)",
+            |ed| &ed.origin,
+            |ed| &mut ed.origin,
+        ),
+        FormField::markdown(Field::Description, "Description", 120.0, |ed| &ed.description, |ed| &mut ed.description),
+        FormField::markdown(
+            Field::History,
+            "History: who made it, who carried it, what it did",
+            160.0,
+            |ed| &ed.history,
+            |ed| &mut ed.history,
+        ),
+    ];
+
+    fn get(state: &AppState) -> Option<&Self> {
+        state.artifact_editor.as_ref()
+    }
+
+    fn slot(state: &mut AppState) -> &mut Option<Self> {
+        &mut state.artifact_editor
+    }
+
+    fn create(_state: &AppState, universe_id: String) -> Self {
+        Self::create_new(universe_id)
+    }
+
+    fn edit(_state: &AppState, a: &Artifact) -> Self {
+        Self::from_artifact(a)
+    }
+
+    fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    fn universe_id(&self) -> &str {
+        &self.universe_id
+    }
+
+    fn errors(&self, project: &Project) -> Errors {
+        validation::artifact(project, &self.to_artifact())
+    }
+
+    fn build(self) -> Artifact {
+        self.to_artifact()
+    }
+
+    fn forget(&mut self, codex: Codex, id: &str) {
+        let held = match &self.holder {
+            Some(Holder::Character(h)) => codex == Codex::Character && h == id,
+            Some(Holder::Creature(h)) => codex == Codex::Creature && h == id,
+            None => false,
+        };
+        if held {
+            self.holder = None;
+        }
+    }
+}
+
+/// Parsed Markdown of the long texts pages render, keyed by their source text.
+///
+/// `markdown::view` borrows its items, so they are parsed here after each update
+/// instead of while building the view.
+#[derive(Debug, Default)]
+pub struct MarkdownCache {
+    items: HashMap<String, Vec<markdown::Item>>,
+}
+
+impl MarkdownCache {
+    /// Parses new sources and drops the ones no longer shown anywhere.
+    fn sync(&mut self, project: &Project) {
+        let universes = project.universes.iter().flat_map(|u| {
+            [
+                u.description.as_str(),
+                ui::first_paragraph(&u.description),
+                u.lore.as_str(),
+            ]
+        });
+        let entries = project.entries().flat_map(|e| {
+            [e.description(), ui::first_paragraph(e.description())]
+                .into_iter()
+                .chain(e.long_texts())
+        });
+
+        let sources: HashSet<&str> = universes
+            .chain(entries)
+            .filter(|s| !s.trim().is_empty())
+            .collect();
+
+        self.items.retain(|source, _| sources.contains(source.as_str()));
+        for source in sources {
+            if !self.items.contains_key(source) {
+                self.items.insert(source.to_string(), markdown::parse(source).collect());
+            }
+        }
+    }
+
+    pub fn get(&self, source: &str) -> Option<&[markdown::Item]> {
+        self.items.get(source).map(Vec::as_slice)
+    }
+}
+
+/// Temporary in-memory editor state for a universe's creature template.
+#[derive(Debug, Clone)]
+pub struct TemplateEditor {
+    pub universe_id: String,
+    pub fields: Vec<FieldDraft>,
+}
+
+/// One template field being edited.
+#[derive(Debug, Clone)]
+pub struct FieldDraft {
+    /// `None` until saved; existing ids are kept so creature values stay attached.
+    pub id: Option<String>,
+    pub label: String,
+    pub kind: FieldKind,
+    /// Comma-separated choices of an enum field.
+    pub options: String,
+    pub on_card: bool,
+}
+
+impl FieldDraft {
+    pub fn parsed_options(&self) -> Vec<String> {
+        parse_tags(&self.options)
+    }
+
+    /// Message shown under the field's row when it can't be saved.
+    pub fn error(&self) -> Option<&'static str> {
+        if self.label.trim().is_empty() {
+            Some("A field needs a label")
+        } else if self.kind == FieldKind::Enum && self.parsed_options().is_empty() {
+            Some("A choice field needs at least one option")
+        } else {
+            None
+        }
+    }
+}
+
+impl TemplateEditor {
+    pub fn from_fields(universe_id: String, fields: &[FieldDef]) -> Self {
+        Self {
+            universe_id,
+            fields: fields
+                .iter()
+                .map(|f| FieldDraft {
+                    id: Some(f.id.clone()),
+                    label: f.label.clone(),
+                    kind: f.kind,
+                    options: f.options.join(", "),
+                    on_card: f.on_card,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.fields.iter().all(|f| f.error().is_none())
+    }
+
+    /// `None` while any field is invalid.
+    pub fn to_template(&self) -> Option<CreatureTemplate> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        let mut fields: Vec<FieldDef> = Vec::with_capacity(self.fields.len());
+        for draft in &self.fields {
+            let id = match &draft.id {
+                Some(id) => id.clone(),
+                None => crate::model::unique_id(&draft.label, "field", |id| {
+                    fields.iter().any(|f| f.id == id) || self.fields.iter().any(|d| d.id.as_deref() == Some(id))
+                }),
+            };
+
+            fields.push(FieldDef {
+                id,
+                label: draft.label.trim().to_string(),
+                kind: draft.kind,
+                options: if draft.kind == FieldKind::Enum { draft.parsed_options() } else { Vec::new() },
+                on_card: draft.on_card,
+            });
+        }
+
+        Some(CreatureTemplate {
+            universe_id: self.universe_id.clone(),
+            fields,
+        })
+    }
+}
+
+/// Sort order of the bestiary grids.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BestiarySort {
+    #[default]
+    Name,
+    Danger,
+    LastEdited,
+}
+
+impl BestiarySort {
+    pub const ALL: [BestiarySort; 3] = [BestiarySort::Name, BestiarySort::Danger, BestiarySort::LastEdited];
+}
+
+impl fmt::Display for BestiarySort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BestiarySort::Name => "Name",
+            BestiarySort::Danger => "Danger",
+            BestiarySort::LastEdited => "Last edited",
+        })
+    }
+}
+
+/// Filters and sort of one universe's bestiary. Kept per universe for the session.
+#[derive(Debug, Clone, Default)]
+pub struct BestiaryFilter {
+    pub danger: Option<DangerLevel>,
+    pub tag: Option<String>,
+    /// Case-insensitive substring of `Creature::habitat`.
+    pub habitat: String,
+    pub sort: BestiarySort,
+}
+
+impl BestiaryFilter {
+    pub fn is_active(&self) -> bool {
+        self.danger.is_some() || self.tag.is_some() || !self.habitat.trim().is_empty()
+    }
+
+    pub fn matches(&self, c: &Creature) -> bool {
+        let habitat = self.habitat.trim().to_lowercase();
+
+        self.danger.as_ref().is_none_or(|d| c.danger == *d)
+            && self.tag.as_deref().is_none_or(|tag| c.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+            && (habitat.is_empty() || c.habitat.to_lowercase().contains(&habitat))
+    }
+
+    /// Name sorts A→Z, danger most dangerous first with unrated creatures last, last
+    /// edited newest first. Custom levels rank in the order `universe` lists them.
+    pub fn sort(&self, creatures: &mut [&Creature], universe: Option<&Universe>) {
+        let by_name = |a: &&Creature, b: &&Creature| a.name.to_lowercase().cmp(&b.name.to_lowercase());
+        let rank = |c: &Creature| universe.and_then(|u| u.danger_rank(&c.danger));
+
+        match self.sort {
+            BestiarySort::Name => creatures.sort_by(by_name),
+            // `None` orders before any rank, so reversing puts unrated creatures last.
+            BestiarySort::Danger => creatures.sort_by(|a, b| rank(b).cmp(&rank(a)).then_with(|| by_name(a, b))),
+            BestiarySort::LastEdited => {
+                creatures.sort_by(|a, b| b.edited_at.cmp(&a.edited_at).then_with(|| by_name(a, b)))
+            }
+        }
+    }
+}
+
+/// Temporary in-memory editor state for the timeline page. Only one form is open at a time.
+#[derive(Debug, Clone)]
+pub enum TimelineEditor {
+    Event(EventEditor),
+    Era(EraEditor),
+    Calendar(CalendarEditor),
+}
+
+#[derive(Debug, Clone)]
+pub struct EventEditor {
+    /// `None` while creating; the id is assigned on save.
+    pub id: Option<String>,
+    pub universe_id: String,
+
+    pub title: String,
+    pub description: String,
+    pub year: String,
+    /// 1-based month of the universe calendar, `0` for "no month".
+    pub month: u32,
+    pub day: String,
+}
+
+impl EventEditor {
+    pub fn create_new(universe_id: String) -> Self {
+        Self {
+            id: None,
+            universe_id,
+            title: String::new(),
+            description: String::new(),
+            year: String::new(),
+            month: 0,
+            day: String::new(),
+        }
+    }
+
+    pub fn from_event(e: &TimelineEvent) -> Self {
+        Self {
+            id: Some(e.id.clone()),
+            universe_id: e.universe_id.clone(),
+            title: e.title.clone(),
+            description: e.description.clone(),
+            year: e.date.year.to_string(),
+            month: e.date.month,
+            day: if e.date.day > 0 { e.date.day.to_string() } else { String::new() },
+        }
+    }
+
+    /// `None` while the form cannot produce a valid event for `calendar`.
+    pub fn to_event(&self, calendar: &Calendar) -> Option<TimelineEvent> {
+        let title = self.title.trim();
+        let year = self.year.trim().parse().ok()?;
+        let day = match self.day.trim() {
+            "" => 0,
+            d => d.parse().ok()?,
+        };
+
+        if title.is_empty() || self.month as usize > calendar.months.len() || day > calendar.days_per_month {
+            return None;
+        }
+
+        // A day only makes sense inside a month.
+        let month = self.month;
+        let day = if month == 0 { 0 } else { day };
+
+        Some(TimelineEvent {
+            id: self.id.clone().unwrap_or_default(),
+            universe_id: self.universe_id.clone(),
+            title: title.to_string(),
+            description: self.description.trim().to_string(),
+            date: FantasyDate { year, month, day },
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EraEditor {
+    /// `None` while creating; the id is assigned on save.
+    pub id: Option<String>,
+    pub universe_id: String,
+
+    pub name: String,
+    pub description: String,
+    pub start_year: String,
+    /// Empty for an ongoing era.
+    pub end_year: String,
+}
+
+impl EraEditor {
+    pub fn create_new(universe_id: String) -> Self {
+        Self {
+            id: None,
+            universe_id,
+            name: String::new(),
+            description: String::new(),
+            start_year: String::new(),
+            end_year: String::new(),
+        }
+    }
+
+    pub fn from_era(e: &Era) -> Self {
+        Self {
+            id: Some(e.id.clone()),
+            universe_id: e.universe_id.clone(),
+            name: e.name.clone(),
+            description: e.description.clone(),
+            start_year: e.start_year.to_string(),
+            end_year: e.end_year.map(|y| y.to_string()).unwrap_or_default(),
+        }
+    }
+
+    /// `None` while the form cannot produce a valid era.
+    pub fn to_era(&self) -> Option<Era> {
+        let name = self.name.trim();
+        let start_year = self.start_year.trim().parse().ok()?;
+        let end_year = match self.end_year.trim() {
+            "" => None,
+            y => Some(y.parse().ok()?),
+        };
+
+        if name.is_empty() || end_year.is_some_and(|end| end < start_year) {
+            return None;
+        }
+
+        Some(Era {
+            id: self.id.clone().unwrap_or_default(),
+            universe_id: self.universe_id.clone(),
+            name: name.to_string(),
+            description: self.description.trim().to_string(),
+            start_year,
+            end_year,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CalendarEditor {
+    pub universe_id: String,
+
+    /// Comma-separated month names, in order.
+    pub months: String,
+    pub days_per_month: String,
+    pub year_suffix: String,
+}
+
+impl CalendarEditor {
+    pub fn from_calendar(c: &Calendar) -> Self {
+        Self {
+            universe_id: c.universe_id.clone(),
+            months: c.months.join(", "),
+            days_per_month: c.days_per_month.to_string(),
+            year_suffix: c.year_suffix.clone(),
+        }
+    }
+
+    /// `None` while the form cannot produce a valid calendar.
+    pub fn to_calendar(&self) -> Option<Calendar> {
+        let months: Vec<String> = self
+            .months
+            .split(',')
+            .map(str::trim)
+            .filter(|m| !m.is_empty())
+            .map(str::to_string)
+            .collect();
+        let days_per_month = self.days_per_month.trim().parse().ok().filter(|d| *d > 0)?;
+
+        if months.is_empty() {
+            return None;
+        }
+
+        Some(Calendar {
+            universe_id: self.universe_id.clone(),
+            months,
+            days_per_month,
+            year_suffix: self.year_suffix.trim().to_string(),
+        })
+    }
+}
+
+/// The scene open in The Forge. `content` mirrors `Scene::body`; typing stays here until
+/// `update` writes it back before any message that isn't typing, e.g. a click or Save.
+#[derive(Debug, Clone)]
+pub struct SceneEditor {
+    pub scene_id: String,
+    pub content: text_editor::Content,
+    /// Typed text not yet written back to the scene.
+    pub unsaved: bool,
+}
+
+/// Temporary in-memory editor state for a Kanban card of the open board.
+#[derive(Debug, Clone)]
+pub struct CardEditor {
+    /// `None` while creating; the id is assigned on save.
+    pub id: Option<String>,
+    /// Column a new card is added to; ignored when editing.
+    pub column_id: String,
+
+    pub title: String,
+    pub description: String,
+    pub assignee: String,
+    pub due: String,
+    /// Comma-separated.
+    pub labels: String,
+}
+
+impl CardEditor {
+    pub fn create_new(column_id: String) -> Self {
+        Self {
+            id: None,
+            column_id,
+            title: String::new(),
+            description: String::new(),
+            assignee: String::new(),
+            due: String::new(),
+            labels: String::new(),
+        }
+    }
+
+    pub fn from_card(column_id: &str, c: &Card) -> Self {
+        Self {
+            id: Some(c.id.clone()),
+            column_id: column_id.to_string(),
+            title: c.title.clone(),
+            description: c.description.clone(),
+            assignee: c.assignee.clone(),
+            due: c.due.clone().unwrap_or_default(),
+            labels: c.labels.join(", "),
+        }
+    }
+
+    /// `None` while the form cannot produce a valid card.
+    pub fn to_card(&self) -> Option<Card> {
+        let title = self.title.trim();
+        let due = match self.due.trim() {
+            "" => None,
+            d if is_valid_due_date(d) => Some(d.to_string()),
+            _ => return None,
+        };
+
+        if title.is_empty() {
+            return None;
+        }
+
+        Some(Card {
+            id: self.id.clone().unwrap_or_default(),
+            title: title.to_string(),
+            description: self.description.trim().to_string(),
+            assignee: self.assignee.trim().to_string(),
+            due,
+            labels: self
+                .labels
+                .split(',')
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .map(str::to_string)
+                .collect(),
+        })
+    }
+}
+
+/// Temporary in-memory editor state for an asset's tags and attachments.
+#[derive(Debug, Clone)]
+pub struct AssetEditor {
+    pub id: String,
+
+    /// Comma-separated.
+    pub tags: String,
+    pub universe_id: Option<String>,
+    pub creature_id: Option<String>,
+}
+
+impl AssetEditor {
+    pub fn from_asset(a: &Asset) -> Self {
+        Self {
+            id: a.id.clone(),
+            tags: a.tags.join(", "),
+            universe_id: a.universe_id.clone(),
+            creature_id: a.creature_id.clone(),
+        }
+    }
+
+    pub fn parsed_tags(&self) -> Vec<String> {
+        self.tags
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// Board entry targeted by a delete confirmation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BoardEntry {
+    Board(String),
+    Column(String),
+    Card(String),
+}
+
+/// Forge entry targeted by a delete confirmation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ForgeEntry {
+    Manuscript(String),
+    Chapter(String),
+    Scene(String),
+}
+
+/// Inline title field whose text is kept in [`AppState::title_draft`] while typing.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TitleField {
+    Workspace(String),
+    Chapter(String),
+    Scene(String),
+    Board,
+    Column(String),
+}
+
+/// What was typed into a [`TitleField`] and not applied yet.
+#[derive(Debug, Clone)]
+pub struct TitleDraft {
+    pub field: TitleField,
+    pub value: String,
+}
+
+/// Timeline entry targeted by a delete confirmation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimelineEntry {
+    Event(String),
+    Era(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Navigate(Route),
+    /// Opens the item behind an Overview recents row.
+    OpenActivity(ActivityTarget),
+
+    // Global search
+    SearchFocus,
+    SearchQueryChanged(String),
+    /// Enter in the search box: opens the best hit.
+    SearchSubmit,
+    SearchSelect(ActivityTarget),
+    SearchClose,
+
+    Undo,
+    Redo,
+    DismissToast,
+    DismissStorageError,
+    /// Enter in an inline title field; `update` applies the draft before any message but typing.
+    TitleSubmit,
+
+    Logout,
+    CloseWindow(window::Id),
+
+    // Workspace interactions
+    WorkspaceNewNameChanged(String),
+    CreateWorkspace,
+    SwitchWorkspace(String),
+    /// Kept as a draft, see [`Message::TitleSubmit`].
+    WorkspaceNameChanged(String, String),
+    /// First step of a delete: asks for confirmation on the workspace card.
+    DeleteWorkspace(String),
+    DeleteWorkspaceConfirm,
+    DeleteWorkspaceCancel,
+
+    UniverseNameChanged(String),
+    UniverseDescChanged(String),
+    CreateUniverse,
+
+    ArchiveUniverse(String),
+    RestoreUniverse(String),
+    /// First step of a delete: asks for confirmation on the universe card.
+    DeleteUniverse(String),
+    DeleteUniverseConfirm,
+    DeleteUniverseCancel,
+
+    OpenUniverse(String),
+    BackToUniverses,
+
+    UniverseEditorOpen(String),
+    UniverseEditorNameChanged(String),
+    UniverseEditorDescriptionEdit(text_editor::Action),
+    UniverseEditorLoreEdit(text_editor::Action),
+    UniverseEditorSave,
+    UniverseEditorCancel,
+
+    /// A link clicked in rendered Markdown.
+    OpenLink(markdown::Uri),
+
+    /// Opens a universe's codex page.
+    OpenCodex(Codex, String),
+    OpenTimeline(String),
+    BackToUniverse(String),
+
+    // Codex interactions, the same for every codex
+    /// Opens a creature's full sheet, or any other entry's codex with the entry in the editor.
+    OpenEntry(Codex, String),
+    /// Opens an empty editor for a new entry of the universe.
+    EditorOpenCreate(Codex, String),
+    EditorOpenEdit(Codex, String),
+    EditorCancel(Codex),
+    EditorSave(Codex),
+    /// Input of one of the fields every editor of the codex has, e.g. its name.
+    EditorLineChanged(Codex, Field, String),
+    EditorTextEdit(Codex, Field, text_editor::Action),
+
+    ArchiveEntry(Codex, String),
+    RestoreEntry(Codex, String),
+    /// First step of a delete: asks for confirmation on the entry's card.
+    DeleteEntry(Codex, String),
+    DeleteEntryConfirm,
+    DeleteEntryCancel,
+
+    // Bestiary interactions
+
+    /// Filter/sort messages act on the bestiary of the current `Route::Codex` showing creatures.
+    BestiaryDangerFilterChanged(Option<DangerLevel>),
+    BestiaryTagFilterChanged(Option<String>),
+    BestiaryHabitatFilterChanged(String),
+    BestiarySortChanged(BestiarySort),
+    BestiaryClearFilters,
+
+    CreatureEditorTagInputChanged(String),
+    /// Enter in the tag input: adds what was typed.
+    CreatureEditorTagSubmit,
+    /// Picks an autocomplete suggestion.
+    CreatureEditorAddTag(String),
+    CreatureEditorRemoveTag(String),
+    CreatureEditorAddHabitatLocation(String),
+    CreatureEditorRemoveHabitatLocation(String),
+    CreatureEditorDangerChanged(DangerLevel),
+    CreatureEditorNewDangerLevelChanged(String),
+    /// Adds the typed level to the universe's custom danger levels and selects it.
+    CreatureEditorAddDangerLevel,
+
+    /// Input of a template field, keyed by field id.
+    CreatureEditorFieldChanged(String, String),
+    CreatureEditorLongFieldEdit(String, text_editor::Action),
+
+    CreatureEditorTransferTargetChanged(String),
+    /// Saves the editor into the picked universe: `copy` keeps the original in place.
+    CreatureEditorTransfer { copy: bool },
+
+    // Creature template editor
+    TemplateEditorOpen(String),
+    TemplateEditorCancel,
+    TemplateEditorSave,
+    TemplateFieldAdd,
+    TemplateFieldLabelChanged(usize, String),
+    TemplateFieldKindChanged(usize, FieldKind),
+    TemplateFieldOptionsChanged(usize, String),
+    TemplateFieldOnCardToggled(usize, bool),
+    TemplateFieldMove { index: usize, up: bool },
+    TemplateFieldRemove(usize),
+
+    // Locations interactions
+    /// Opens an empty editor for a place inside the location.
+    LocationEditorAddInside(String),
+    LocationEditorKindChanged(LocationKind),
+    LocationEditorParentChanged(Option<String>),
+
+    // Characters interactions
+    /// Joins the faction, with no rank yet.
+    CharacterEditorAddMembership(String),
+    CharacterEditorRankChanged(usize, String),
+    CharacterEditorRemoveMembership(usize),
+    /// Adds a relationship towards the character; its kind is typed next.
+    CharacterEditorAddRelationship(String),
+    CharacterEditorRelationshipKindChanged(usize, String),
+    CharacterEditorRemoveRelationship(usize),
+
+    // Factions interactions
+    /// Adds a stance towards the faction, neutral until changed.
+    FactionEditorAddAllegiance(String),
+    FactionEditorStanceChanged(usize, Stance),
+    FactionEditorRemoveAllegiance(usize),
+
+    // Runomicon interactions
+    /// Narrows the Runomicon of the current `Route::Codex` showing terms; `None` shows every category.
+    RunomiconCategoryChanged(Option<String>),
+    TermEditorAddSeeAlso(String),
+    TermEditorRemoveSeeAlso(usize),
+    /// An image or SVG asset to draw the glyph from; `None` removes it.
+    TermEditorGlyphChanged(Option<String>),
+
+    // Artifacts interactions
+    ArtifactEditorRarityChanged(Rarity),
+    /// `None` marks the artifact as lost or unclaimed.
+    ArtifactEditorHolderChanged(Option<Holder>),
+    ArtifactEditorAddProperty,
+    ArtifactEditorPropertyNameChanged(usize, String),
+    ArtifactEditorPropertyValueChanged(usize, String),
+    ArtifactEditorRemoveProperty(usize),
+
+    // Timeline interactions
+    TimelineEventEditorOpenCreate(String),
+    TimelineEventEdit(String),
+    TimelineEventTitleChanged(String),
+    TimelineEventDescriptionChanged(String),
+    TimelineEventYearChanged(String),
+    TimelineEventMonthChanged(u32),
+    TimelineEventDayChanged(String),
+
+    TimelineEraEditorOpenCreate(String),
+    TimelineEraEdit(String),
+    TimelineEraNameChanged(String),
+    TimelineEraDescriptionChanged(String),
+    TimelineEraStartChanged(String),
+    TimelineEraEndChanged(String),
+
+    TimelineCalendarEdit(String),
+    TimelineCalendarMonthsChanged(String),
+    TimelineCalendarDaysChanged(String),
+    TimelineCalendarSuffixChanged(String),
+
+    TimelineEditorCancel,
+    TimelineEditorSave,
+
+    /// First step of a delete: asks for confirmation on the timeline entry.
+    DeleteTimelineEntry(TimelineEntry),
+    DeleteTimelineConfirm,
+    DeleteTimelineCancel,
+
+    // Forge interactions
+    ForgeNewTitleChanged(String),
+    ForgeNewKindChanged(ManuscriptKind),
+    CreateManuscript,
+    OpenManuscript(String),
+
+    /// Chapter/scene messages act on the manuscript of the current `Route::Manuscript`.
+    ForgeAddChapter,
+    /// Title typing is kept as a draft, see [`Message::TitleSubmit`].
+    ForgeChapterTitleChanged(String, String),
+    ForgeMoveChapter { chapter_id: String, up: bool },
+    ForgeAddScene(String),
+    ForgeOpenScene(String),
+    ForgeMoveScene { scene_id: String, up: bool },
+    ForgeSceneTitleChanged(String),
+    /// Typing stays in the editor until any other message, e.g. this one, writes it back.
+    ForgeSceneEdit(text_editor::Action),
+    ForgeSceneSave,
+
+    /// First step of a delete: asks for confirmation next to the entry.
+    DeleteForgeEntry(ForgeEntry),
+    DeleteForgeConfirm,
+    DeleteForgeCancel,
+
+    // PM interactions
+    PmNewBoardNameChanged(String),
+    CreateBoard,
+    OpenBoard(String),
+
+    /// Board/column/card messages act on the board of the current `Route::Board`.
+    /// Name and title typing is kept as a draft, see [`Message::TitleSubmit`].
+    BoardNameChanged(String),
+    BoardUniverseChanged(Option<String>),
+    BoardAddColumn,
+    BoardColumnTitleChanged(String, String),
+    BoardMoveColumn { column_id: String, left: bool },
+    MoveCard { card_id: String, direction: CardMove },
+
+    CardEditorOpenCreate(String),
+    CardEdit(String),
+    CardEditorCancel,
+    CardEditorSave,
+    CardEditorTitleChanged(String),
+    CardEditorDescriptionChanged(String),
+    CardEditorAssigneeChanged(String),
+    CardEditorDueChanged(String),
+    CardEditorLabelsChanged(String),
+
+    /// First step of a delete: asks for confirmation next to the entry.
+    DeleteBoardEntry(BoardEntry),
+    DeleteBoardConfirm,
+    DeleteBoardCancel,
+
+    // Assets interactions
+    AssetImportPathChanged(String),
+    ImportAsset,
+    AssetTagFilterChanged(String),
+
+    AssetEdit(String),
+    AssetEditorCancel,
+    AssetEditorSave,
+    AssetEditorTagsChanged(String),
+    AssetEditorUniverseChanged(Option<String>),
+    AssetEditorCreatureChanged(Option<String>),
+
+    /// First step of a delete: asks for confirmation on the asset card.
+    DeleteAsset(String),
+    DeleteAssetConfirm,
+    DeleteAssetCancel,
+}
+
+#[derive(Debug)]
+pub struct AppState {
+    pub route: Route,
+
+    pub search_query: String,
+
+    pub undo: UndoStack,
+    /// Notice at the bottom of the window after a destructive action or an undo.
+    pub toast: Option<Toast>,
+
+    pub new_workspace_name: String,
+    /// Workspace waiting for delete confirmation.
+    pub pending_workspace_delete: Option<String>,
+
+    pub new_universe_name: String,
+    pub new_universe_desc: String,
+    /// Universe waiting for delete confirmation.
+    pub pending_universe_delete: Option<String>,
+    pub universe_editor: Option<UniverseEditor>,
+
+    pub project: Project,
+    pub markdown: MarkdownCache,
+
+    /// `None` when the store could not be read nor backed up; the session then stays in
+    /// memory only so a damaged file is never overwritten.
+    pub store: Option<Store>,
+    /// The project changed since it was last written to the store.
+    pub unsaved: bool,
+    /// Problem reading or writing the project file, shown above every page until dismissed.
+    pub storage_error: Option<String>,
+
+    // Codices
+    /// Codex entry waiting for delete confirmation.
+    pub pending_entry_delete: Option<(Codex, String)>,
+
+    // Bestiary
+    /// Keyed by universe id.
+    pub bestiary_filters: HashMap<String, BestiaryFilter>,
+    pub creature_editor: Option<CreatureEditor>,
+    pub template_editor: Option<TemplateEditor>,
+
+    // Locations
+    pub location_editor: Option<LocationEditor>,
+
+    // Characters & factions
+    pub character_editor: Option<CharacterEditor>,
+    pub faction_editor: Option<FactionEditor>,
+
+    // Runomicon
+    /// Category each universe's Runomicon is narrowed to, keyed by universe id.
+    pub runomicon_categories: HashMap<String, String>,
+    pub term_editor: Option<TermEditor>,
+
+    // Artifacts
+    pub artifact_editor: Option<ArtifactEditor>,
+
+    // Timeline editor
+    pub timeline_editor: Option<TimelineEditor>,
+    pub pending_timeline_delete: Option<TimelineEntry>,
+
+    // The Forge
+    pub new_manuscript_title: String,
+    pub new_manuscript_kind: ManuscriptKind,
+    pub scene_editor: Option<SceneEditor>,
+    pub pending_forge_delete: Option<ForgeEntry>,
+    /// Name or title being typed inline, applied once the user moves on.
+    pub title_draft: Option<TitleDraft>,
+
+    // PM Tools
+    pub new_board_name: String,
+    pub card_editor: Option<CardEditor>,
+    pub pending_board_delete: Option<BoardEntry>,
+
+    // Assets
+    pub asset_import_path: String,
+    pub asset_import_error: Option<String>,
+    pub asset_tag_filter: String,
+    pub asset_editor: Option<AssetEditor>,
+    pub pending_asset_delete: Option<String>,
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        let arhelis_id = "arhelis".to_string();
+        let workspace_id = "arhelis".to_string();
+
+        Self {
+            route: Route::Overview,
+
+            search_query: String::new(),
+            undo: UndoStack::default(),
+            toast: None,
+
+            new_workspace_name: String::new(),
+            pending_workspace_delete: None,
+
+            new_universe_name: "".to_string(),
+            new_universe_desc: "".to_string(),
+            pending_universe_delete: None,
+            universe_editor: None,
+
+            project: Project {
+                workspaces: vec![Workspace {
+                    id: workspace_id.clone(),
+                    name: "Arhelis".to_string(),
+                }],
+                active_workspace: workspace_id.clone(),
+
+                universes: vec![Universe {
+                    id: arhelis_id.clone(),
+                    workspace_id: workspace_id.clone(),
+                    name: "Arhelis".to_string(),
+                    description: "Fantasy Universe".to_string(),
+                    lore: String::new(),
+                    archived: false,
+                    danger_levels: Vec::new(),
+                }],
+
+                creatures: vec![
+                    Creature {
+                        id: "fog-engulfer".to_string(),
+                        universe_id: arhelis_id.clone(),
+                        name: "Fog Engulfer".to_string(),
+                        tags: vec!["Elemental".to_string(), "Relicto".to_string()],
+                        habitat: "Pasos de montaña brumosos, valles cerrados al amanecer.".to_string(),
+                        habitat_locations: Vec::new(),
+                        description: "Una criatura compuesta de vapor condensado y huesos de sus víctimas, solo sólida cuando ataca.".to_string(),
+                        danger: DangerLevel::High,
+                        fields: BTreeMap::new(),
+                        archived: false,
+                        edited_at: 0,
+                        history: History::default(),
+                    },
+                    Creature {
+                        id: "oathbound-specter".to_string(),
+                        universe_id: arhelis_id.clone(),
+                        name: "Oathbound Specter".to_string(),
+                        tags: vec!["Espectro".to_string(), "Constructo".to_string(), "Maldito".to_string()],
+                        habitat: "Ruinas de castillos, antiguos campos de batalla, criptas de familias nobles.".to_string(),
+                        habitat_locations: Vec::new(),
+                        description: "Una armadura vacía animada por el espíritu de un guerrero que murió rompiendo una promesa sagrada.".to_string(),
+                        danger: DangerLevel::Medium,
+                        fields: BTreeMap::new(),
+                        archived: false,
+                        edited_at: 0,
+                        history: History::default(),
+                    },
+                    Creature {
+                        id: "obsidian-widow".to_string(),
+                        universe_id: arhelis_id.clone(),
+                        name: "Obsidian Widow".to_string(),
+                        tags: vec!["Insectoide".to_string(), "Arácnido Acorazado".to_string()],
+                        habitat: "Cavernas volcánicas, minas profundas y grietas montañosas.".to_string(),
+                        habitat_locations: Vec::new(),
+                        description: "Una araña gigante con un caparazón cristalino que refleja la magia y garras afiladas como diamantes.".to_string(),
+                        danger: DangerLevel::Extreme,
+                        fields: BTreeMap::new(),
+                        archived: false,
+                        edited_at: 0,
+                        history: History::default(),
+                    },
+                    Creature {
+                        id: "silt-siren".to_string(),
+                        universe_id: arhelis_id.clone(),
+                        name: "Silt Siren".to_string(),
+                        tags: vec!["Anfibio".to_string(), "Dracónido Menor".to_string()],
+                        habitat: "Pantanos, deltas de ríos y alcantarillas de grandes ciudades.".to_string(),
+                        habitat_locations: Vec::new(),
+                        description: "Una criatura reptiliana que puede licuar su propia estructura ósea para esconderse en charcos poco profundos.".to_string(),
+                        danger: DangerLevel::Medium,
+                        fields: BTreeMap::new(),
+                        archived: false,
+                        edited_at: 0,
+                        history: History::default(),
+                    },
+                    Creature {
+                        id: "the-howling-mycophage".to_string(),
+                        universe_id: arhelis_id.clone(),
+                        name: "The Howling Mycophage".to_string(),
+                        tags: vec!["Híbrido".to_string(), "Necrófago".to_string(), "Bestia Infectada".to_string()],
+                        habitat: "Bosques densos, cuevas húmedas y zonas con poca luz solar.".to_string(),
+                        habitat_locations: Vec::new(),
+                        description: "Un lobo o bestia similar, mutado grotescamente por hongos parásitos que controlan su sistema nervioso.".to_string(),
+                        danger: DangerLevel::High,
+                        fields: BTreeMap::new(),
+                        archived: false,
+                        edited_at: 0,
+                        history: History::default(),
+                    },
+                    Creature {
+                        id: "the-vessel-tearer".to_string(),
+                        universe_id: arhelis_id.clone(),
+                        name: "The Vessel-Tearer".to_string(),
+                        tags: vec!["Monstruosidad".to_string(), "Mutante Arcano".to_string(), "Maldito".to_string()],
+                        habitat: "Zonas de catástrofes mágicas recientes, grietas de maná abiertas, ruinas de laboratorios de hechiceros renegados.".to_string(),
+                        habitat_locations: Vec::new(),
+                        description: "Una criatura grotescamente asimétrica cuya carne se ha fusionado con cristales de maná volátil, brillando con una luz enfermiza.".to_string(),
+                        danger: DangerLevel::High,
+                        fields: BTreeMap::new(),
+                        archived: false,
+                        edited_at: 0,
+                        history: History::default(),
+                    },
+                ],
+                locations: Vec::new(),
+                characters: Vec::new(),
+                factions: Vec::new(),
+                terms: Vec::new(),
+                artifacts: Vec::new(),
+                calendars: Vec::new(),
+                creature_templates: Vec::new(),
+                eras: Vec::new(),
+                events: Vec::new(),
+
+                manuscripts: vec![Manuscript {
+                    id: "eventum-arhalen".to_string(),
+                    workspace_id: workspace_id.clone(),
+                    title: "Eventum Arhalen".to_string(),
+                    kind: ManuscriptKind::Novel,
+                    chapters: vec![Chapter {
+                        id: "chapter-1".to_string(),
+                        title: "Chapter 1".to_string(),
+                        scenes: vec![Scene {
+                            id: "the-oath".to_string(),
+                            title: "The Oath".to_string(),
+                            body: String::new(),
+                        }],
+                    }],
+                }],
+
+                boards: vec![Board::new(
+                    "tas-pm-roadmap".to_string(),
+                    workspace_id,
+                    "TAS — PM Roadmap".to_string(),
+                )],
+
+                assets: Vec::new(),
+
+                activity: ActivityLog::default(),
+            },
+
+            store: None,
+            unsaved: false,
+            storage_error: None,
+            markdown: MarkdownCache::default(),
+
+            pending_entry_delete: None,
+
+            bestiary_filters: HashMap::new(),
+            creature_editor: None,
+            template_editor: None,
+
+            location_editor: None,
+
+            character_editor: None,
+            faction_editor: None,
+            runomicon_categories: HashMap::new(),
+            term_editor: None,
+            artifact_editor: None,
+
+            timeline_editor: None,
+            pending_timeline_delete: None,
+
+            new_manuscript_title: String::new(),
+            new_manuscript_kind: ManuscriptKind::Novel,
+            scene_editor: None,
+            pending_forge_delete: None,
+            title_draft: None,
+
+            new_board_name: String::new(),
+            card_editor: None,
+            pending_board_delete: None,
+
+            asset_import_path: String::new(),
+            asset_import_error: None,
+            asset_tag_filter: String::new(),
+            asset_editor: None,
+            pending_asset_delete: None,
+        }
+    }
+}
+
+impl AppState {
+    /// Boots from the on-disk store. The seed data from `Default` is only kept for a
+    /// brand-new store (no project file yet) or one that could not be read.
+    pub fn load() -> Self {
+        let mut state = Self::default();
+        let mut store = Store::open(Store::default_path());
+
+        match store.load() {
+            Ok(Some(mut project)) => {
+                project.migrate();
+                state.project = project;
+                state.store = Some(store);
+            }
+            Ok(None) => state.store = Some(store),
+            Err(err) => {
+                let path = store.path().display().to_string();
+                eprintln!("{APP_ACRONYM}: could not read {path}: {err}");
+
+                // With a copy kept, the fresh project may be saved over the damaged file.
+                state.storage_error = Some(match store.keep_unreadable() {
+                    Ok(copy) => {
+                        state.store = Some(store);
+                        format!(
+                            "Could not read {path} ({err}). A copy was kept at {}; this session starts from a fresh project.",
+                            copy.display()
+                        )
+                    }
+                    Err(copy_err) => format!(
+                        "Could not read {path} ({err}) nor back it up ({copy_err}). Changes made now won't be saved."
+                    ),
+                });
+            }
+        }
+
+        state.markdown.sync(&state.project);
+        state
+    }
+
+    /// Location of an imported asset's file, if the store is available.
+    pub fn asset_path(&self, asset: &Asset) -> Option<PathBuf> {
+        self.store.as_ref().map(|s| s.assets_dir().join(&asset.file))
+    }
+
+    /// Id of `codex`'s entry waiting for delete confirmation.
+    pub fn pending_delete(&self, codex: Codex) -> Option<&str> {
+        self.pending_entry_delete
+            .as_ref()
+            .filter(|(c, _)| *c == codex)
+            .map(|(_, id)| id.as_str())
+    }
+
+    /// Text of an inline title field: the draft typed into it, else `saved`.
+    pub fn title_text<'a>(&'a self, field: &TitleField, saved: &'a str) -> &'a str {
+        match &self.title_draft {
+            Some(draft) if draft.field == *field => &draft.value,
+            _ => saved,
+        }
+    }
+
+    fn persist(&mut self) {
+        let Some(store) = self.store.as_mut().filter(|_| self.unsaved) else {
+            return;
+        };
+
+        // A failed save stays unsaved and is retried after the next message.
+        match store.save(&self.project) {
+            Ok(()) => self.unsaved = false,
+            Err(err) => {
+                let path = store.path().display();
+                eprintln!("{APP_ACRONYM}: could not save {path}: {err}");
+                self.storage_error =
+                    Some(format!("Could not save {path} ({err}). Recent changes are not on disk yet."));
+            }
+        }
+    }
+}
+
+pub fn update(state: &mut AppState, message: Message) -> Task<Message> {
+    let task = match message {
+        Message::SearchFocus => operation::focus(ui::SEARCH_INPUT),
+        Message::CloseWindow(id) => window::close(id),
+        _ => Task::none(),
+    };
+
+    if !matches!(message, Message::ForgeSceneEdit(_)) {
+        write_back_scene(state);
+    }
+    let typing = typed_title(state, &message);
+    if state.title_draft.as_ref().is_some_and(|d| Some(&d.field) != typing.as_ref()) {
+        commit_title(state);
+    }
+
+    let undoable = undo_label(state, &message);
+    let before = undoable.is_some().then(|| Snapshot::take(&state.project));
+
+    apply(state, message);
+
+    if let (Some((label, destructive)), Some(before)) = (undoable, before)
+        && let Some(command) = Command::between(label.clone(), &before, &Snapshot::take(&state.project))
+    {
+        state.undo.push(command);
+        state.toast = destructive.then_some(Toast {
+            text: label,
+            action: Message::Undo,
+        });
+    }
+
+    state.persist();
+    state.markdown.sync(&state.project);
+
+    task
+}
+
+fn apply(state: &mut AppState, message: Message) {
+    match message {
+        Message::Navigate(route) => {
+            state.pending_workspace_delete = None;
+            state.pending_universe_delete = None;
+            state.pending_forge_delete = None;
+            state.pending_board_delete = None;
+            state.pending_asset_delete = None;
+            state.pending_entry_delete = None;
+            state.pending_timeline_delete = None;
+            state.route = route;
+        }
+
+        Message::OpenActivity(target) => open_activity(state, target),
+
+        Message::Undo => {
+            let Some(label) = state.undo.undo(&mut state.project) else {
+                return;
+            };
+
+            state.unsaved = true;
+            after_undo(state);
+            state.toast = Some(Toast {
+                text: format!("Undone: {label}"),
+                action: Message::Redo,
+            });
+        }
+        Message::Redo => {
+            let Some(label) = state.undo.redo(&mut state.project) else {
+                return;
+            };
+
+            state.unsaved = true;
+            after_undo(state);
+            state.toast = Some(Toast {
+                text: format!("Redone: {label}"),
+                action: Message::Undo,
+            });
+        }
+        Message::DismissToast => state.toast = None,
+        Message::DismissStorageError => state.storage_error = None,
+        Message::TitleSubmit => {}
+
+        Message::SearchFocus => {}
+        Message::SearchQueryChanged(v) => state.search_query = v,
+        Message::SearchSubmit => {
+            let best = search(&state.project, &state.search_query)
+                .into_iter()
+                .flat_map(|(_, hits)| hits)
+                .next();
+
+            if let Some(hit) = best {
+                state.search_query.clear();
+                open_activity(state, hit.target);
+            }
+        }
+        Message::SearchSelect(target) => {
+            state.search_query.clear();
+            open_activity(state, target);
+        }
+        Message::SearchClose => state.search_query.clear(),
+
+        Message::Logout => {
+            // Offline app: route back to Overview for now
+            state.route = Route::Overview;
+        }
+        // Closed by the task `update` returns, after the project is saved.
+        Message::CloseWindow(_) => {}
+
+        // Workspace interactions
+        Message::WorkspaceNewNameChanged(v) => state.new_workspace_name = v,
+
+        Message::CreateWorkspace => {
+            if !new_workspace_errors(state).is_empty() {
+                return;
+            }
+
+            let name = state.new_workspace_name.trim().to_string();
+            let id = state.project.new_workspace_id(&name);
+            state.project.workspaces.push(Workspace { id: id.clone(), name });
+            state.unsaved = true;
+
+            state.new_workspace_name.clear();
+            switch_workspace(state, id);
+        }
+
+        Message::SwitchWorkspace(id) => switch_workspace(state, id),
+
+        Message::WorkspaceNameChanged(id, value) => {
+            state.title_draft = Some(TitleDraft {
+                field: TitleField::Workspace(id),
+                value,
+            });
+        }
+
+        Message::DeleteWorkspace(id) => state.pending_workspace_delete = Some(id),
+        Message::DeleteWorkspaceCancel => state.pending_workspace_delete = None,
+        Message::DeleteWorkspaceConfirm => {
+            let Some(id) = state.pending_workspace_delete.take() else {
+                return;
+            };
+
+            let was_active = state.project.active_workspace == id;
+            let removed = state.project.remove_workspace(&id);
+            state.unsaved = true;
+            // Commands may still hold the workspace's universes, which have nowhere to return to.
+            state.undo.clear();
+
+            if let Some(store) = state.store.as_ref() {
+                for asset in &removed {
+                    if let Err(err) = store.remove_file(&asset.file) {
+                        eprintln!("{APP_ACRONYM}: could not remove asset file {}: {err}", asset.file);
+                    }
+                }
+            }
+
+            if was_active {
+                let active = state.project.active_workspace.clone();
+                switch_workspace(state, active);
+            }
+        }
+
+        Message::UniverseNameChanged(v) => state.new_universe_name = v,
+        Message::UniverseDescChanged(v) => state.new_universe_desc = v,
+
+        Message::CreateUniverse => {
+            if !new_universe_errors(state).is_empty() {
+                return;
+            }
+
+            let name = state.new_universe_name.trim().to_string();
+
+            let id = state.project.new_universe_id(&name);
+            state.project.activity.record(ActivityTarget::Universe(id.clone()), ActivityKind::Created);
+
+            state.project.universes.push(Universe {
+                id,
+                workspace_id: state.project.active_workspace.clone(),
+                name,
+                description: state.new_universe_desc.trim().to_string(),
+                lore: String::new(),
+                archived: false,
+                danger_levels: Vec::new(),
+            });
+            state.unsaved = true;
+
+            state.new_universe_name.clear();
+            state.new_universe_desc.clear();
+        }
+
+        Message::ArchiveUniverse(id) => set_universe_archived(state, &id, true),
+        Message::RestoreUniverse(id) => set_universe_archived(state, &id, false),
+
+        Message::DeleteUniverse(id) => state.pending_universe_delete = Some(id),
+        Message::DeleteUniverseCancel => state.pending_universe_delete = None,
+        Message::DeleteUniverseConfirm => {
+            let Some(id) = state.pending_universe_delete.take() else {
+                return;
+            };
+
+            state.project.remove_universe(&id);
+            state.unsaved = true;
+        }
+
+        Message::OpenUniverse(id) => {
+            state.universe_editor = None;
+            state.route = Route::UniverseDetail { universe_id: id };
+        }
+        Message::BackToUniverses => {
+            state.universe_editor = None;
+            state.route = Route::UniverseList;
+        }
+
+        Message::UniverseEditorOpen(id) => {
+            state.universe_editor = state.project.universe(&id).map(UniverseEditor::from_universe);
+        }
+        Message::UniverseEditorNameChanged(v) => {
+            if let Some(ed) = state.universe_editor.as_mut() {
+                ed.name = v;
+            }
+        }
+        Message::UniverseEditorDescriptionEdit(action) => {
+            if let Some(ed) = state.universe_editor.as_mut() {
+                ed.description.perform(action);
+            }
+        }
+        Message::UniverseEditorLoreEdit(action) => {
+            if let Some(ed) = state.universe_editor.as_mut() {
+                ed.lore.perform(action);
+            }
+        }
+        Message::UniverseEditorSave => {
+            let Some(ed) = state.universe_editor.take() else {
+                return;
+            };
+
+            if !ed.errors(&state.project).is_empty() {
+                state.universe_editor = Some(ed);
+                return;
+            }
+
+            if let Some(u) = state.project.universe_mut(&ed.id) {
+                u.name = ed.name.trim().to_string();
+                u.description = ed.description.text().trim().to_string();
+                u.lore = ed.lore.text().trim().to_string();
+                state.project.activity.record(ActivityTarget::Universe(ed.id), ActivityKind::Edited);
+                state.unsaved = true;
+            }
+        }
+        Message::UniverseEditorCancel => state.universe_editor = None,
+
+        Message::OpenLink(url) => open_link(&url),
+
+        Message::OpenCodex(codex, id) => {
+            forms::ops(codex).close(state);
+            if codex == Codex::Creature {
+                state.template_editor = None;
+            }
+            state.pending_entry_delete = None;
+            state.route = Route::Codex { codex, universe_id: id };
+        }
+        Message::OpenTimeline(id) => {
+            state.timeline_editor = None;
+            state.pending_timeline_delete = None;
+            state.route = Route::Timeline { universe_id: id };
+        }
+        Message::BackToUniverse(id) => {
+            close_editors(state);
+            state.template_editor = None;
+            state.timeline_editor = None;
+            state.pending_timeline_delete = None;
+            state.route = Route::UniverseDetail { universe_id: id };
+        }
+
+        // Codex interactions
+        Message::OpenEntry(Codex::Creature, id) => {
+            if state.project.creature(&id).is_none() {
+                return;
+            }
+
+            // An editor for another creature would otherwise follow onto this sheet.
+            if state.creature_editor.as_ref().and_then(|ed| ed.id.as_deref()) != Some(id.as_str()) {
+                state.creature_editor = None;
+            }
+            state.template_editor = None;
+            apply(state, Message::Navigate(Route::CreatureDetail { creature_id: id }));
+        }
+        Message::OpenEntry(codex, id) => {
+            let Some(universe_id) = state.project.find_entry(codex, &id).map(|e| e.universe_id().to_string()) else {
+                return;
+            };
+
+            apply(state, Message::OpenCodex(codex, universe_id));
+            forms::ops(codex).open_edit(state, &id);
+        }
+        Message::EditorOpenCreate(codex, universe_id) => forms::ops(codex).open_create(state, universe_id),
+        Message::EditorOpenEdit(codex, id) => forms::ops(codex).open_edit(state, &id),
+        Message::EditorCancel(codex) => forms::ops(codex).close(state),
+        Message::EditorSave(codex) => forms::ops(codex).save(state),
+        Message::EditorLineChanged(codex, field, v) => forms::ops(codex).line_changed(state, field, v),
+        Message::EditorTextEdit(codex, field, action) => forms::ops(codex).text_edit(state, field, action),
+
+        Message::ArchiveEntry(codex, id) => forms::ops(codex).set_archived(state, &id, true),
+        Message::RestoreEntry(codex, id) => forms::ops(codex).set_archived(state, &id, false),
+
+        Message::DeleteEntry(codex, id) => state.pending_entry_delete = Some((codex, id)),
+        Message::DeleteEntryCancel => state.pending_entry_delete = None,
+        Message::DeleteEntryConfirm => {
+            let Some((codex, id)) = state.pending_entry_delete.take() else {
+                return;
+            };
+
+            let universe_id = state.project.find_entry(codex, &id).map(|e| e.universe_id().to_string());
+            state.project.remove_entry(codex, &id);
+            state.unsaved = true;
+
+            // Deleting a creature from its own sheet falls back to the bestiary.
+            if let (Route::CreatureDetail { creature_id }, Some(universe_id)) = (&state.route, universe_id)
+                && *creature_id == id
+            {
+                state.route = Route::Codex { codex, universe_id };
+            }
+
+            // Open editors drop the entry, or their links to it.
+            for c in Codex::ALL {
+                forms::ops(c).forget(state, codex, &id);
+            }
+        }
+
+        // Bestiary interactions
+        Message::BestiaryDangerFilterChanged(danger) => {
+            if let Some(f) = current_bestiary_filter_mut(state) {
+                f.danger = danger;
+            }
+        }
+        Message::BestiaryTagFilterChanged(tag) => {
+            if let Some(f) = current_bestiary_filter_mut(state) {
+                f.tag = tag;
+            }
+        }
+        Message::BestiaryHabitatFilterChanged(habitat) => {
+            if let Some(f) = current_bestiary_filter_mut(state) {
+                f.habitat = habitat;
+            }
+        }
+        Message::BestiarySortChanged(sort) => {
+            if let Some(f) = current_bestiary_filter_mut(state) {
+                f.sort = sort;
+            }
+        }
+        Message::BestiaryClearFilters => {
+            if let Some(f) = current_bestiary_filter_mut(state) {
+                // The sort is a preference, not a filter: keep it.
+                *f = BestiaryFilter {
+                    sort: f.sort,
+                    ..BestiaryFilter::default()
+                };
+            }
+        }
+
+        Message::CreatureEditorTransfer { copy } => {
+            let Some(editor) = state.creature_editor.take() else {
+                return;
+            };
+
+            let target = editor
+                .transfer_target
+                .clone()
+                .filter(|id| state.project.universe(id).is_some());
+
+            let Some(target) = target.filter(|_| editor.can_save(&state.project)) else {
+                state.creature_editor = Some(editor);
+                return;
+            };
+
+            // A move keeps the id, so the creature itself doesn't count as a clash.
+            let keeps = if copy { None } else { editor.id.as_deref() };
+            if let Some(err) = validation::duplicate_creature(&state.project, keeps, &target, &editor.name) {
+                state.creature_editor = Some(CreatureEditor {
+                    transfer_error: Some(err),
+                    ..editor
+                });
+                return;
+            }
+
+            let mut moved = editor.build();
+            moved.universe_id = target;
+            // Locations belong to the universe being left behind.
+            moved.habitat_locations.clear();
+
+            // Copy: the original keeps its saved values, only the copy lands in the target.
+            if copy {
+                moved.id.clear();
+            }
+
+            CreatureEditor::saving(state, &moved);
+            forms::upsert(state, moved);
+        }
+
+        // Locations interactions
+        Message::LocationEditorAddInside(parent_id) => {
+            if let Some(parent) = state.project.location(&parent_id) {
+                state.location_editor = Some(LocationEditor::create_new(parent.universe_id.clone(), Some(parent)));
+            }
+        }
+        Message::LocationEditorKindChanged(v) => {
+            if let Some(ed) = state.location_editor.as_mut() {
+                ed.kind = v;
+            }
+        }
+        Message::LocationEditorParentChanged(v) => {
+            if let Some(ed) = state.location_editor.as_mut() {
+                ed.parent_id = v;
+            }
+        }
+        // Characters interactions
+        Message::CharacterEditorAddMembership(faction_id) => {
+            if let Some(ed) = state.character_editor.as_mut()
+                && !ed.memberships.iter().any(|m| m.faction_id == faction_id)
+            {
+                ed.memberships.push(Membership {
+                    faction_id,
+                    rank: String::new(),
+                });
+            }
+        }
+        Message::CharacterEditorRankChanged(index, rank) => {
+            if let Some(m) = state.character_editor.as_mut().and_then(|ed| ed.memberships.get_mut(index)) {
+                m.rank = rank;
+            }
+        }
+        Message::CharacterEditorRemoveMembership(index) => {
+            if let Some(ed) = state.character_editor.as_mut()
+                && index < ed.memberships.len()
+            {
+                ed.memberships.remove(index);
+            }
+        }
+        Message::CharacterEditorAddRelationship(character_id) => {
+            if let Some(ed) = state.character_editor.as_mut() {
+                ed.relationships.push(Relationship {
+                    character_id,
+                    kind: String::new(),
+                });
+            }
+        }
+        Message::CharacterEditorRelationshipKindChanged(index, kind) => {
+            if let Some(r) = state.character_editor.as_mut().and_then(|ed| ed.relationships.get_mut(index)) {
+                r.kind = kind;
+            }
+        }
+        Message::CharacterEditorRemoveRelationship(index) => {
+            if let Some(ed) = state.character_editor.as_mut()
+                && index < ed.relationships.len()
+            {
+                ed.relationships.remove(index);
+            }
+        }
+
+        // Factions interactions
+        Message::FactionEditorAddAllegiance(faction_id) => {
+            if let Some(ed) = state.faction_editor.as_mut()
+                && !ed.allegiances.iter().any(|a| a.faction_id == faction_id)
+            {
+                ed.allegiances.push(Allegiance {
+                    faction_id,
+                    stance: Stance::Neutral,
+                });
+            }
+        }
+        Message::FactionEditorStanceChanged(index, stance) => {
+            if let Some(a) = state.faction_editor.as_mut().and_then(|ed| ed.allegiances.get_mut(index)) {
+                a.stance = stance;
+            }
+        }
+        Message::FactionEditorRemoveAllegiance(index) => {
+            if let Some(ed) = state.faction_editor.as_mut()
+                && index < ed.allegiances.len()
+            {
+                ed.allegiances.remove(index);
+            }
+        }
+
+        // Runomicon interactions
+        Message::RunomiconCategoryChanged(category) => {
+            if let Route::Codex { codex: Codex::Term, universe_id } = &state.route {
+                match category {
+                    Some(c) => state.runomicon_categories.insert(universe_id.clone(), c),
+                    None => state.runomicon_categories.remove(universe_id),
+                };
+            }
+        }
+        Message::TermEditorAddSeeAlso(id) => {
+            if let Some(ed) = state.term_editor.as_mut()
+                && ed.id.as_deref() != Some(id.as_str())
+                && !ed.see_also.contains(&id)
+            {
+                ed.see_also.push(id);
+            }
+        }
+        Message::TermEditorRemoveSeeAlso(index) => {
+            if let Some(ed) = state.term_editor.as_mut()
+                && index < ed.see_also.len()
+            {
+                ed.see_also.remove(index);
+            }
+        }
+        Message::TermEditorGlyphChanged(asset_id) => {
+            if let Some(ed) = state.term_editor.as_mut() {
+                ed.glyph_asset_id = asset_id;
+            }
+        }
+
+        // Artifacts interactions
+        Message::ArtifactEditorRarityChanged(rarity) => {
+            if let Some(ed) = state.artifact_editor.as_mut() {
+                ed.rarity = rarity;
+            }
+        }
+        Message::ArtifactEditorHolderChanged(holder) => {
+            if let Some(ed) = state.artifact_editor.as_mut() {
+                ed.holder = holder;
+            }
+        }
+        Message::ArtifactEditorAddProperty => {
+            if let Some(ed) = state.artifact_editor.as_mut() {
+                ed.properties.push(ArtifactProperty {
+                    name: String::new(),
+                    value: String::new(),
+                });
+            }
+        }
+        Message::ArtifactEditorPropertyNameChanged(index, v) => {
+            if let Some(p) = state.artifact_editor.as_mut().and_then(|ed| ed.properties.get_mut(index)) {
+                p.name = v;
+            }
+        }
+        Message::ArtifactEditorPropertyValueChanged(index, v) => {
+            if let Some(p) = state.artifact_editor.as_mut().and_then(|ed| ed.properties.get_mut(index)) {
+                p.value = v;
+            }
+        }
+        Message::ArtifactEditorRemoveProperty(index) => {
+            if let Some(ed) = state.artifact_editor.as_mut()
+                && index < ed.properties.len()
+            {
+                ed.properties.remove(index);
+            }
+        }
+        // Timeline interactions
+        Message::TimelineEventEditorOpenCreate(universe_id) => {
+            state.timeline_editor = Some(TimelineEditor::Event(EventEditor::create_new(universe_id)));
+        }
+        Message::TimelineEventEdit(id) => {
+            if let Some(e) = state.project.events.iter().find(|e| e.id == id) {
+                state.timeline_editor = Some(TimelineEditor::Event(EventEditor::from_event(e)));
+            }
+        }
+        Message::TimelineEraEditorOpenCreate(universe_id) => {
+            state.timeline_editor = Some(TimelineEditor::Era(EraEditor::create_new(universe_id)));
+        }
+        Message::TimelineEraEdit(id) => {
+            if let Some(e) = state.project.eras.iter().find(|e| e.id == id) {
+                state.timeline_editor = Some(TimelineEditor::Era(EraEditor::from_era(e)));
+            }
+        }
+        Message::TimelineCalendarEdit(universe_id) => {
+            let calendar = state.project.calendar(&universe_id);
+            state.timeline_editor = Some(TimelineEditor::Calendar(CalendarEditor::from_calendar(&calendar)));
+        }
+
+        Message::TimelineEditorCancel => state.timeline_editor = None,
+
+        Message::TimelineEditorSave => {
+            let Some(editor) = state.timeline_editor.take() else {
+                return;
+            };
+
+            match &editor {
+                TimelineEditor::Event(ed) => {
+                    let calendar = state.project.calendar(&ed.universe_id);
+                    let Some(mut event) = ed.to_event(&calendar) else {
+                        state.timeline_editor = Some(editor);
+                        return;
+                    };
+
+                    match state.project.events.iter_mut().find(|e| e.id == event.id) {
+                        Some(slot) => *slot = event,
+                        None => {
+                            event.id = state.project.new_event_id(&event.title);
+                            state.project.events.push(event);
+                        }
+                    }
+                }
+                TimelineEditor::Era(ed) => {
+                    let Some(mut era) = ed.to_era() else {
+                        state.timeline_editor = Some(editor);
+                        return;
+                    };
+
+                    match state.project.eras.iter_mut().find(|e| e.id == era.id) {
+                        Some(slot) => *slot = era,
+                        None => {
+                            era.id = state.project.new_era_id(&era.name);
+                            state.project.eras.push(era);
+                        }
+                    }
+                }
+                TimelineEditor::Calendar(ed) => {
+                    let Some(calendar) = ed.to_calendar() else {
+                        state.timeline_editor = Some(editor);
+                        return;
+                    };
+
+                    state.project.set_calendar(calendar);
+                }
+            }
+
+            // Timeline entries have no page of their own; they count as edits of the universe.
+            let universe_id = match editor {
+                TimelineEditor::Event(ed) => ed.universe_id,
+                TimelineEditor::Era(ed) => ed.universe_id,
+                TimelineEditor::Calendar(ed) => ed.universe_id,
+            };
+            state.project.activity.record(ActivityTarget::Universe(universe_id), ActivityKind::Edited);
+            state.unsaved = true;
+        }
+
+        Message::TimelineEventTitleChanged(v) => {
+            if let Some(TimelineEditor::Event(ed)) = state.timeline_editor.as_mut() {
+                ed.title = v;
+            }
+        }
+        Message::TimelineEventDescriptionChanged(v) => {
+            if let Some(TimelineEditor::Event(ed)) = state.timeline_editor.as_mut() {
+                ed.description = v;
+            }
+        }
+        Message::TimelineEventYearChanged(v) => {
+            if let Some(TimelineEditor::Event(ed)) = state.timeline_editor.as_mut() {
+                ed.year = v;
+            }
+        }
+        Message::TimelineEventMonthChanged(v) => {
+            if let Some(TimelineEditor::Event(ed)) = state.timeline_editor.as_mut() {
+                ed.month = v;
+            }
+        }
+        Message::TimelineEventDayChanged(v) => {
+            if let Some(TimelineEditor::Event(ed)) = state.timeline_editor.as_mut() {
+                ed.day = v;
+            }
+        }
+        Message::TimelineEraNameChanged(v) => {
+            if let Some(TimelineEditor::Era(ed)) = state.timeline_editor.as_mut() {
+                ed.name = v;
+            }
+        }
+        Message::TimelineEraDescriptionChanged(v) => {
+            if let Some(TimelineEditor::Era(ed)) = state.timeline_editor.as_mut() {
+                ed.description = v;
+            }
+        }
+        Message::TimelineEraStartChanged(v) => {
+            if let Some(TimelineEditor::Era(ed)) = state.timeline_editor.as_mut() {
+                ed.start_year = v;
+            }
+        }
+        Message::TimelineEraEndChanged(v) => {
+            if let Some(TimelineEditor::Era(ed)) = state.timeline_editor.as_mut() {
+                ed.end_year = v;
+            }
+        }
+        Message::TimelineCalendarMonthsChanged(v) => {
+            if let Some(TimelineEditor::Calendar(ed)) = state.timeline_editor.as_mut() {
+                ed.months = v;
+            }
+        }
+        Message::TimelineCalendarDaysChanged(v) => {
+            if let Some(TimelineEditor::Calendar(ed)) = state.timeline_editor.as_mut() {
+                ed.days_per_month = v;
+            }
+        }
+        Message::TimelineCalendarSuffixChanged(v) => {
+            if let Some(TimelineEditor::Calendar(ed)) = state.timeline_editor.as_mut() {
+                ed.year_suffix = v;
+            }
+        }
+
+        Message::DeleteTimelineEntry(entry) => state.pending_timeline_delete = Some(entry),
+        Message::DeleteTimelineCancel => state.pending_timeline_delete = None,
+        Message::DeleteTimelineConfirm => {
+            let Some(entry) = state.pending_timeline_delete.take() else {
+                return;
+            };
+
+            // Deleting an era keeps its events; they simply fall outside any era.
+            match &entry {
+                TimelineEntry::Event(id) => state.project.events.retain(|e| &e.id != id),
+                TimelineEntry::Era(id) => state.project.eras.retain(|e| &e.id != id),
+            }
+            state.unsaved = true;
+
+            let editing = match (&state.timeline_editor, &entry) {
+                (Some(TimelineEditor::Event(ed)), TimelineEntry::Event(id)) => ed.id.as_ref() == Some(id),
+                (Some(TimelineEditor::Era(ed)), TimelineEntry::Era(id)) => ed.id.as_ref() == Some(id),
+                _ => false,
+            };
+
+            if editing {
+                state.timeline_editor = None;
+            }
+        }
+
+        // Forge interactions
+        Message::ForgeNewTitleChanged(v) => state.new_manuscript_title = v,
+        Message::ForgeNewKindChanged(kind) => state.new_manuscript_kind = kind,
+
+        Message::CreateManuscript => {
+            let title = state.new_manuscript_title.trim().to_string();
+            if title.is_empty() {
+                return;
+            }
+
+            let id = state.project.new_manuscript_id(&title);
+            state.project.activity.record(ActivityTarget::Manuscript(id.clone()), ActivityKind::Created);
+
+            state.project.manuscripts.push(Manuscript {
+                id: id.clone(),
+                workspace_id: state.project.active_workspace.clone(),
+                title,
+                kind: state.new_manuscript_kind,
+                chapters: Vec::new(),
+            });
+            state.unsaved = true;
+
+            state.new_manuscript_title.clear();
+            open_manuscript(state, id);
+        }
+
+        Message::OpenManuscript(id) => open_manuscript(state, id),
+
+        Message::ForgeAddChapter => {
+            edit_manuscript(state, |m| {
+                let title = format!("Chapter {}", m.chapters.len() + 1);
+                let id = m.new_chapter_id(&title);
+                m.chapters.push(Chapter {
+                    id,
+                    title,
+                    scenes: Vec::new(),
+                });
+            });
+        }
+        Message::ForgeChapterTitleChanged(chapter_id, value) => {
+            state.title_draft = Some(TitleDraft {
+                field: TitleField::Chapter(chapter_id),
+                value,
+            });
+        }
+        Message::ForgeMoveChapter { chapter_id, up } => {
+            edit_manuscript(state, |m| m.move_chapter(&chapter_id, up));
+        }
+
+        Message::ForgeAddScene(chapter_id) => {
+            let added = edit_manuscript(state, |m| {
+                let count = m.chapter_mut(&chapter_id)?.scenes.len();
+                let title = format!("Scene {}", count + 1);
+                let id = m.new_scene_id(&title);
+
+                m.chapter_mut(&chapter_id)?.scenes.push(Scene {
+                    id: id.clone(),
+                    title,
+                    body: String::new(),
+                });
+                Some(id)
+            });
+
+            if let Some(id) = added.flatten() {
+                open_scene(state, id);
+            }
+        }
+        Message::ForgeOpenScene(scene_id) => open_scene(state, scene_id),
+        Message::ForgeMoveScene { scene_id, up } => {
+            edit_manuscript(state, |m| m.move_scene(&scene_id, up));
+        }
+
+        Message::ForgeSceneTitleChanged(value) => {
+            if let Some(ed) = &state.scene_editor {
+                state.title_draft = Some(TitleDraft {
+                    field: TitleField::Scene(ed.scene_id.clone()),
+                    value,
+                });
+            }
+        }
+        Message::ForgeSceneEdit(action) => {
+            if let Some(ed) = state.scene_editor.as_mut() {
+                ed.unsaved |= action.is_edit();
+                ed.content.perform(action);
+            }
+        }
+        // `update` writes the scene back before every message but typing.
+        Message::ForgeSceneSave => {}
+
+        Message::DeleteForgeEntry(entry) => state.pending_forge_delete = Some(entry),
+        Message::DeleteForgeCancel => state.pending_forge_delete = None,
+        Message::DeleteForgeConfirm => {
+            let Some(entry) = state.pending_forge_delete.take() else {
+                return;
+            };
+
+            match entry {
+                ForgeEntry::Manuscript(id) => {
+                    state.project.manuscripts.retain(|m| m.id != id);
+                    state.unsaved = true;
+                    state.scene_editor = None;
+                    state.route = Route::Forge;
+                }
+                ForgeEntry::Chapter(id) => {
+                    edit_manuscript(state, |m| m.remove_chapter(&id));
+                }
+                ForgeEntry::Scene(id) => {
+                    edit_manuscript(state, |m| m.remove_scene(&id));
+                }
+            }
+
+            // Drop the editor if its scene went away with the deleted entry.
+            let scene_gone = state.scene_editor.as_ref().is_some_and(|ed| {
+                current_manuscript(state).and_then(|m| m.scene(&ed.scene_id)).is_none()
+            });
+
+            if scene_gone {
+                state.scene_editor = None;
+            }
+        }
+
+        // PM interactions
+        Message::PmNewBoardNameChanged(v) => state.new_board_name = v,
+
+        Message::CreateBoard => {
+            let name = state.new_board_name.trim().to_string();
+            if name.is_empty() {
+                return;
+            }
+
+            let id = state.project.new_board_id(&name);
+            state.project.activity.record(ActivityTarget::Board(id.clone()), ActivityKind::Created);
+            let workspace_id = state.project.active_workspace.clone();
+            state.project.boards.push(Board::new(id.clone(), workspace_id, name));
+            state.unsaved = true;
+
+            state.new_board_name.clear();
+            open_board(state, id);
+        }
+
+        Message::OpenBoard(id) => open_board(state, id),
+
+        Message::BoardNameChanged(value) => {
+            state.title_draft = Some(TitleDraft {
+                field: TitleField::Board,
+                value,
+            });
+        }
+        Message::BoardUniverseChanged(universe_id) => {
+            edit_board(state, |b| b.universe_id = universe_id);
+        }
+        Message::BoardAddColumn => {
+            edit_board(state, |b| {
+                let title = "New column".to_string();
+                let id = b.new_column_id(&title);
+                b.columns.push(BoardColumn {
+                    id,
+                    title,
+                    cards: Vec::new(),
+                });
+            });
+        }
+        Message::BoardColumnTitleChanged(column_id, value) => {
+            state.title_draft = Some(TitleDraft {
+                field: TitleField::Column(column_id),
+                value,
+            });
+        }
+        Message::BoardMoveColumn { column_id, left } => {
+            edit_board(state, |b| b.move_column(&column_id, left));
+        }
+        Message::MoveCard { card_id, direction } => {
+            edit_board(state, |b| b.move_card(&card_id, direction));
+        }
+
+        Message::CardEditorOpenCreate(column_id) => {
+            state.card_editor = Some(CardEditor::create_new(column_id));
+        }
+        Message::CardEdit(card_id) => {
+            let editor = current_board(state)
+                .and_then(|b| b.card(&card_id))
+                .map(|(column_id, c)| CardEditor::from_card(column_id, c));
+
+            if editor.is_some() {
+                state.card_editor = editor;
+            }
+        }
+        Message::CardEditorCancel => state.card_editor = None,
+        Message::CardEditorSave => {
+            let Some(editor) = state.card_editor.take() else {
+                return;
+            };
+
+            let Some(mut card) = editor.to_card() else {
+                state.card_editor = Some(editor);
+                return;
+            };
+
+            edit_board(state, |b| {
+                if let Some(slot) = b.card_mut(&card.id) {
+                    *slot = card;
+                    return;
+                }
+
+                card.id = b.new_card_id(&card.title);
+                if let Some(col) = b.column_mut(&editor.column_id) {
+                    col.cards.push(card);
+                }
+            });
+        }
+
+        Message::CardEditorTitleChanged(v) => {
+            if let Some(ed) = state.card_editor.as_mut() {
+                ed.title = v;
+            }
+        }
+        Message::CardEditorDescriptionChanged(v) => {
+            if let Some(ed) = state.card_editor.as_mut() {
+                ed.description = v;
+            }
+        }
+        Message::CardEditorAssigneeChanged(v) => {
+            if let Some(ed) = state.card_editor.as_mut() {
+                ed.assignee = v;
+            }
+        }
+        Message::CardEditorDueChanged(v) => {
+            if let Some(ed) = state.card_editor.as_mut() {
+                ed.due = v;
+            }
+        }
+        Message::CardEditorLabelsChanged(v) => {
+            if let Some(ed) = state.card_editor.as_mut() {
+                ed.labels = v;
+            }
+        }
+
+        Message::DeleteBoardEntry(entry) => state.pending_board_delete = Some(entry),
+        Message::DeleteBoardCancel => state.pending_board_delete = None,
+        Message::DeleteBoardConfirm => {
+            let Some(entry) = state.pending_board_delete.take() else {
+                return;
+            };
+
+            match entry {
+                BoardEntry::Board(id) => {
+                    state.project.boards.retain(|b| b.id != id);
+                    state.unsaved = true;
+                    state.card_editor = None;
+                    state.route = Route::PmTools;
+                }
+                BoardEntry::Column(id) => {
+                    edit_board(state, |b| b.remove_column(&id));
+                }
+                BoardEntry::Card(id) => {
+                    edit_board(state, |b| b.remove_card(&id));
+                }
+            }
+
+            // Drop the editor if its card (or the column it was being added to) went away.
+            let orphaned = state.card_editor.as_ref().is_some_and(|ed| {
+                let Some(b) = current_board(state) else {
+                    return true;
+                };
+
+                match &ed.id {
+                    Some(id) => b.card(id).is_none(),
+                    None => !b.columns.iter().any(|c| c.id == ed.column_id),
+                }
+            });
+
+            if orphaned {
+                state.card_editor = None;
+            }
+        }
+
+        // Assets interactions
+        Message::AssetImportPathChanged(v) => {
+            state.asset_import_path = v;
+            state.asset_import_error = None;
+        }
+        Message::ImportAsset => {
+            // Paths pasted from a file manager often come quoted.
+            let raw = state.asset_import_path.trim().trim_matches(['"', '\'']).to_string();
+            if raw.is_empty() {
+                return;
+            }
+
+            match import_asset(state, Path::new(&raw)) {
+                Ok(()) => {
+                    state.asset_import_path.clear();
+                    state.asset_import_error = None;
+                }
+                Err(err) => state.asset_import_error = Some(err),
+            }
+        }
+        Message::AssetTagFilterChanged(v) => state.asset_tag_filter = v,
+
+        Message::AssetEdit(id) => {
+            state.asset_editor = state.project.asset(&id).map(AssetEditor::from_asset);
+        }
+        Message::AssetEditorCancel => state.asset_editor = None,
+        Message::AssetEditorSave => {
+            let Some(editor) = state.asset_editor.take() else {
+                return;
+            };
+
+            let tags = editor.parsed_tags();
+            if let Some(a) = state.project.asset_mut(&editor.id) {
+                a.tags = tags;
+                a.universe_id = editor.universe_id;
+                a.creature_id = editor.creature_id;
+                state.project.activity.record(ActivityTarget::Asset(editor.id), ActivityKind::Edited);
+                state.unsaved = true;
+            }
+        }
+        Message::AssetEditorTagsChanged(v) => {
+            if let Some(ed) = state.asset_editor.as_mut() {
+                ed.tags = v;
+            }
+        }
+        Message::AssetEditorUniverseChanged(universe_id) => {
+            if let Some(ed) = state.asset_editor.as_mut() {
+                // A creature only makes sense inside its own universe.
+                if ed.universe_id != universe_id {
+                    ed.creature_id = None;
+                }
+                ed.universe_id = universe_id;
+            }
+        }
+        Message::AssetEditorCreatureChanged(creature_id) => {
+            if let Some(ed) = state.asset_editor.as_mut() {
+                ed.creature_id = creature_id;
+            }
+        }
+
+        Message::DeleteAsset(id) => state.pending_asset_delete = Some(id),
+        Message::DeleteAssetCancel => state.pending_asset_delete = None,
+        Message::DeleteAssetConfirm => {
+            let Some(id) = state.pending_asset_delete.take() else {
+                return;
+            };
+
+            let Some(pos) = state.project.assets.iter().position(|a| a.id == id) else {
+                return;
+            };
+
+            let asset = state.project.assets.remove(pos);
+            for t in state.project.terms.iter_mut().filter(|t| t.glyph_asset_id.as_deref() == Some(id.as_str())) {
+                t.glyph_asset_id = None;
+            }
+            state.unsaved = true;
+
+            if let Some(store) = state.store.as_ref()
+                && let Err(err) = store.remove_file(&asset.file)
+            {
+                eprintln!("{APP_ACRONYM}: could not remove asset file {}: {err}", asset.file);
+            }
+
+            if state.asset_editor.as_ref().is_some_and(|ed| ed.id == id) {
+                state.asset_editor = None;
+            }
+        }
+
+        Message::CreatureEditorTagInputChanged(v) => {
+            if let Some(ed) = state.creature_editor.as_mut() {
+                ed.tag_input = v;
+            }
+        }
+        Message::CreatureEditorTagSubmit => {
+            if let Some(ed) = state.creature_editor.as_mut() {
+                let input = std::mem::take(&mut ed.tag_input);
+                ed.add_tags(&input);
+            }
+        }
+        Message::CreatureEditorAddTag(tag) => {
+            if let Some(ed) = state.creature_editor.as_mut() {
+                ed.add_tags(&tag);
+                ed.tag_input.clear();
+            }
+        }
+        Message::CreatureEditorRemoveTag(tag) => {
+            if let Some(ed) = state.creature_editor.as_mut() {
+                ed.tags.retain(|t| *t != tag);
+            }
+        }
+        Message::CreatureEditorAddHabitatLocation(id) => {
+            if let Some(ed) = state.creature_editor.as_mut()
+                && !ed.habitat_locations.contains(&id)
+            {
+                ed.habitat_locations.push(id);
+            }
+        }
+        Message::CreatureEditorRemoveHabitatLocation(id) => {
+            if let Some(ed) = state.creature_editor.as_mut() {
+                ed.habitat_locations.retain(|l| *l != id);
+            }
+        }
+        Message::CreatureEditorDangerChanged(v) => {
+            if let Some(ed) = state.creature_editor.as_mut() {
+                ed.danger = v;
+            }
+        }
+        Message::CreatureEditorNewDangerLevelChanged(v) => {
+            if let Some(ed) = state.creature_editor.as_mut() {
+                ed.new_danger_level = v;
+            }
+        }
+        Message::CreatureEditorAddDangerLevel => {
+            let Some(ed) = state.creature_editor.as_mut() else {
+                return;
+            };
+
+            let level = DangerLevel::from(std::mem::take(&mut ed.new_danger_level));
+            if level == DangerLevel::Unrated {
+                return;
+            }
+
+            ed.danger = level.clone();
+            let universe_id = ed.universe_id.clone();
+            add_danger_level(state, &universe_id, &level);
+        }
+        Message::CreatureEditorTransferTargetChanged(v) => {
+            if let Some(ed) = state.creature_editor.as_mut() {
+                ed.transfer_error = None;
+                ed.transfer_target = Some(v);
+            }
+        }
+        Message::CreatureEditorFieldChanged(id, v) => {
+            if let Some(ed) = state.creature_editor.as_mut() {
+                ed.field_inputs.insert(id, v);
+            }
+        }
+        Message::CreatureEditorLongFieldEdit(id, action) => {
+            if let Some(content) = state.creature_editor.as_mut().and_then(|ed| ed.long_fields.get_mut(&id)) {
+                content.perform(action);
+            }
+        }
+
+        Message::TemplateEditorOpen(universe_id) => {
+            let fields = state.project.creature_fields(&universe_id);
+            state.template_editor = Some(TemplateEditor::from_fields(universe_id, fields));
+            state.creature_editor = None;
+        }
+        Message::TemplateEditorCancel => {
+            state.template_editor = None;
+        }
+        Message::TemplateEditorSave => {
+            let Some(template) = state.template_editor.as_ref().and_then(TemplateEditor::to_template) else {
+                return;
+            };
+
+            state
+                .project
+                .activity
+                .record(ActivityTarget::Universe(template.universe_id.clone()), ActivityKind::Edited);
+            state.project.set_creature_template(template);
+            state.unsaved = true;
+            state.template_editor = None;
+        }
+        Message::TemplateFieldAdd => {
+            if let Some(ed) = state.template_editor.as_mut() {
+                ed.fields.push(FieldDraft {
+                    id: None,
+                    label: String::new(),
+                    kind: FieldKind::Text,
+                    options: String::new(),
+                    on_card: false,
+                });
+            }
+        }
+        Message::TemplateFieldLabelChanged(index, v) => {
+            if let Some(f) = state.template_editor.as_mut().and_then(|ed| ed.fields.get_mut(index)) {
+                f.label = v;
+            }
+        }
+        Message::TemplateFieldKindChanged(index, kind) => {
+            if let Some(f) = state.template_editor.as_mut().and_then(|ed| ed.fields.get_mut(index)) {
+                f.kind = kind;
+            }
+        }
+        Message::TemplateFieldOptionsChanged(index, v) => {
+            if let Some(f) = state.template_editor.as_mut().and_then(|ed| ed.fields.get_mut(index)) {
+                f.options = v;
+            }
+        }
+        Message::TemplateFieldOnCardToggled(index, on_card) => {
+            if let Some(f) = state.template_editor.as_mut().and_then(|ed| ed.fields.get_mut(index)) {
+                f.on_card = on_card;
+            }
+        }
+        Message::TemplateFieldMove { index, up } => {
+            if let Some(ed) = state.template_editor.as_mut() {
+                let other = if up { index.checked_sub(1) } else { Some(index + 1) };
+                if let Some(other) = other.filter(|&o| o < ed.fields.len() && index < ed.fields.len()) {
+                    ed.fields.swap(index, other);
+                }
+            }
+        }
+        Message::TemplateFieldRemove(index) => {
+            if let Some(ed) = state.template_editor.as_mut()
+                && index < ed.fields.len()
+            {
+                ed.fields.remove(index);
+            }
+        }
+    }
+}
+
+pub fn current_manuscript(state: &AppState) -> Option<&Manuscript> {
+    match &state.route {
+        Route::Manuscript { manuscript_id } => state.project.manuscript(manuscript_id),
+        _ => None,
+    }
+}
+
+/// Runs `edit` on the open manuscript and records the edit if it changed anything.
+fn edit_manuscript<R>(state: &mut AppState, edit: impl FnOnce(&mut Manuscript) -> R) -> Option<R> {
+    let Route::Manuscript { manuscript_id } = &state.route else {
+        return None;
+    };
+
+    let manuscript_id = manuscript_id.clone();
+    let m = state.project.manuscript_mut(&manuscript_id)?;
+    let before = m.clone();
+    let out = edit(m);
+
+    if *m != before {
+        state
+            .project
+            .activity
+            .record(ActivityTarget::Manuscript(manuscript_id), ActivityKind::Edited);
+        state.unsaved = true;
+    }
+    Some(out)
+}
+
+/// Sets the `field` of `scene_id` to `text`, recording a scene edit if that changed it.
+fn set_scene_text(state: &mut AppState, scene_id: &str, field: fn(&mut Scene) -> &mut String, text: String) {
+    let changed = edit_manuscript(state, |m| {
+        let slot = field(m.scene_mut(scene_id)?);
+        let changed = *slot != text;
+        *slot = text;
+        Some(changed)
+    });
+
+    if changed.flatten() == Some(true) {
+        record_scene_edit(state, scene_id.to_string());
+    }
+}
+
+/// Copies what was typed in the scene editor into its scene.
+fn write_back_scene(state: &mut AppState) {
+    let Some(ed) = state.scene_editor.as_mut().filter(|ed| ed.unsaved) else {
+        return;
+    };
+
+    ed.unsaved = false;
+    let scene_id = ed.scene_id.clone();
+    let body = ed.content.text();
+    set_scene_text(state, &scene_id, |s| &mut s.body, body);
+}
+
+/// The inline title field `message` types into, if any.
+fn typed_title(state: &AppState, message: &Message) -> Option<TitleField> {
+    match message {
+        Message::WorkspaceNameChanged(id, _) => Some(TitleField::Workspace(id.clone())),
+        Message::ForgeChapterTitleChanged(id, _) => Some(TitleField::Chapter(id.clone())),
+        Message::ForgeSceneTitleChanged(_) => {
+            state.scene_editor.as_ref().map(|ed| TitleField::Scene(ed.scene_id.clone()))
+        }
+        Message::BoardNameChanged(_) => Some(TitleField::Board),
+        Message::BoardColumnTitleChanged(id, _) => Some(TitleField::Column(id.clone())),
+        _ => None,
+    }
+}
+
+/// Applies the title draft; a blank or invalid one is dropped and the old title kept.
+fn commit_title(state: &mut AppState) {
+    let Some(TitleDraft { field, value }) = state.title_draft.take() else {
+        return;
+    };
+
+    let title = value.trim().to_string();
+    if title.is_empty() {
+        return;
+    }
+
+    match field {
+        TitleField::Workspace(id) => {
+            if !validation::workspace(&state.project, Some(&id), &title).is_empty() {
+                return;
+            }
+            if let Some(w) = state.project.workspace_mut(&id).filter(|w| w.name != title) {
+                w.name = title;
+                state.unsaved = true;
+            }
+        }
+        TitleField::Chapter(id) => {
+            edit_manuscript(state, |m| {
+                if let Some(c) = m.chapter_mut(&id) {
+                    c.title = title;
+                }
+            });
+        }
+        TitleField::Scene(id) => {
+            set_scene_text(state, &id, |s| &mut s.title, title);
+        }
+        TitleField::Board => {
+            edit_board(state, |b| b.name = title);
+        }
+        TitleField::Column(id) => {
+            edit_board(state, |b| {
+                if let Some(col) = b.column_mut(&id) {
+                    col.title = title;
+                }
+            });
+        }
+    }
+}
+
+fn record_scene_edit(state: &mut AppState, scene_id: String) {
+    if let Route::Manuscript { manuscript_id } = &state.route {
+        let target = ActivityTarget::Scene {
+            manuscript_id: manuscript_id.clone(),
+            scene_id,
+        };
+        state.project.activity.record(target, ActivityKind::Edited);
+    }
+}
+
+/// Problems with the create-workspace form.
+pub fn new_workspace_errors(state: &AppState) -> Errors {
+    validation::workspace(&state.project, None, &state.new_workspace_name)
+}
+
+/// Problems with the create-universe form.
+pub fn new_universe_errors(state: &AppState) -> Errors {
+    validation::universe(&state.project, None, &state.new_universe_name, &state.new_universe_desc, "")
+}
+
+/// Label of the undo command `message` creates, and whether the action is destructive
+/// enough to offer undo in a toast. `None` for messages that aren't undoable.
+fn undo_label(state: &AppState, message: &Message) -> Option<(String, bool)> {
+    let universe = |id: &str| state.project.universe(id).map(|u| u.name.clone()).unwrap_or_default();
+    let entry = |codex, id: &str| state.project.find_entry(codex, id).map(|e| e.name().to_string()).unwrap_or_default();
+
+    let label = match message {
+        Message::CreateUniverse => (format!("Created universe \"{}\"", state.new_universe_name.trim()), false),
+        Message::UniverseEditorSave => {
+            let id = state.universe_editor.as_ref()?.id.as_str();
+            (format!("Edited universe \"{}\"", universe(id)), false)
+        }
+        Message::ArchiveUniverse(id) => (format!("Archived universe \"{}\"", universe(id)), true),
+        Message::RestoreUniverse(id) => (format!("Restored universe \"{}\"", universe(id)), false),
+        Message::DeleteUniverseConfirm => {
+            let id = state.pending_universe_delete.as_deref()?;
+            (format!("Deleted universe \"{}\"", universe(id)), true)
+        }
+        Message::TemplateEditorSave => {
+            let id = state.template_editor.as_ref()?.universe_id.as_str();
+            (format!("Edited the creature sheet of \"{}\"", universe(id)), false)
+        }
+        Message::EditorSave(codex) => (forms::ops(*codex).save_label(state)?, false),
+        Message::CreatureEditorTransfer { copy } => {
+            let verb = if *copy { "Copied" } else { "Moved" };
+            (format!("{verb} creature \"{}\"", state.creature_editor.as_ref()?.name.trim()), false)
+        }
+        Message::ArchiveEntry(codex, id) => (format!("Archived {} \"{}\"", codex.noun(), entry(*codex, id)), true),
+        Message::RestoreEntry(codex, id) => (format!("Restored {} \"{}\"", codex.noun(), entry(*codex, id)), false),
+        Message::DeleteEntryConfirm => {
+            let (codex, id) = state.pending_entry_delete.as_ref()?;
+            (format!("Deleted {} \"{}\"", codex.noun(), entry(*codex, id)), true)
+        }
+        _ => return None,
+    };
+
+    Some(label)
+}
+
+/// Drops UI state pointing at records an undo or redo just removed.
+fn after_undo(state: &mut AppState) {
+    state.pending_universe_delete = None;
+    state.pending_entry_delete = None;
+
+    let project = &state.project;
+    if state.universe_editor.as_ref().is_some_and(|ed| project.universe(&ed.id).is_none()) {
+        state.universe_editor = None;
+    }
+    if state.template_editor.as_ref().is_some_and(|ed| project.universe(&ed.universe_id).is_none()) {
+        state.template_editor = None;
+    }
+    for codex in Codex::ALL {
+        forms::ops(codex).close_stale(state);
+    }
+
+    // A page whose subject is gone falls back to its parent.
+    let project = &state.project;
+    let gone = match &state.route {
+        Route::UniverseDetail { universe_id }
+        | Route::Codex { universe_id, .. }
+        | Route::Timeline { universe_id } => project.universe(universe_id).is_none(),
+        Route::CreatureDetail { creature_id } => project.creature(creature_id).is_none(),
+        _ => false,
+    };
+    if gone {
+        state.route = Route::UniverseList;
+    }
+}
+
+/// Hands a Markdown link to the system's default handler. Only web and mail
+/// links are followed, so a description can't launch local programs.
+fn open_link(url: &str) {
+    if !["http://", "https://", "mailto:"].iter().any(|scheme| url.starts_with(scheme))
+        || url.chars().any(|c| c.is_whitespace() || c.is_control())
+    {
+        return;
+    }
+
+    // Not `cmd /C start`: cmd.exe would run whatever follows a `&` in the URL.
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut c = std::process::Command::new("rundll32");
+        c.arg("url.dll,FileProtocolHandler");
+        c
+    };
+    #[cfg(target_os = "macos")]
+    let mut command = std::process::Command::new("open");
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let mut command = std::process::Command::new("xdg-open");
+
+    if let Err(err) = command.arg(url).spawn() {
+        eprintln!("{APP_ACRONYM}: could not open {url}: {err}");
+    }
+}
+
+/// Copies a local file into the store and registers it as an asset.
+fn import_asset(state: &mut AppState, source: &Path) -> Result<(), String> {
+    let Some(store) = state.store.as_ref() else {
+        return Err("The project store is unavailable, so files cannot be imported right now.".to_string());
+    };
+
+    if !source.is_file() {
+        return Err(format!("No file found at {}", source.display()));
+    }
+
+    let name = source
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "file".to_string());
+    let stem = source
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let ext = source
+        .extension()
+        .map(|e| e.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let id = state.project.new_asset_id(&stem);
+    let file = if ext.is_empty() { id.clone() } else { format!("{id}.{ext}") };
+
+    let size_bytes = store
+        .import_file(source, &file)
+        .map_err(|err| format!("Could not import {name}: {err}"))?;
+
+    state.project.activity.record(ActivityTarget::Asset(id.clone()), ActivityKind::Created);
+    state.project.assets.push(Asset {
+        id,
+        workspace_id: state.project.active_workspace.clone(),
+        name,
+        file,
+        kind: AssetKind::from_extension(&ext),
+        size_bytes,
+        tags: Vec::new(),
+        universe_id: None,
+        creature_id: None,
+    });
+    state.unsaved = true;
+
+    Ok(())
+}
+
+/// Makes `id` the active workspace. Pages showing an item of the previous workspace fall
+/// back to their module's list, and any open editor or pending delete is dropped.
+fn switch_workspace(state: &mut AppState, id: String) {
+    if state.project.workspace(&id).is_none() {
+        return;
+    }
+
+    state.project.active_workspace = id;
+    state.unsaved = true;
+
+    state.pending_universe_delete = None;
+    state.universe_editor = None;
+    close_editors(state);
+    state.template_editor = None;
+    state.timeline_editor = None;
+    state.pending_timeline_delete = None;
+    state.scene_editor = None;
+    state.pending_forge_delete = None;
+    state.card_editor = None;
+    state.pending_board_delete = None;
+    state.asset_editor = None;
+    state.pending_asset_delete = None;
+
+    state.route = match &state.route {
+        Route::UniverseDetail { .. }
+        | Route::Codex { .. }
+        | Route::CreatureDetail { .. }
+        | Route::Timeline { .. } => Route::UniverseList,
+        Route::Manuscript { .. } => Route::Forge,
+        Route::Board { .. } => Route::PmTools,
+        other => other.clone(),
+    };
+}
+
+/// Closes every codex editor and forgets a pending codex delete.
+fn close_editors(state: &mut AppState) {
+    for codex in Codex::ALL {
+        forms::ops(codex).close(state);
+    }
+    state.pending_entry_delete = None;
+}
+
+fn current_bestiary_filter_mut(state: &mut AppState) -> Option<&mut BestiaryFilter> {
+    match &state.route {
+        Route::Codex { codex: Codex::Creature, universe_id } => Some(state.bestiary_filters.entry(universe_id.clone()).or_default()),
+        _ => None,
+    }
+}
+
+pub fn current_board(state: &AppState) -> Option<&Board> {
+    match &state.route {
+        Route::Board { board_id } => state.project.board(board_id),
+        _ => None,
+    }
+}
+
+/// Runs `edit` on the open board and records the edit if it changed anything.
+fn edit_board<R>(state: &mut AppState, edit: impl FnOnce(&mut Board) -> R) -> Option<R> {
+    let Route::Board { board_id } = &state.route else {
+        return None;
+    };
+
+    let board_id = board_id.clone();
+    let b = state.project.board_mut(&board_id)?;
+    let before = b.clone();
+    let out = edit(b);
+
+    if *b != before {
+        state.project.activity.record(ActivityTarget::Board(board_id), ActivityKind::Edited);
+        state.unsaved = true;
+    }
+    Some(out)
+}
+
+fn open_activity(state: &mut AppState, target: ActivityTarget) {
+    match target {
+        ActivityTarget::Universe(universe_id) => {
+            apply(state, Message::Navigate(Route::UniverseDetail { universe_id }));
+        }
+        ActivityTarget::Manuscript(id) => open_manuscript(state, id),
+        ActivityTarget::Scene { manuscript_id, scene_id } => {
+            open_manuscript(state, manuscript_id);
+            open_scene(state, scene_id);
+        }
+        ActivityTarget::Board(id) => open_board(state, id),
+        ActivityTarget::Asset(id) => {
+            apply(state, Message::Navigate(Route::Assets));
+            state.asset_editor = state.project.asset(&id).map(AssetEditor::from_asset);
+        }
+        entry => {
+            if let Some((codex, id)) = entry.entry() {
+                apply(state, Message::OpenEntry(codex, id.to_string()));
+            }
+        }
+    }
+}
+
+fn open_board(state: &mut AppState, id: String) {
+    state.pending_board_delete = None;
+    state.card_editor = None;
+    state.route = Route::Board { board_id: id };
+}
+
+/// Opens a manuscript on its first scene, if it has one.
+fn open_manuscript(state: &mut AppState, id: String) {
+    state.pending_forge_delete = None;
+    state.scene_editor = None;
+
+    let first = state
+        .project
+        .manuscript(&id)
+        .and_then(|m| m.chapters.iter().flat_map(|c| &c.scenes).next())
+        .map(|s| s.id.clone());
+
+    state.route = Route::Manuscript { manuscript_id: id };
+
+    if let Some(scene_id) = first {
+        open_scene(state, scene_id);
+    }
+}
+
+fn open_scene(state: &mut AppState, scene_id: String) {
+    let Some(scene) = current_manuscript(state).and_then(|m| m.scene(&scene_id)) else {
+        return;
+    };
+
+    state.scene_editor = Some(SceneEditor {
+        content: text_editor::Content::with_text(&scene.body),
+        scene_id,
+        unsaved: false,
+    });
+}
+
+fn set_universe_archived(state: &mut AppState, id: &str, archived: bool) {
+    if let Some(u) = state.project.universes.iter_mut().find(|u| u.id == id) {
+        u.archived = archived;
+        state.project.activity.record(ActivityTarget::Universe(id.to_string()), ActivityKind::Edited);
+        state.unsaved = true;
+    }
+
+    if state.pending_universe_delete.as_deref() == Some(id) {
+        state.pending_universe_delete = None;
+    }
+}
+
+/// Makes a custom level available in the universe; built-in levels need nothing.
+fn add_danger_level(state: &mut AppState, universe_id: &str, level: &DangerLevel) {
+    let DangerLevel::Custom(name) = level else {
+        return;
+    };
+
+    if let Some(u) = state.project.universes.iter_mut().find(|u| u.id == universe_id)
+        && !u.danger_levels.iter().any(|l| l.eq_ignore_ascii_case(name))
+    {
+        u.danger_levels.push(name.clone());
+        state.project.activity.record(ActivityTarget::Universe(universe_id.to_string()), ActivityKind::Edited);
+        state.unsaved = true;
+    }
+}
+
+pub fn view(state: &AppState) -> Element<'_, Message> {
+    let t = ui::Tokens::nub_dark();
+
+    let sidebar = ui::sidebar(state, t);
+    let header = ui::header(state, t);
+    let search_results = ui::search_results(state, t);
+
+    let page: Element<'_, Message> = match &state.route {
+        Route::Overview => pages::overview(state, t),
+        Route::Workspaces => pages::workspaces(state, t),
+        Route::UniverseList => pages::universe_list(state, t),
+        Route::UniverseDetail { universe_id } => pages::universe_detail(state, t, universe_id),
+        Route::Codex { codex, universe_id } => pages::codex(state, t, *codex, universe_id),
+        Route::CreatureDetail { creature_id } => pages::creature_detail(state, t, creature_id),
+        Route::Timeline { universe_id } => pages::timeline(state, t, universe_id),
+        Route::Forge => pages::forge(state, t),
+        Route::Manuscript { manuscript_id } => pages::manuscript(state, t, manuscript_id),
+        Route::PmTools => pages::pm_tools(state, t),
+        Route::Board { board_id } => pages::board(state, t, board_id),
+        Route::Assets => pages::assets(state, t),
         Route::Account => pages::account_stub(state, t),
     };
 
-    let right = Column::new()
+    let mut right = Column::new()
         .spacing(14)
         .push(header)
-        .push(scrollable(page).width(Length::Fill).height(Length::Fill))
-        .width(Length::Fill)
-        .height(Length::Fill);
+        .push(state.storage_error.as_deref().map(|e| ui::storage_error(t, e)));
+
+    if let Some(results) = search_results {
+        right = right.push(results);
+    }
+
+    let mut right = right.push(scrollable(page).width(Length::Fill).height(Length::Fill));
+
+    if let Some(toast) = &state.toast {
+        right = right.push(ui::toast(t, toast));
+    }
+
+    let right = right.width(Length::Fill).height(Length::Fill);
 
     let root = Row::new()
         .spacing(18)
@@ -0,0 +1,290 @@
+//! Editors shared by every codex: how an entry opens in its form, what the form's
+//! built-in inputs are, and how a saved form lands back in the project.
+//!
+//! Each editor in [`crate::app`] implements [`Form`]; [`ops`] hands out the matching
+//! [`CodexOps`] so `update` can drive any codex from one set of messages. Inputs only
+//! one codex has (tags, memberships, holders, …) keep their own messages.
+
+use std::marker::PhantomData;
+
+use iced::widget::text_editor;
+
+use crate::app::{
+    AppState, ArtifactEditor, CharacterEditor, CreatureEditor, FactionEditor, LocationEditor, TermEditor,
+};
+use crate::model::validation::{Errors, Field};
+use crate::model::{ActivityKind, Codex, Entity, Entry, Project};
+
+/// One built-in input of a form.
+pub struct FormField<F> {
+    pub field: Field,
+    pub placeholder: &'static str,
+    pub input: Input<F>,
+}
+
+pub enum Input<F> {
+    /// A single line.
+    Line {
+        get: fn(&F) -> &String,
+        get_mut: fn(&mut F) -> &mut String,
+    },
+    /// A multi-line editor, `height` pixels tall.
+    Text {
+        get: fn(&F) -> &text_editor::Content,
+        get_mut: fn(&mut F) -> &mut text_editor::Content,
+        height: f32,
+        markdown: bool,
+    },
+}
+
+impl<F> FormField<F> {
+    pub const fn line(
+        field: Field,
+        placeholder: &'static str,
+        get: fn(&F) -> &String,
+        get_mut: fn(&mut F) -> &mut String,
+    ) -> Self {
+        FormField { field, placeholder, input: Input::Line { get, get_mut } }
+    }
+
+    /// Plain multi-line text.
+    pub const fn text(
+        field: Field,
+        placeholder: &'static str,
+        height: f32,
+        get: fn(&F) -> &text_editor::Content,
+        get_mut: fn(&mut F) -> &mut text_editor::Content,
+    ) -> Self {
+        FormField { field, placeholder, input: Input::Text { get, get_mut, height, markdown: false } }
+    }
+
+    /// Multi-line text rendered as Markdown on cards and sheets.
+    pub const fn markdown(
+        field: Field,
+        placeholder: &'static str,
+        height: f32,
+        get: fn(&F) -> &text_editor::Content,
+        get_mut: fn(&mut F) -> &mut text_editor::Content,
+    ) -> Self {
+        FormField { field, placeholder, input: Input::Text { get, get_mut, height, markdown: true } }
+    }
+}
+
+/// Temporary editor state for one codex entry.
+pub trait Form: Sized + 'static {
+    type Entity: Entity;
+
+    /// Inputs every form of this kind shows, in order. Pages add their own sections
+    /// between them.
+    const FIELDS: &'static [FormField<Self>];
+
+    fn get(state: &AppState) -> Option<&Self>;
+    fn slot(state: &mut AppState) -> &mut Option<Self>;
+
+    fn create(state: &AppState, universe_id: String) -> Self;
+    fn edit(state: &AppState, entry: &Self::Entity) -> Self;
+
+    /// `None` while creating; the id is assigned on save.
+    fn id(&self) -> Option<&str>;
+    fn universe_id(&self) -> &str;
+
+    fn errors(&self, project: &Project) -> Errors;
+
+    fn can_save(&self, project: &Project) -> bool {
+        self.errors(project).is_empty()
+    }
+
+    fn build(self) -> Self::Entity;
+
+    fn name(&self) -> &str {
+        Self::FIELDS
+            .iter()
+            .find_map(|f| match f.input {
+                Input::Line { get, .. } if f.field == Field::Name => Some(get(self).as_str()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Runs when the form opens, e.g. to close a panel it would clash with.
+    fn opening(_state: &mut AppState) {}
+
+    /// Runs right before a valid form is saved.
+    fn saving(_state: &mut AppState, _entry: &Self::Entity) {}
+
+    /// Drops references to an entry that was just deleted.
+    fn forget(&mut self, _codex: Codex, _id: &str) {}
+}
+
+/// What `update` can do with the editor of one codex, whatever its type.
+pub trait CodexOps {
+    fn open_create(&self, state: &mut AppState, universe_id: String);
+    /// Does nothing for archived entries; restore them first.
+    fn open_edit(&self, state: &mut AppState, id: &str);
+    fn close(&self, state: &mut AppState);
+    /// Saves the form unless it still has errors.
+    fn save(&self, state: &mut AppState);
+    fn line_changed(&self, state: &mut AppState, field: Field, value: String);
+    fn text_edit(&self, state: &mut AppState, field: Field, action: text_editor::Action);
+    fn set_archived(&self, state: &mut AppState, id: &str, archived: bool);
+    /// `codex`'s entry `id` was deleted.
+    fn forget(&self, state: &mut AppState, codex: Codex, id: &str);
+    /// Closes the form if an undo or redo removed its entry or universe.
+    fn close_stale(&self, state: &mut AppState);
+    /// Undo label of saving the open form, e.g. `Edited faction "Grey Wardens"`.
+    fn save_label(&self, state: &AppState) -> Option<String>;
+}
+
+struct Ops<F>(PhantomData<fn() -> F>);
+
+impl<F> Ops<F> {
+    const OPS: Self = Ops(PhantomData);
+}
+
+pub fn ops(codex: Codex) -> &'static dyn CodexOps {
+    match codex {
+        Codex::Creature => &Ops::<CreatureEditor>::OPS,
+        Codex::Location => &Ops::<LocationEditor>::OPS,
+        Codex::Character => &Ops::<CharacterEditor>::OPS,
+        Codex::Faction => &Ops::<FactionEditor>::OPS,
+        Codex::Term => &Ops::<TermEditor>::OPS,
+        Codex::Artifact => &Ops::<ArtifactEditor>::OPS,
+    }
+}
+
+impl<F: Form> Ops<F> {
+    fn editing(state: &AppState, id: &str) -> bool {
+        F::get(state).and_then(F::id) == Some(id)
+    }
+}
+
+impl<F: Form> CodexOps for Ops<F> {
+    fn open_create(&self, state: &mut AppState, universe_id: String) {
+        let form = F::create(state, universe_id);
+        *F::slot(state) = Some(form);
+        F::opening(state);
+    }
+
+    fn open_edit(&self, state: &mut AppState, id: &str) {
+        let Some(entry) = state.project.entry::<F::Entity>(id).filter(|e| !e.archived()) else {
+            return;
+        };
+
+        let form = F::edit(state, entry);
+        *F::slot(state) = Some(form);
+        F::opening(state);
+    }
+
+    fn close(&self, state: &mut AppState) {
+        *F::slot(state) = None;
+    }
+
+    fn save(&self, state: &mut AppState) {
+        let Some(form) = F::slot(state).take() else {
+            return;
+        };
+
+        if !form.can_save(&state.project) {
+            *F::slot(state) = Some(form);
+            return;
+        }
+
+        let entry = form.build();
+        F::saving(state, &entry);
+        upsert(state, entry);
+    }
+
+    fn line_changed(&self, state: &mut AppState, field: Field, value: String) {
+        let Some(form) = F::slot(state).as_mut() else {
+            return;
+        };
+
+        let input = F::FIELDS.iter().find_map(|f| match f.input {
+            Input::Line { get_mut, .. } if f.field == field => Some(get_mut),
+            _ => None,
+        });
+        if let Some(get_mut) = input {
+            *get_mut(form) = value;
+        }
+    }
+
+    fn text_edit(&self, state: &mut AppState, field: Field, action: text_editor::Action) {
+        let Some(form) = F::slot(state).as_mut() else {
+            return;
+        };
+
+        let input = F::FIELDS.iter().find_map(|f| match f.input {
+            Input::Text { get_mut, .. } if f.field == field => Some(get_mut),
+            _ => None,
+        });
+        if let Some(get_mut) = input {
+            get_mut(form).perform(action);
+        }
+    }
+
+    fn set_archived(&self, state: &mut AppState, id: &str, archived: bool) {
+        if let Some(entry) = state.project.entry_mut::<F::Entity>(id) {
+            entry.set_archived(archived);
+            entry.archive_toggled();
+            state
+                .project
+                .activity
+                .record(F::Entity::CODEX.target(id), ActivityKind::Edited);
+            state.unsaved = true;
+        }
+
+        if state.pending_delete(F::Entity::CODEX) == Some(id) {
+            state.pending_entry_delete = None;
+        }
+
+        // An archived entry leaves the grid, so close its editor too.
+        if archived && Self::editing(state, id) {
+            self.close(state);
+        }
+    }
+
+    fn forget(&self, state: &mut AppState, codex: Codex, id: &str) {
+        if codex == F::Entity::CODEX && Self::editing(state, id) {
+            self.close(state);
+        } else if let Some(form) = F::slot(state).as_mut() {
+            form.forget(codex, id);
+        }
+    }
+
+    fn close_stale(&self, state: &mut AppState) {
+        let project = &state.project;
+        let stale = F::get(state).is_some_and(|form| {
+            project.universe(form.universe_id()).is_none()
+                || form.id().is_some_and(|id| project.entry::<F::Entity>(id).is_none())
+        });
+
+        if stale {
+            self.close(state);
+        }
+    }
+
+    fn save_label(&self, state: &AppState) -> Option<String> {
+        let form = F::get(state)?;
+        let verb = if form.id().is_some() { "Edited" } else { "Created" };
+        Some(format!("{verb} {} \"{}\"", F::Entity::CODEX.noun(), form.name().trim()))
+    }
+}
+
+/// Replaces the entry with the same id, or adds it under a freshly generated id.
+pub fn upsert<T: Entity>(state: &mut AppState, mut entry: T) {
+    state.unsaved = true;
+
+    if let Some(slot) = state.project.entry_mut::<T>(entry.id()) {
+        entry.replacing(slot);
+        *slot = entry;
+        let target = T::CODEX.target(slot.id());
+        state.project.activity.record(target, ActivityKind::Edited);
+        return;
+    }
+
+    let id = state.project.new_entry_id::<T>(entry.name());
+    entry.set_id(id.clone());
+    entry.creating();
+    state.project.activity.record(T::CODEX.target(id), ActivityKind::Created);
+    T::list_mut(&mut state.project).push(entry);
+}
@@ -0,0 +1,13 @@
+//! TAS — the worldbuilding workbench.
+//!
+//! The library exposes the project [`model`], the Elm-style [`app`] state and update logic,
+//! and the file-backed [`storage`], so tests and companion tools can drive a project
+//! without opening a window. The `tas` binary just calls [`app::run`].
+
+pub mod app;
+pub mod model;
+pub mod storage;
+
+mod forms;
+mod pages;
+mod ui;
@@ -0,0 +1,3 @@
+fn main() -> iced::Result {
+    tas::app::run()
+}
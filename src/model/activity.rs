@@ -0,0 +1,214 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+/// Oldest entries are dropped past this many.
+const MAX_ENTRIES: usize = 200;
+
+/// Oldest revisions of an item are dropped past this many.
+const MAX_REVISIONS: usize = 50;
+
+/// What an [`Activity`] entry points at. Names are looked up when shown, so renames
+/// are picked up and entries for deleted items simply stop showing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActivityTarget {
+    Universe(String),
+    Creature(String),
+    Location(String),
+    Character(String),
+    Faction(String),
+    /// A Runomicon entry.
+    Term(String),
+    Artifact(String),
+    Manuscript(String),
+    Scene { manuscript_id: String, scene_id: String },
+    Board(String),
+    Asset(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActivityKind {
+    Created,
+    Edited,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Activity {
+    pub target: ActivityTarget,
+    pub kind: ActivityKind,
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+}
+
+/// Most recent activity per item, newest last.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ActivityLog {
+    entries: Vec<Activity>,
+}
+
+impl ActivityLog {
+    /// Records `kind` on `target` now; an item only keeps its latest entry.
+    pub fn record(&mut self, target: ActivityTarget, kind: ActivityKind) {
+        self.entries.retain(|a| a.target != target);
+        self.entries.push(Activity { target, kind, at: now() });
+
+        if self.entries.len() > MAX_ENTRIES {
+            let excess = self.entries.len() - MAX_ENTRIES;
+            self.entries.drain(..excess);
+        }
+    }
+
+    /// Newest first.
+    pub fn recent(&self) -> impl Iterator<Item = &Activity> {
+        self.entries.iter().rev()
+    }
+}
+
+/// One saved change to an item, e.g. "Created" or "Changed name and tags".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Revision {
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+    pub summary: String,
+}
+
+/// Edit history of a single item, newest last.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History {
+    revisions: Vec<Revision>,
+}
+
+impl History {
+    pub fn record(&mut self, summary: impl Into<String>) {
+        self.revisions.push(Revision {
+            at: now(),
+            summary: summary.into(),
+        });
+
+        if self.revisions.len() > MAX_REVISIONS {
+            let excess = self.revisions.len() - MAX_REVISIONS;
+            self.revisions.drain(..excess);
+        }
+    }
+
+    /// Newest first.
+    pub fn recent(&self) -> impl Iterator<Item = &Revision> {
+        self.revisions.iter().rev()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.revisions.is_empty()
+    }
+}
+
+/// "a", "a and b", "a, b and c".
+pub fn join_list(items: &[&str]) -> String {
+    match items {
+        [] => String::new(),
+        [only] => only.to_string(),
+        [rest @ .., last] => format!("{} and {last}", rest.join(", ")),
+    }
+}
+
+/// Seconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// `YYYY-MM-DD HH:MM` in UTC.
+pub fn format_timestamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+    let (y, m, d) = civil_from_days(days);
+
+    format!("{y:04}-{m:02}-{d:02} {:02}:{:02}", rem / 3600, rem % 3600 / 60)
+}
+
+/// Days since 1970-01-01 to a proleptic Gregorian date (Howard Hinnant's algorithm).
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = yoe + era * 400 + i64::from(m <= 2);
+
+    (y, m, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::fixture::project;
+
+    fn universe(id: &str) -> ActivityTarget {
+        ActivityTarget::Universe(id.to_string())
+    }
+
+    #[test]
+    fn an_item_keeps_only_its_latest_entry_newest_first() {
+        let mut log = ActivityLog::default();
+        log.record(universe("a"), ActivityKind::Created);
+        log.record(universe("b"), ActivityKind::Created);
+        log.record(universe("a"), ActivityKind::Edited);
+
+        let recent: Vec<_> = log.recent().map(|a| (a.target.clone(), a.kind)).collect();
+        assert_eq!(recent, [(universe("a"), ActivityKind::Edited), (universe("b"), ActivityKind::Created)]);
+    }
+
+    #[test]
+    fn the_oldest_entries_and_revisions_are_dropped() {
+        let mut log = ActivityLog::default();
+        let mut history = History::default();
+        for i in 0..MAX_ENTRIES + 5 {
+            log.record(universe(&i.to_string()), ActivityKind::Edited);
+            history.record(format!("Change {i}"));
+        }
+
+        assert_eq!(log.recent().count(), MAX_ENTRIES);
+        assert_eq!(log.recent().last().map(|a| &a.target), Some(&universe("5")));
+        assert_eq!(history.recent().count(), MAX_REVISIONS);
+        assert_eq!(history.recent().next().map(|r| r.summary.as_str()), Some("Change 204"));
+    }
+
+    #[test]
+    fn workspace_activity_skips_other_workspaces_and_deleted_items() {
+        let mut project = project();
+        for target in [
+            universe("elsewhere"),
+            universe("arhelis"),
+            ActivityTarget::Creature("wisp".to_string()),
+            ActivityTarget::Creature("gone".to_string()),
+            ActivityTarget::Scene {
+                manuscript_id: "saga".to_string(),
+                scene_id: "dusk".to_string(),
+            },
+            ActivityTarget::Asset("notes".to_string()),
+        ] {
+            project.activity.record(target, ActivityKind::Edited);
+        }
+
+        let shown: Vec<_> = project.workspace_activity().map(|a| a.target.clone()).collect();
+        assert_eq!(shown.len(), 3);
+        assert!(shown.contains(&universe("arhelis")) && !shown.contains(&universe("elsewhere")));
+        assert_eq!(project.activity_workspace(&ActivityTarget::Location("harrow".to_string())), Some("home"));
+    }
+
+    #[test]
+    fn lists_and_timestamps_read_naturally() {
+        assert_eq!(join_list(&[]), "");
+        assert_eq!(join_list(&["name"]), "name");
+        assert_eq!(join_list(&["name", "tags", "danger"]), "name, tags and danger");
+
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_709_210_096), "2024-02-29 12:34");
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// An item of a universe: a relic, weapon, heirloom or anything worth tracking.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Artifact {
+    pub id: String,
+    pub universe_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub rarity: Rarity,
+    /// Where and when it was made or found, e.g. "Forged under Mount Vey, Second Age".
+    #[serde(default)]
+    pub origin: String,
+    /// Who has it now; `None` when lost, hidden or unclaimed.
+    #[serde(default)]
+    pub holder: Option<Holder>,
+    /// Markdown.
+    #[serde(default)]
+    pub description: String,
+    /// Markdown: the hands it passed through and what it did there.
+    #[serde(default)]
+    pub history: String,
+    #[serde(default)]
+    pub properties: Vec<ArtifactProperty>,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+/// The character or creature currently holding an [`Artifact`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Holder {
+    Character(String),
+    Creature(String),
+}
+
+/// One named trait of an artifact, e.g. `Weight: 2 kg` or `Grants: sight in the dark`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArtifactProperty {
+    pub name: String,
+    #[serde(default)]
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Rarity {
+    #[default]
+    Common,
+    Uncommon,
+    Rare,
+    Legendary,
+    /// One of a kind.
+    Unique,
+}
+
+impl Rarity {
+    pub const ALL: [Rarity; 5] = [
+        Rarity::Common,
+        Rarity::Uncommon,
+        Rarity::Rare,
+        Rarity::Legendary,
+        Rarity::Unique,
+    ];
+}
+
+impl fmt::Display for Rarity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Rarity::Common => "Common",
+            Rarity::Uncommon => "Uncommon",
+            Rarity::Rare => "Rare",
+            Rarity::Legendary => "Legendary",
+            Rarity::Unique => "Unique",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::fixture::project;
+
+    #[test]
+    fn holders_are_looked_up_by_kind() {
+        let project = project();
+        let wyrm = Holder::Creature("wyrm".to_string());
+
+        let held: Vec<&str> = project.artifacts_held_by(&wyrm).map(|a| a.id.as_str()).collect();
+        assert_eq!(held, ["torc"]);
+        assert_eq!(project.holder_name(&wyrm), Some("Wyrm"));
+        assert_eq!(project.holder_name(&Holder::Character("wyrm".to_string())), None);
+    }
+
+    #[test]
+    fn artifacts_outlive_their_holder() {
+        let mut project = project();
+        project.remove_creature("wyrm");
+        project.remove_character("ilsa");
+
+        assert!(project.artifacts.iter().all(|a| a.holder.is_none()));
+        assert_eq!(project.artifacts.len(), 2);
+    }
+
+    #[test]
+    fn rarity_runs_from_common_to_unique() {
+        assert!(Rarity::ALL.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(project().artifact("signet").unwrap().rarity, Rarity::Common);
+    }
+}
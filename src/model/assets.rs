@@ -0,0 +1,91 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A file imported into the project store's `assets/` folder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Asset {
+    pub id: String,
+    #[serde(default)]
+    pub workspace_id: String,
+    /// Original file name, shown to the user.
+    pub name: String,
+    /// File name inside the store's assets folder.
+    pub file: String,
+    pub kind: AssetKind,
+    pub size_bytes: u64,
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub universe_id: Option<String>,
+    #[serde(default)]
+    pub creature_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AssetKind {
+    Image,
+    Vector,
+    Document,
+    Archive,
+    Other,
+}
+
+impl AssetKind {
+    pub fn from_extension(ext: &str) -> Self {
+        match ext.to_ascii_lowercase().as_str() {
+            "png" | "jpg" | "jpeg" | "gif" | "bmp" | "webp" | "tif" | "tiff" | "ico" => AssetKind::Image,
+            "svg" => AssetKind::Vector,
+            "pdf" | "txt" | "md" | "doc" | "docx" | "odt" | "rtf" => AssetKind::Document,
+            "zip" | "7z" | "rar" | "tar" | "gz" => AssetKind::Archive,
+            _ => AssetKind::Other,
+        }
+    }
+}
+
+impl fmt::Display for AssetKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            AssetKind::Image => "Image",
+            AssetKind::Vector => "Vector",
+            AssetKind::Document => "Doc",
+            AssetKind::Archive => "Archive",
+            AssetKind::Other => "File",
+        })
+    }
+}
+
+/// "512 B", "14.2 KB", "3.1 MB".
+pub fn format_size(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+
+    let b = bytes as f64;
+    if b >= MB {
+        format!("{:.1} MB", b / MB)
+    } else if b >= KB {
+        format!("{:.1} KB", b / KB)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind_follows_the_extension_in_any_case() {
+        assert_eq!(AssetKind::from_extension("PNG"), AssetKind::Image);
+        assert_eq!(AssetKind::from_extension("svg"), AssetKind::Vector);
+        assert_eq!(AssetKind::from_extension("md"), AssetKind::Document);
+        assert_eq!(AssetKind::from_extension("7z"), AssetKind::Archive);
+        assert_eq!(AssetKind::from_extension(""), AssetKind::Other);
+    }
+
+    #[test]
+    fn sizes_use_the_largest_fitting_unit() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(14_540), "14.2 KB");
+        assert_eq!(format_size(3 * 1024 * 1024 + 100_000), "3.1 MB");
+    }
+}
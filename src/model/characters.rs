@@ -0,0 +1,125 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A person of a universe: the cast novels in The Forge draw on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Character {
+    pub id: String,
+    pub universe_id: String,
+    pub name: String,
+    /// Epithet or role, e.g. "Exiled heir of Marekhan".
+    #[serde(default)]
+    pub title: String,
+    /// Markdown.
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub memberships: Vec<Membership>,
+    #[serde(default)]
+    pub relationships: Vec<Relationship>,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+/// Belonging to a [`Faction`], optionally at one of its ranks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Membership {
+    pub faction_id: String,
+    /// One of [`Faction::ranks`], or free text for factions without ranks; may be empty.
+    #[serde(default)]
+    pub rank: String,
+}
+
+/// What another character is to this one, e.g. `Mentor` or `Sister`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Relationship {
+    pub character_id: String,
+    pub kind: String,
+}
+
+/// An organization of a universe: a guild, house, order, army...
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Faction {
+    pub id: String,
+    pub universe_id: String,
+    pub name: String,
+    /// Markdown.
+    #[serde(default)]
+    pub description: String,
+    /// Highest first.
+    #[serde(default)]
+    pub ranks: Vec<String>,
+    /// This faction's stance towards others. Stances aren't mirrored: a faction can
+    /// count on an ally that secretly considers it a rival.
+    #[serde(default)]
+    pub allegiances: Vec<Allegiance>,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Allegiance {
+    pub faction_id: String,
+    pub stance: Stance,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Stance {
+    Allied,
+    Neutral,
+    Rival,
+    Hostile,
+}
+
+impl Stance {
+    pub const ALL: [Stance; 4] = [Stance::Allied, Stance::Neutral, Stance::Rival, Stance::Hostile];
+}
+
+impl fmt::Display for Stance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Stance::Allied => "Allied",
+            Stance::Neutral => "Neutral",
+            Stance::Rival => "Rival",
+            Stance::Hostile => "Hostile",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::fixture::project;
+
+    #[test]
+    fn relationships_and_members_are_found_from_the_other_side() {
+        let project = project();
+
+        let towards: Vec<(&str, &str)> =
+            project.relationships_towards("ilsa").map(|(c, r)| (c.id.as_str(), r.kind.as_str())).collect();
+        assert_eq!(towards, [("maren", "Sister")]);
+
+        let members: Vec<(&str, &str)> =
+            project.faction_members("watch").map(|(c, m)| (c.id.as_str(), m.rank.as_str())).collect();
+        assert_eq!(members, [("ilsa", "Captain"), ("maren", "")]);
+    }
+
+    #[test]
+    fn removing_a_character_drops_relationships_towards_it() {
+        let mut project = project();
+        project.remove_character("ilsa");
+
+        assert!(project.character("maren").unwrap().relationships.is_empty());
+        assert_eq!(project.faction_members("watch").count(), 1);
+    }
+
+    #[test]
+    fn removing_a_faction_keeps_its_members_and_drops_stances_towards_it() {
+        let mut project = project();
+        project.remove_faction("crows");
+
+        let maren = project.character("maren").unwrap();
+        assert_eq!(maren.memberships.len(), 1);
+        assert!(project.faction("watch").unwrap().allegiances.is_empty());
+    }
+}
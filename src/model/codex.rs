@@ -0,0 +1,794 @@
+//! What every codex entry has in common: creatures, locations, characters, factions,
+//! Runomicon entries and artifacts.
+//!
+//! Search, mentions, undo, the activity log and the codex pages work on [`Entry`] and
+//! [`Entity`] instead of on each type. A new kind of entry implements both, then needs:
+//!
+//! - a [`Codex`] variant, with its arms in [`Codex::ALL`], `noun`, `title`, `plural` and `target`;
+//! - an [`ActivityTarget`] variant, mapped back in [`ActivityTarget::entry`];
+//! - an arm in [`Project::entries`], [`Project::remove_entry`], `Project::drop_entry`
+//!   and [`OwnedEntry`];
+//! - an editor implementing `forms::Form`, handed out by `forms::ops`;
+//! - a page, dispatched from `pages::codex`.
+
+use std::borrow::Cow;
+
+use super::{
+    activity, unique_id, ActivityTarget, Artifact, Character, Creature, Faction, FieldValue, History, Location,
+    Project, Term,
+};
+
+/// The kinds of codex entries, in the order they are listed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Codex {
+    Creature,
+    Location,
+    Character,
+    Faction,
+    /// Runomicon entries.
+    Term,
+    Artifact,
+}
+
+impl Codex {
+    pub const ALL: [Codex; 6] = [
+        Codex::Creature,
+        Codex::Location,
+        Codex::Character,
+        Codex::Faction,
+        Codex::Term,
+        Codex::Artifact,
+    ];
+
+    /// What one entry is called in labels, e.g. `Deleted creature "Silt Siren"`.
+    pub fn noun(self) -> &'static str {
+        match self {
+            Codex::Creature => "creature",
+            Codex::Location => "location",
+            Codex::Character => "character",
+            Codex::Faction => "faction",
+            Codex::Term => "entry",
+            Codex::Artifact => "artifact",
+        }
+    }
+
+    /// Heading of the codex page.
+    pub fn title(self) -> &'static str {
+        match self {
+            Codex::Creature => "Bestiary",
+            Codex::Location => "Locations",
+            Codex::Character => "Characters",
+            Codex::Faction => "Factions",
+            Codex::Term => "Runomicon",
+            Codex::Artifact => "Artifacts",
+        }
+    }
+
+    /// Name of a group of entries, e.g. in search results.
+    pub fn plural(self) -> &'static str {
+        match self {
+            Codex::Creature => "Creatures",
+            Codex::Location => "Locations",
+            Codex::Character => "Characters",
+            Codex::Faction => "Factions",
+            Codex::Term => "Runomicon",
+            Codex::Artifact => "Artifacts",
+        }
+    }
+
+    /// Base of generated ids for entries without a usable name.
+    fn fallback_id(self) -> &'static str {
+        match self {
+            Codex::Term => "term",
+            other => other.noun(),
+        }
+    }
+
+    pub fn target(self, id: impl Into<String>) -> ActivityTarget {
+        let id = id.into();
+        match self {
+            Codex::Creature => ActivityTarget::Creature(id),
+            Codex::Location => ActivityTarget::Location(id),
+            Codex::Character => ActivityTarget::Character(id),
+            Codex::Faction => ActivityTarget::Faction(id),
+            Codex::Term => ActivityTarget::Term(id),
+            Codex::Artifact => ActivityTarget::Artifact(id),
+        }
+    }
+}
+
+impl ActivityTarget {
+    /// The codex entry this points at, if it is one.
+    pub fn entry(&self) -> Option<(Codex, &str)> {
+        match self {
+            ActivityTarget::Creature(id) => Some((Codex::Creature, id)),
+            ActivityTarget::Location(id) => Some((Codex::Location, id)),
+            ActivityTarget::Character(id) => Some((Codex::Character, id)),
+            ActivityTarget::Faction(id) => Some((Codex::Faction, id)),
+            ActivityTarget::Term(id) => Some((Codex::Term, id)),
+            ActivityTarget::Artifact(id) => Some((Codex::Artifact, id)),
+            _ => None,
+        }
+    }
+}
+
+/// Read access to an entry of any codex.
+pub trait Entry {
+    fn codex(&self) -> Codex;
+    fn id(&self) -> &str;
+    fn universe_id(&self) -> &str;
+    fn name(&self) -> &str;
+    fn archived(&self) -> bool;
+
+    /// Markdown: shown in full on the entry's own page, its first paragraph on cards.
+    fn description(&self) -> &str;
+
+    /// Other Markdown the pages render in full.
+    fn long_texts(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// What the entry is, shown next to its name in activity lists, e.g. "City".
+    fn kind(&self) -> String;
+
+    /// Facts shown under the name on cards, e.g. "Region · cold and misty". May be empty.
+    fn summary(&self, project: &Project) -> String;
+
+    /// Labelled texts search looks at besides the name.
+    fn search_fields<'a>(&'a self, project: &'a Project) -> Vec<(&'static str, Cow<'a, str>)>;
+
+    /// Labelled prose that may name other entries.
+    fn mention_fields(&self) -> Vec<(&'static str, &str)>;
+
+    fn to_owned_entry(&self) -> OwnedEntry;
+}
+
+/// A concrete entry type, stored in one list of the [`Project`].
+pub trait Entity: Entry + Clone + 'static {
+    const CODEX: Codex;
+
+    fn list(project: &Project) -> &[Self];
+    fn list_mut(project: &mut Project) -> &mut Vec<Self>;
+
+    fn set_id(&mut self, id: String);
+    fn set_archived(&mut self, archived: bool);
+
+    /// Removes entry `id` along with every link to it elsewhere in the project.
+    fn remove(project: &mut Project, id: &str);
+
+    /// Readies an edited copy to take the place of `old`. Only the archived flag
+    /// carries over unless a type keeps more.
+    fn replacing(&mut self, old: &mut Self) {
+        self.set_archived(old.archived());
+    }
+
+    /// Readies a new entry before it is added.
+    fn creating(&mut self) {}
+
+    /// Runs after the entry was archived or restored.
+    fn archive_toggled(&mut self) {}
+}
+
+/// An entry of any codex, as kept by undo.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OwnedEntry {
+    Creature(Creature),
+    Location(Location),
+    Character(Character),
+    Faction(Faction),
+    Term(Term),
+    Artifact(Artifact),
+}
+
+impl OwnedEntry {
+    /// Replaces the entry with the same id, or inserts it at `index` of its list.
+    pub fn put(self, project: &mut Project, index: usize) {
+        match self {
+            OwnedEntry::Creature(e) => put_in(project, e, index),
+            OwnedEntry::Location(e) => put_in(project, e, index),
+            OwnedEntry::Character(e) => put_in(project, e, index),
+            OwnedEntry::Faction(e) => put_in(project, e, index),
+            OwnedEntry::Term(e) => put_in(project, e, index),
+            OwnedEntry::Artifact(e) => put_in(project, e, index),
+        }
+    }
+}
+
+fn put_in<T: Entity>(project: &mut Project, entry: T, index: usize) {
+    let list = T::list_mut(project);
+    match list.iter().position(|e| e.id() == entry.id()) {
+        Some(i) => list[i] = entry,
+        None => list.insert(index.min(list.len()), entry),
+    }
+}
+
+fn as_entries<T: Entity>(project: &Project) -> impl Iterator<Item = &dyn Entry> {
+    T::list(project).iter().map(|e| e as &dyn Entry)
+}
+
+impl Project {
+    /// Every codex entry, list by list in [`Codex::ALL`] order.
+    pub fn entries(&self) -> impl Iterator<Item = &dyn Entry> + '_ {
+        as_entries::<Creature>(self)
+            .chain(as_entries::<Location>(self))
+            .chain(as_entries::<Character>(self))
+            .chain(as_entries::<Faction>(self))
+            .chain(as_entries::<Term>(self))
+            .chain(as_entries::<Artifact>(self))
+    }
+
+    /// Codex entries of every kind that belong to `universe_id`.
+    pub fn universe_entries<'a>(&'a self, universe_id: &'a str) -> impl Iterator<Item = &'a dyn Entry> + 'a {
+        self.entries().filter(move |e| e.universe_id() == universe_id)
+    }
+
+    pub fn find_entry(&self, codex: Codex, id: &str) -> Option<&dyn Entry> {
+        self.entries().find(|e| e.codex() == codex && e.id() == id)
+    }
+
+    pub fn entry<T: Entity>(&self, id: &str) -> Option<&T> {
+        T::list(self).iter().find(|e| e.id() == id)
+    }
+
+    pub fn entry_mut<T: Entity>(&mut self, id: &str) -> Option<&mut T> {
+        T::list_mut(self).iter_mut().find(|e| e.id() == id)
+    }
+
+    pub fn new_entry_id<T: Entity>(&self, name: &str) -> String {
+        unique_id(name, T::CODEX.fallback_id(), |id| self.entry::<T>(id).is_some())
+    }
+
+    /// Removes an entry and every link to it.
+    pub fn remove_entry(&mut self, codex: Codex, id: &str) {
+        match codex {
+            Codex::Creature => Creature::remove(self, id),
+            Codex::Location => Location::remove(self, id),
+            Codex::Character => Character::remove(self, id),
+            Codex::Faction => Faction::remove(self, id),
+            Codex::Term => Term::remove(self, id),
+            Codex::Artifact => Artifact::remove(self, id),
+        }
+    }
+
+    /// Removes only the entry itself, leaving links to it alone.
+    pub(super) fn drop_entry(&mut self, codex: Codex, id: &str) {
+        fn drop<T: Entity>(project: &mut Project, id: &str) {
+            T::list_mut(project).retain(|e| e.id() != id);
+        }
+
+        match codex {
+            Codex::Creature => drop::<Creature>(self, id),
+            Codex::Location => drop::<Location>(self, id),
+            Codex::Character => drop::<Character>(self, id),
+            Codex::Faction => drop::<Faction>(self, id),
+            Codex::Term => drop::<Term>(self, id),
+            Codex::Artifact => drop::<Artifact>(self, id),
+        }
+    }
+}
+
+/// `first · second`, skipping empty parts.
+fn facts(parts: &[&str]) -> String {
+    parts.iter().filter(|p| !p.is_empty()).copied().collect::<Vec<_>>().join(" · ")
+}
+
+impl Entry for Creature {
+    fn codex(&self) -> Codex {
+        Self::CODEX
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn universe_id(&self) -> &str {
+        &self.universe_id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn archived(&self) -> bool {
+        self.archived
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn kind(&self) -> String {
+        "Creature".to_string()
+    }
+
+    fn summary(&self, _project: &Project) -> String {
+        self.tags.join(" · ")
+    }
+
+    fn search_fields<'a>(&'a self, project: &'a Project) -> Vec<(&'static str, Cow<'a, str>)> {
+        let places = self
+            .habitat_locations
+            .iter()
+            .filter_map(|id| project.location(id))
+            .map(|l| l.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut fields: Vec<(&'static str, Cow<'a, str>)> = vec![
+            ("Tags", self.tags.join(" · ").into()),
+            ("Danger", self.danger.to_string().into()),
+            ("Habitat", self.habitat.as_str().into()),
+            ("Places", places.into()),
+            ("Description", self.description.as_str().into()),
+        ];
+        // Template fields: abilities, loot, stats…
+        fields.extend(self.fields.values().map(|v| {
+            let text: Cow<'a, str> = match v {
+                FieldValue::Text(text) => text.as_str().into(),
+                other => other.to_input().into(),
+            };
+            ("Sheet", text)
+        }));
+        fields
+    }
+
+    fn mention_fields(&self) -> Vec<(&'static str, &str)> {
+        let mut fields = vec![("Description", self.description.as_str()), ("Habitat", self.habitat.as_str())];
+        for v in self.fields.values() {
+            match v {
+                FieldValue::Text(text) => fields.push(("Sheet", text.as_str())),
+                FieldValue::List(items) => fields.extend(items.iter().map(|item| ("Sheet", item.as_str()))),
+                FieldValue::Number(_) => {}
+            }
+        }
+        fields
+    }
+
+    fn to_owned_entry(&self) -> OwnedEntry {
+        OwnedEntry::Creature(self.clone())
+    }
+}
+
+impl Entity for Creature {
+    const CODEX: Codex = Codex::Creature;
+
+    fn list(project: &Project) -> &[Self] {
+        &project.creatures
+    }
+
+    fn list_mut(project: &mut Project) -> &mut Vec<Self> {
+        &mut project.creatures
+    }
+
+    fn set_id(&mut self, id: String) {
+        self.id = id;
+    }
+
+    fn set_archived(&mut self, archived: bool) {
+        self.archived = archived;
+    }
+
+    fn remove(project: &mut Project, id: &str) {
+        project.remove_creature(id);
+    }
+
+    /// Creatures also keep their history, extended with what the edit changed.
+    fn replacing(&mut self, old: &mut Self) {
+        let changes = self.changes_since(old);
+
+        self.archived = old.archived;
+        self.edited_at = activity::now();
+        self.history = std::mem::take(&mut old.history);
+        if !changes.is_empty() {
+            self.history.record(format!("Changed {}", activity::join_list(&changes)));
+        }
+    }
+
+    fn creating(&mut self) {
+        self.edited_at = activity::now();
+        self.history = History::default();
+        self.history.record("Created");
+    }
+
+    fn archive_toggled(&mut self) {
+        self.edited_at = activity::now();
+        self.history.record(if self.archived { "Archived" } else { "Restored" });
+    }
+}
+
+impl Entry for Location {
+    fn codex(&self) -> Codex {
+        Self::CODEX
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn universe_id(&self) -> &str {
+        &self.universe_id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn archived(&self) -> bool {
+        self.archived
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn kind(&self) -> String {
+        self.kind.to_string()
+    }
+
+    fn summary(&self, _project: &Project) -> String {
+        facts(&[&self.kind.to_string(), &self.climate])
+    }
+
+    fn search_fields<'a>(&'a self, _project: &'a Project) -> Vec<(&'static str, Cow<'a, str>)> {
+        vec![
+            ("Kind", self.kind.to_string().into()),
+            ("Climate", self.climate.as_str().into()),
+            ("Description", self.description.as_str().into()),
+        ]
+    }
+
+    fn mention_fields(&self) -> Vec<(&'static str, &str)> {
+        vec![("Description", &self.description), ("Climate", &self.climate)]
+    }
+
+    fn to_owned_entry(&self) -> OwnedEntry {
+        OwnedEntry::Location(self.clone())
+    }
+}
+
+impl Entity for Location {
+    const CODEX: Codex = Codex::Location;
+
+    fn list(project: &Project) -> &[Self] {
+        &project.locations
+    }
+
+    fn list_mut(project: &mut Project) -> &mut Vec<Self> {
+        &mut project.locations
+    }
+
+    fn set_id(&mut self, id: String) {
+        self.id = id;
+    }
+
+    fn set_archived(&mut self, archived: bool) {
+        self.archived = archived;
+    }
+
+    fn remove(project: &mut Project, id: &str) {
+        project.remove_location(id);
+    }
+}
+
+impl Entry for Character {
+    fn codex(&self) -> Codex {
+        Self::CODEX
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn universe_id(&self) -> &str {
+        &self.universe_id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn archived(&self) -> bool {
+        self.archived
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn kind(&self) -> String {
+        "Character".to_string()
+    }
+
+    fn summary(&self, _project: &Project) -> String {
+        self.title.clone()
+    }
+
+    fn search_fields<'a>(&'a self, project: &'a Project) -> Vec<(&'static str, Cow<'a, str>)> {
+        let factions = self
+            .memberships
+            .iter()
+            .filter_map(|m| project.faction(&m.faction_id))
+            .map(|f| f.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        vec![
+            ("Title", self.title.as_str().into()),
+            ("Factions", factions.into()),
+            ("Description", self.description.as_str().into()),
+        ]
+    }
+
+    fn mention_fields(&self) -> Vec<(&'static str, &str)> {
+        vec![("Description", &self.description), ("Title", &self.title)]
+    }
+
+    fn to_owned_entry(&self) -> OwnedEntry {
+        OwnedEntry::Character(self.clone())
+    }
+}
+
+impl Entity for Character {
+    const CODEX: Codex = Codex::Character;
+
+    fn list(project: &Project) -> &[Self] {
+        &project.characters
+    }
+
+    fn list_mut(project: &mut Project) -> &mut Vec<Self> {
+        &mut project.characters
+    }
+
+    fn set_id(&mut self, id: String) {
+        self.id = id;
+    }
+
+    fn set_archived(&mut self, archived: bool) {
+        self.archived = archived;
+    }
+
+    fn remove(project: &mut Project, id: &str) {
+        project.remove_character(id);
+    }
+}
+
+impl Entry for Faction {
+    fn codex(&self) -> Codex {
+        Self::CODEX
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn universe_id(&self) -> &str {
+        &self.universe_id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn archived(&self) -> bool {
+        self.archived
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn kind(&self) -> String {
+        "Faction".to_string()
+    }
+
+    fn summary(&self, _project: &Project) -> String {
+        self.ranks.join(" › ")
+    }
+
+    fn search_fields<'a>(&'a self, _project: &'a Project) -> Vec<(&'static str, Cow<'a, str>)> {
+        vec![
+            ("Ranks", self.ranks.join(", ").into()),
+            ("Description", self.description.as_str().into()),
+        ]
+    }
+
+    fn mention_fields(&self) -> Vec<(&'static str, &str)> {
+        vec![("Description", &self.description)]
+    }
+
+    fn to_owned_entry(&self) -> OwnedEntry {
+        OwnedEntry::Faction(self.clone())
+    }
+}
+
+impl Entity for Faction {
+    const CODEX: Codex = Codex::Faction;
+
+    fn list(project: &Project) -> &[Self] {
+        &project.factions
+    }
+
+    fn list_mut(project: &mut Project) -> &mut Vec<Self> {
+        &mut project.factions
+    }
+
+    fn set_id(&mut self, id: String) {
+        self.id = id;
+    }
+
+    fn set_archived(&mut self, archived: bool) {
+        self.archived = archived;
+    }
+
+    fn remove(project: &mut Project, id: &str) {
+        project.remove_faction(id);
+    }
+}
+
+impl Entry for Term {
+    fn codex(&self) -> Codex {
+        Self::CODEX
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn universe_id(&self) -> &str {
+        &self.universe_id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn archived(&self) -> bool {
+        self.archived
+    }
+
+    /// The definition.
+    fn description(&self) -> &str {
+        &self.definition
+    }
+
+    fn kind(&self) -> String {
+        "Runomicon entry".to_string()
+    }
+
+    fn summary(&self, _project: &Project) -> String {
+        let pronunciation = if self.pronunciation.is_empty() {
+            String::new()
+        } else {
+            format!("/{}/", self.pronunciation)
+        };
+        facts(&[&pronunciation, &self.category])
+    }
+
+    fn search_fields<'a>(&'a self, _project: &'a Project) -> Vec<(&'static str, Cow<'a, str>)> {
+        vec![
+            ("Category", self.category.as_str().into()),
+            ("Pronunciation", self.pronunciation.as_str().into()),
+            ("Definition", self.definition.as_str().into()),
+        ]
+    }
+
+    fn mention_fields(&self) -> Vec<(&'static str, &str)> {
+        vec![("Definition", &self.definition)]
+    }
+
+    fn to_owned_entry(&self) -> OwnedEntry {
+        OwnedEntry::Term(self.clone())
+    }
+}
+
+impl Entity for Term {
+    const CODEX: Codex = Codex::Term;
+
+    fn list(project: &Project) -> &[Self] {
+        &project.terms
+    }
+
+    fn list_mut(project: &mut Project) -> &mut Vec<Self> {
+        &mut project.terms
+    }
+
+    fn set_id(&mut self, id: String) {
+        self.id = id;
+    }
+
+    fn set_archived(&mut self, archived: bool) {
+        self.archived = archived;
+    }
+
+    fn remove(project: &mut Project, id: &str) {
+        project.remove_term(id);
+    }
+}
+
+impl Entry for Artifact {
+    fn codex(&self) -> Codex {
+        Self::CODEX
+    }
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn universe_id(&self) -> &str {
+        &self.universe_id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn archived(&self) -> bool {
+        self.archived
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn long_texts(&self) -> Vec<&str> {
+        vec![&self.history]
+    }
+
+    fn kind(&self) -> String {
+        format!("{} artifact", self.rarity)
+    }
+
+    fn summary(&self, _project: &Project) -> String {
+        facts(&[&self.rarity.to_string(), &self.origin])
+    }
+
+    fn search_fields<'a>(&'a self, project: &'a Project) -> Vec<(&'static str, Cow<'a, str>)> {
+        let holder = self.holder.as_ref().and_then(|h| project.holder_name(h)).unwrap_or_default();
+        let properties = self
+            .properties
+            .iter()
+            .map(|p| format!("{}: {}", p.name, p.value))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        vec![
+            ("Held by", holder.into()),
+            ("Origin", self.origin.as_str().into()),
+            ("Properties", properties.into()),
+            ("Description", self.description.as_str().into()),
+            ("History", self.history.as_str().into()),
+        ]
+    }
+
+    fn mention_fields(&self) -> Vec<(&'static str, &str)> {
+        vec![("Description", &self.description), ("History", &self.history), ("Origin", &self.origin)]
+    }
+
+    fn to_owned_entry(&self) -> OwnedEntry {
+        OwnedEntry::Artifact(self.clone())
+    }
+}
+
+impl Entity for Artifact {
+    const CODEX: Codex = Codex::Artifact;
+
+    fn list(project: &Project) -> &[Self] {
+        &project.artifacts
+    }
+
+    fn list_mut(project: &mut Project) -> &mut Vec<Self> {
+        &mut project.artifacts
+    }
+
+    fn set_id(&mut self, id: String) {
+        self.id = id;
+    }
+
+    fn set_archived(&mut self, archived: bool) {
+        self.archived = archived;
+    }
+
+    fn remove(project: &mut Project, id: &str) {
+        project.remove_artifact(id);
+    }
+}
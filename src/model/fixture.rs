@@ -0,0 +1,135 @@
+//! Small project shared by the unit tests.
+
+use super::Project;
+
+/// Two workspaces. `home` holds Arhelis, with creatures, nested places, two linked
+/// terms and a custom danger level, and Sylvara, with two characters in two factions;
+/// each universe has an artifact held by one of its own. `away` holds Elsewhere. Home
+/// also has a two-chapter manuscript and a two-column board.
+pub fn project() -> Project {
+    serde_json::from_str(
+        r#"{
+            "workspaces": [{ "id": "home", "name": "Home" }, { "id": "away", "name": "Away" }],
+            "active_workspace": "home",
+            "universes": [
+                {
+                    "id": "arhelis",
+                    "workspace_id": "home",
+                    "name": "Arhelis",
+                    "description": "A world of *fog*.",
+                    "archived": false,
+                    "danger_levels": ["Apocalyptic"]
+                },
+                { "id": "sylvara", "workspace_id": "home", "name": "Sylvara", "description": "", "archived": false },
+                { "id": "elsewhere", "workspace_id": "away", "name": "Elsewhere", "description": "", "archived": false }
+            ],
+            "creatures": [
+                {
+                    "id": "wisp",
+                    "universe_id": "arhelis",
+                    "name": "Wisp",
+                    "tags": [],
+                    "habitat": "",
+                    "habitat_locations": ["harrow"],
+                    "description": "",
+                    "danger": "Low"
+                },
+                { "id": "wyrm", "universe_id": "arhelis", "name": "Wyrm", "tags": [], "habitat": "", "description": "", "danger": "High" },
+                { "id": "ender", "universe_id": "arhelis", "name": "Ender", "tags": [], "habitat": "", "description": "", "danger": "" }
+            ],
+            "characters": [
+                {
+                    "id": "ilsa",
+                    "universe_id": "sylvara",
+                    "name": "Ilsa",
+                    "memberships": [{ "faction_id": "watch", "rank": "Captain" }]
+                },
+                {
+                    "id": "maren",
+                    "universe_id": "sylvara",
+                    "name": "Maren",
+                    "memberships": [{ "faction_id": "watch" }, { "faction_id": "crows" }],
+                    "relationships": [{ "character_id": "ilsa", "kind": "Sister" }]
+                }
+            ],
+            "factions": [
+                {
+                    "id": "watch",
+                    "universe_id": "sylvara",
+                    "name": "Thorn Watch",
+                    "ranks": ["Captain", "Warden"],
+                    "allegiances": [{ "faction_id": "crows", "stance": "Rival" }]
+                },
+                {
+                    "id": "crows",
+                    "universe_id": "sylvara",
+                    "name": "Crows",
+                    "allegiances": [{ "faction_id": "watch", "stance": "Allied" }]
+                }
+            ],
+            "locations": [
+                { "id": "vey", "universe_id": "arhelis", "name": "Vey", "kind": "Region" },
+                { "id": "harrow", "universe_id": "arhelis", "name": "Harrow", "kind": "City", "parent_id": "vey" },
+                { "id": "beacon", "universe_id": "arhelis", "name": "Beacon", "kind": "Landmark", "parent_id": "harrow" },
+                { "id": "thorn", "universe_id": "sylvara", "name": "Thorn", "kind": "Region" }
+            ],
+            "terms": [
+                { "id": "ward", "universe_id": "arhelis", "name": "Warding", "category": "Magic system", "see_also": ["kel"] },
+                {
+                    "id": "kel",
+                    "universe_id": "arhelis",
+                    "name": "Kel",
+                    "category": "Glyph",
+                    "see_also": ["ward"],
+                    "glyph_asset_id": "rune"
+                }
+            ],
+            "artifacts": [
+                { "id": "torc", "universe_id": "arhelis", "name": "Torc", "rarity": "Rare", "holder": { "Creature": "wyrm" } },
+                { "id": "signet", "universe_id": "sylvara", "name": "Signet", "holder": { "Character": "ilsa" } }
+            ],
+            "assets": [
+                { "id": "rune", "workspace_id": "home", "name": "rune.svg", "file": "rune.svg", "kind": "Vector", "size_bytes": 1, "tags": [] },
+                { "id": "notes", "workspace_id": "away", "name": "notes.pdf", "file": "notes.pdf", "kind": "Document", "size_bytes": 1, "tags": [] }
+            ],
+            "manuscripts": [
+                {
+                    "id": "saga",
+                    "workspace_id": "home",
+                    "title": "Saga",
+                    "kind": "Novel",
+                    "chapters": [
+                        {
+                            "id": "one",
+                            "title": "One",
+                            "scenes": [
+                                { "id": "dawn", "title": "Dawn", "body": "The bells rang\nover Vey." },
+                                { "id": "dusk", "title": "Dusk", "body": "Quiet." }
+                            ]
+                        },
+                        { "id": "two", "title": "Two", "scenes": [{ "id": "night", "title": "Night", "body": "" }] }
+                    ]
+                }
+            ],
+            "boards": [
+                {
+                    "id": "plans",
+                    "workspace_id": "home",
+                    "name": "Plans",
+                    "columns": [
+                        {
+                            "id": "todo",
+                            "title": "To do",
+                            "cards": [
+                                { "id": "map", "title": "Map", "description": "", "assignee": "", "labels": [] },
+                                { "id": "names", "title": "Names", "description": "", "assignee": "", "labels": [] }
+                            ]
+                        },
+                        { "id": "done", "title": "Done", "cards": [] }
+                    ]
+                }
+            ]
+        }"#,
+    )
+    .unwrap()
+}
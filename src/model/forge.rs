@@ -0,0 +1,223 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::unique_id;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ManuscriptKind {
+    Novel,
+    Novella,
+    ShortStory,
+    Outline,
+}
+
+impl ManuscriptKind {
+    pub const ALL: [ManuscriptKind; 4] = [
+        ManuscriptKind::Novel,
+        ManuscriptKind::Novella,
+        ManuscriptKind::ShortStory,
+        ManuscriptKind::Outline,
+    ];
+}
+
+impl fmt::Display for ManuscriptKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ManuscriptKind::Novel => "Novel",
+            ManuscriptKind::Novella => "Novella",
+            ManuscriptKind::ShortStory => "Short story",
+            ManuscriptKind::Outline => "Outline",
+        })
+    }
+}
+
+/// A book in The Forge: chapters in reading order, each holding its scenes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Manuscript {
+    pub id: String,
+    #[serde(default)]
+    pub workspace_id: String,
+    pub title: String,
+    pub kind: ManuscriptKind,
+    pub chapters: Vec<Chapter>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Chapter {
+    /// Unique within its manuscript.
+    pub id: String,
+    pub title: String,
+    pub scenes: Vec<Scene>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Scene {
+    /// Unique within its manuscript.
+    pub id: String,
+    pub title: String,
+    pub body: String,
+}
+
+pub fn word_count(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+impl Scene {
+    pub fn word_count(&self) -> usize {
+        word_count(&self.body)
+    }
+}
+
+impl Chapter {
+    pub fn word_count(&self) -> usize {
+        self.scenes.iter().map(Scene::word_count).sum()
+    }
+}
+
+impl Manuscript {
+    pub fn word_count(&self) -> usize {
+        self.chapters.iter().map(Chapter::word_count).sum()
+    }
+
+    pub fn scene_count(&self) -> usize {
+        self.chapters.iter().map(|c| c.scenes.len()).sum()
+    }
+
+    pub fn chapter_mut(&mut self, id: &str) -> Option<&mut Chapter> {
+        self.chapters.iter_mut().find(|c| c.id == id)
+    }
+
+    pub fn scene(&self, id: &str) -> Option<&Scene> {
+        self.chapters.iter().flat_map(|c| &c.scenes).find(|s| s.id == id)
+    }
+
+    pub fn scene_mut(&mut self, id: &str) -> Option<&mut Scene> {
+        self.chapters.iter_mut().flat_map(|c| &mut c.scenes).find(|s| s.id == id)
+    }
+
+    pub fn new_chapter_id(&self, title: &str) -> String {
+        unique_id(title, "chapter", |id| self.chapters.iter().any(|c| c.id == id))
+    }
+
+    pub fn new_scene_id(&self, title: &str) -> String {
+        unique_id(title, "scene", |id| self.scene(id).is_some())
+    }
+
+    pub fn move_chapter(&mut self, id: &str, up: bool) {
+        let Some(i) = self.chapters.iter().position(|c| c.id == id) else {
+            return;
+        };
+
+        match up {
+            true if i > 0 => self.chapters.swap(i, i - 1),
+            false if i + 1 < self.chapters.len() => self.chapters.swap(i, i + 1),
+            _ => {}
+        }
+    }
+
+    /// Moves a scene one slot up/down; at a chapter edge it crosses into the neighbouring chapter.
+    pub fn move_scene(&mut self, id: &str, up: bool) {
+        let Some((ci, si)) = self.chapters.iter().enumerate().find_map(|(ci, c)| {
+            c.scenes.iter().position(|s| s.id == id).map(|si| (ci, si))
+        }) else {
+            return;
+        };
+
+        let len = self.chapters[ci].scenes.len();
+
+        match up {
+            true if si > 0 => self.chapters[ci].scenes.swap(si, si - 1),
+            true if ci > 0 => {
+                let scene = self.chapters[ci].scenes.remove(si);
+                self.chapters[ci - 1].scenes.push(scene);
+            }
+            false if si + 1 < len => self.chapters[ci].scenes.swap(si, si + 1),
+            false if ci + 1 < self.chapters.len() => {
+                let scene = self.chapters[ci].scenes.remove(si);
+                self.chapters[ci + 1].scenes.insert(0, scene);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn remove_chapter(&mut self, id: &str) {
+        self.chapters.retain(|c| c.id != id);
+    }
+
+    pub fn remove_scene(&mut self, id: &str) {
+        for c in &mut self.chapters {
+            c.scenes.retain(|s| s.id != id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::fixture::project;
+
+    fn saga() -> Manuscript {
+        project().manuscripts.remove(0)
+    }
+
+    fn scene_ids(m: &Manuscript) -> Vec<Vec<&str>> {
+        m.chapters.iter().map(|c| c.scenes.iter().map(|s| s.id.as_str()).collect()).collect()
+    }
+
+    #[test]
+    fn words_add_up_from_scenes_to_the_manuscript() {
+        let saga = saga();
+        assert_eq!(saga.chapters[0].scenes[0].word_count(), 5);
+        assert_eq!(saga.chapters[0].word_count(), 6);
+        assert_eq!(saga.word_count(), 6);
+        assert_eq!(saga.scene_count(), 3);
+        assert_eq!(word_count("  \n "), 0);
+    }
+
+    #[test]
+    fn scenes_cross_into_the_neighbouring_chapter_at_its_edge() {
+        let mut saga = saga();
+
+        saga.move_scene("dusk", false);
+        assert_eq!(scene_ids(&saga), [vec!["dawn"], vec!["dusk", "night"]]);
+
+        saga.move_scene("dusk", true);
+        assert_eq!(scene_ids(&saga), [vec!["dawn", "dusk"], vec!["night"]]);
+
+        saga.move_scene("dawn", true);
+        saga.move_scene("night", false);
+        assert_eq!(scene_ids(&saga), [vec!["dawn", "dusk"], vec!["night"]]);
+    }
+
+    #[test]
+    fn chapters_stop_at_either_end() {
+        let mut saga = saga();
+
+        saga.move_chapter("one", true);
+        saga.move_chapter("two", false);
+        assert_eq!(saga, self::saga());
+
+        saga.move_chapter("two", true);
+        let order: Vec<&str> = saga.chapters.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(order, ["two", "one"]);
+    }
+
+    #[test]
+    fn removing_a_chapter_takes_its_scenes() {
+        let mut saga = saga();
+        saga.remove_chapter("one");
+        assert!(saga.scene("dawn").is_none());
+
+        saga.remove_scene("night");
+        assert_eq!(saga.scene_count(), 0);
+    }
+
+    #[test]
+    fn new_ids_skip_taken_ones() {
+        let saga = saga();
+        assert_ne!(saga.new_chapter_id("One"), "one");
+        assert_ne!(saga.new_scene_id("Dawn"), "dawn");
+        assert_eq!(saga.new_scene_id("Noon"), "noon");
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+/// Categories offered in the Runomicon editor; any other text works too.
+pub const SUGGESTED_CATEGORIES: [&str; 5] = ["Magic system", "Spell", "Glyph", "Vocabulary", "Ritual"];
+
+/// An entry of a universe's Runomicon: a magic system, spell, glyph or invented word.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Term {
+    pub id: String,
+    pub universe_id: String,
+    pub name: String,
+    /// Free-form, e.g. "RU-no-mi-kon".
+    #[serde(default)]
+    pub pronunciation: String,
+    /// Free text; entries sharing one are grouped together. May be empty.
+    #[serde(default)]
+    pub category: String,
+    /// Markdown.
+    #[serde(default)]
+    pub definition: String,
+    /// Related [`Term::id`]s, in the order they were added.
+    #[serde(default)]
+    pub see_also: Vec<String>,
+    /// [`super::Asset::id`] of an image or SVG drawing the term's glyph.
+    #[serde(default)]
+    pub glyph_asset_id: Option<String>,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::fixture::project;
+
+    #[test]
+    fn see_also_links_are_found_from_the_other_side() {
+        let project = project();
+        let linking: Vec<&str> = project.terms_linking("ward").map(|t| t.id.as_str()).collect();
+
+        assert_eq!(linking, ["kel"]);
+        assert_eq!(project.terms_in("arhelis").count(), 2);
+        assert_eq!(project.terms_in("sylvara").count(), 0);
+    }
+
+    #[test]
+    fn removing_a_term_drops_links_to_it() {
+        let mut project = project();
+        project.remove_term("ward");
+
+        assert!(project.term("kel").unwrap().see_also.is_empty());
+        assert_eq!(project.terms_linking("ward").count(), 0);
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A place of a universe. Places nest: a landmark inside a city inside a region.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Location {
+    pub id: String,
+    pub universe_id: String,
+    pub name: String,
+    pub kind: LocationKind,
+    /// Enclosing [`Location::id`]; `None` for a top-level place.
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    #[serde(default)]
+    pub climate: String,
+    /// Markdown.
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LocationKind {
+    Region,
+    City,
+    Landmark,
+}
+
+impl LocationKind {
+    pub const ALL: [LocationKind; 3] = [LocationKind::Region, LocationKind::City, LocationKind::Landmark];
+}
+
+impl fmt::Display for LocationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LocationKind::Region => "Region",
+            LocationKind::City => "City",
+            LocationKind::Landmark => "Landmark",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::fixture::project;
+
+    #[test]
+    fn paths_run_from_the_outermost_place_in() {
+        let project = project();
+        let ids: Vec<&str> = project.location_path("beacon").iter().map(|l| l.id.as_str()).collect();
+
+        assert_eq!(ids, ["vey", "harrow", "beacon"]);
+        assert_eq!(project.location_label("beacon"), "Vey › Harrow › Beacon");
+        assert!(project.location_within("beacon", "vey"));
+        assert!(project.location_within("vey", "vey"));
+        assert!(!project.location_within("vey", "beacon"));
+        assert!(project.location_path("gone").is_empty());
+    }
+
+    #[test]
+    fn a_parent_loop_in_the_store_ends_the_path() {
+        let mut project = project();
+        project.locations.iter_mut().find(|l| l.id == "vey").unwrap().parent_id = Some("beacon".into());
+
+        assert_eq!(project.location_path("beacon").len(), 3);
+    }
+
+    #[test]
+    fn removing_a_place_moves_what_it_held_up_a_level() {
+        let mut project = project();
+        assert_eq!(project.creatures_at("harrow").count(), 1);
+
+        project.remove_location("harrow");
+
+        assert_eq!(project.location_label("beacon"), "Vey › Beacon");
+        assert_eq!(project.creatures_at("harrow").count(), 0);
+        assert!(project.creatures.iter().all(|c| c.habitat_locations.is_empty()));
+    }
+}
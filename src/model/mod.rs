@@ -0,0 +1,1001 @@
+pub mod activity;
+pub mod artifacts;
+pub mod assets;
+pub mod characters;
+pub mod codex;
+#[cfg(test)]
+pub(crate) mod fixture;
+pub mod forge;
+pub mod glossary;
+pub mod locations;
+pub mod pm;
+pub mod search;
+pub mod template;
+pub mod timeline;
+pub mod undo;
+pub mod validation;
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+pub use activity::{Activity, ActivityKind, ActivityLog, ActivityTarget, History, Revision};
+pub use artifacts::{Artifact, ArtifactProperty, Holder, Rarity};
+pub use assets::{Asset, AssetKind};
+pub use characters::{Allegiance, Character, Faction, Membership, Relationship, Stance};
+pub use codex::{Codex, Entity, Entry, OwnedEntry};
+pub use forge::{Chapter, Manuscript, ManuscriptKind, Scene};
+pub use glossary::Term;
+pub use locations::{Location, LocationKind};
+pub use pm::{Board, BoardColumn, Card, CardMove};
+pub use template::{CreatureTemplate, FieldDef, FieldKind, FieldValue};
+pub use timeline::{Calendar, Era, FantasyDate, TimelineEvent};
+
+/// A top-level container: everything the user creates lives in exactly one workspace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workspace {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Universe {
+    pub id: String,
+    /// Owning [`Workspace::id`]. Empty only for data saved before workspaces existed.
+    #[serde(default)]
+    pub workspace_id: String,
+    pub name: String,
+    /// Markdown.
+    pub description: String,
+    /// Markdown notes shown on the universe page: pitch, themes, high-level lore.
+    #[serde(default)]
+    pub lore: String,
+    pub archived: bool,
+    /// Danger levels this universe adds on top of [`DangerLevel::BUILT_IN`], in order.
+    #[serde(default)]
+    pub danger_levels: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Creature {
+    /// Stable id, unique across the project. Empty only for data saved before ids existed.
+    #[serde(default)]
+    pub id: String,
+    /// Owning [`Universe::id`]. Empty only for data saved before creatures were scoped.
+    #[serde(default)]
+    pub universe_id: String,
+    pub name: String,
+    /// Older stores kept these as one `kind` string joined with "·".
+    #[serde(alias = "kind", deserialize_with = "deserialize_tags")]
+    pub tags: Vec<String>,
+    pub habitat: String,
+    /// [`Location::id`]s of its universe the creature lives in, alongside the free-text `habitat`.
+    #[serde(default)]
+    pub habitat_locations: Vec<String>,
+    /// Markdown.
+    pub description: String,
+    pub danger: DangerLevel,
+    /// Values of the universe's [`CreatureTemplate`] fields, keyed by [`FieldDef::id`].
+    /// Values of fields since removed from the template are kept but not shown.
+    #[serde(default)]
+    pub fields: BTreeMap<String, FieldValue>,
+    #[serde(default)]
+    pub archived: bool,
+    /// Seconds since the Unix epoch of the last save; `0` for data from before it was tracked.
+    #[serde(default)]
+    pub edited_at: u64,
+    #[serde(default)]
+    pub history: History,
+}
+
+impl Creature {
+    /// Names of the sheet parts that differ from `old`, in sheet order.
+    pub fn changes_since(&self, old: &Creature) -> Vec<&'static str> {
+        [
+            ("universe", self.universe_id != old.universe_id),
+            ("name", self.name != old.name),
+            ("tags", self.tags != old.tags),
+            ("habitat", self.habitat != old.habitat || self.habitat_locations != old.habitat_locations),
+            ("description", self.description != old.description),
+            ("danger", self.danger != old.danger),
+            ("sheet fields", self.fields != old.fields),
+        ]
+        .into_iter()
+        .filter(|(_, changed)| *changed)
+        .map(|(part, _)| part)
+        .collect()
+    }
+}
+
+/// How dangerous a creature is. Stored as its name, so older stores with free-text
+/// danger strings load unchanged: unknown names become [`DangerLevel::Custom`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum DangerLevel {
+    Low,
+    Medium,
+    High,
+    Extreme,
+    /// A level defined by the universe (see [`Universe::danger_levels`]).
+    Custom(String),
+    Unrated,
+}
+
+impl DangerLevel {
+    pub const BUILT_IN: [DangerLevel; 4] =
+        [DangerLevel::Low, DangerLevel::Medium, DangerLevel::High, DangerLevel::Extreme];
+}
+
+impl From<String> for DangerLevel {
+    fn from(s: String) -> Self {
+        let name = s.trim();
+
+        if name.is_empty() {
+            return DangerLevel::Unrated;
+        }
+
+        DangerLevel::BUILT_IN
+            .into_iter()
+            .find(|level| level.to_string().eq_ignore_ascii_case(name))
+            .unwrap_or_else(|| DangerLevel::Custom(name.to_string()))
+    }
+}
+
+impl From<DangerLevel> for String {
+    fn from(level: DangerLevel) -> Self {
+        match level {
+            DangerLevel::Custom(name) => name,
+            DangerLevel::Unrated => String::new(),
+            other => other.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for DangerLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DangerLevel::Low => "Low",
+            DangerLevel::Medium => "Medium",
+            DangerLevel::High => "High",
+            DangerLevel::Extreme => "Extreme",
+            DangerLevel::Custom(name) => name,
+            DangerLevel::Unrated => "Unrated",
+        })
+    }
+}
+
+impl Universe {
+    /// Every level a creature of this universe can have, least dangerous first.
+    pub fn danger_choices(&self) -> Vec<DangerLevel> {
+        DangerLevel::BUILT_IN
+            .into_iter()
+            .chain(self.danger_levels.iter().cloned().map(DangerLevel::Custom))
+            .collect()
+    }
+
+    /// Position of `level` in [`Self::danger_choices`], higher is more dangerous.
+    /// `None` for unrated creatures and levels the universe doesn't define.
+    pub fn danger_rank(&self, level: &DangerLevel) -> Option<usize> {
+        match level {
+            DangerLevel::Unrated => None,
+            DangerLevel::Custom(name) => self
+                .danger_levels
+                .iter()
+                .position(|l| l.eq_ignore_ascii_case(name))
+                .map(|i| DangerLevel::BUILT_IN.len() + i),
+            built_in => DangerLevel::BUILT_IN.iter().position(|l| l == built_in),
+        }
+    }
+}
+
+/// Splits "Elemental · Relicto" (or a comma list) into trimmed, non-empty tags.
+pub fn parse_tags(s: &str) -> Vec<String> {
+    s.split(['·', ','])
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Accepts the current tag list or the legacy "·"-joined `kind` string.
+fn deserialize_tags<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Tags {
+        List(Vec<String>),
+        Legacy(String),
+    }
+
+    Ok(match Tags::deserialize(deserializer)? {
+        Tags::List(tags) => tags,
+        Tags::Legacy(kind) => parse_tags(&kind),
+    })
+}
+
+/// Everything that belongs to the user's project and survives a restart.
+///
+/// Unknown/missing collections deserialize as empty so older store files keep loading.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Project {
+    pub workspaces: Vec<Workspace>,
+    /// [`Workspace::id`] the app is showing; kept valid by [`Project::migrate`].
+    pub active_workspace: String,
+
+    pub universes: Vec<Universe>,
+    pub creatures: Vec<Creature>,
+    pub locations: Vec<Location>,
+    pub characters: Vec<Character>,
+    pub factions: Vec<Faction>,
+    /// Runomicon entries.
+    pub terms: Vec<Term>,
+    pub artifacts: Vec<Artifact>,
+
+    /// Only universes with a customised calendar have an entry here.
+    pub calendars: Vec<Calendar>,
+
+    /// Only universes with extra creature fields have an entry here.
+    pub creature_templates: Vec<CreatureTemplate>,
+    pub eras: Vec<Era>,
+    pub events: Vec<TimelineEvent>,
+
+    pub manuscripts: Vec<Manuscript>,
+
+    pub boards: Vec<Board>,
+
+    pub assets: Vec<Asset>,
+
+    pub activity: ActivityLog,
+}
+
+impl Project {
+    /// Brings data written by older versions up to the current shape.
+    pub fn migrate(&mut self) {
+        self.adopt_orphan_creatures();
+        self.assign_missing_creature_ids();
+        self.adopt_orphans_into_workspace();
+        self.register_custom_danger_levels();
+    }
+
+    pub fn workspace(&self, id: &str) -> Option<&Workspace> {
+        self.workspaces.iter().find(|w| w.id == id)
+    }
+
+    pub fn workspace_mut(&mut self, id: &str) -> Option<&mut Workspace> {
+        self.workspaces.iter_mut().find(|w| w.id == id)
+    }
+
+    pub fn new_workspace_id(&self, name: &str) -> String {
+        unique_id(name, "workspace", |id| self.workspace(id).is_some())
+    }
+
+    /// Universes of the active workspace.
+    pub fn workspace_universes(&self) -> impl Iterator<Item = &Universe> + '_ {
+        self.universes.iter().filter(|u| u.workspace_id == self.active_workspace)
+    }
+
+    /// Manuscripts of the active workspace.
+    pub fn workspace_manuscripts(&self) -> impl Iterator<Item = &Manuscript> + '_ {
+        self.manuscripts.iter().filter(|m| m.workspace_id == self.active_workspace)
+    }
+
+    /// Boards of the active workspace.
+    pub fn workspace_boards(&self) -> impl Iterator<Item = &Board> + '_ {
+        self.boards.iter().filter(|b| b.workspace_id == self.active_workspace)
+    }
+
+    /// Assets of the active workspace.
+    pub fn workspace_assets(&self) -> impl Iterator<Item = &Asset> + '_ {
+        self.assets.iter().filter(|a| a.workspace_id == self.active_workspace)
+    }
+
+    /// Workspace owning the item an activity entry points at, or `None` once it is gone.
+    pub fn activity_workspace(&self, target: &ActivityTarget) -> Option<&str> {
+        let universe_workspace = |id: &str| self.universe(id).map(|u| u.workspace_id.as_str());
+
+        match target {
+            ActivityTarget::Universe(id) => universe_workspace(id),
+            ActivityTarget::Manuscript(id) => self.manuscript(id).map(|m| m.workspace_id.as_str()),
+            ActivityTarget::Scene { manuscript_id, scene_id } => self
+                .manuscript(manuscript_id)
+                .filter(|m| m.scene(scene_id).is_some())
+                .map(|m| m.workspace_id.as_str()),
+            ActivityTarget::Board(id) => self.board(id).map(|b| b.workspace_id.as_str()),
+            ActivityTarget::Asset(id) => self.asset(id).map(|a| a.workspace_id.as_str()),
+            entry => entry
+                .entry()
+                .and_then(|(codex, id)| self.find_entry(codex, id))
+                .and_then(|e| universe_workspace(e.universe_id())),
+        }
+    }
+
+    /// Activity of the active workspace, newest first, skipping items that no longer exist.
+    pub fn workspace_activity(&self) -> impl Iterator<Item = &Activity> + '_ {
+        self.activity
+            .recent()
+            .filter(|a| self.activity_workspace(&a.target) == Some(self.active_workspace.as_str()))
+    }
+
+    /// Removes a workspace and everything it owns, returning the removed assets so the
+    /// caller can delete their stored files. The last workspace can't be removed.
+    pub fn remove_workspace(&mut self, workspace_id: &str) -> Vec<Asset> {
+        if self.workspaces.len() <= 1 || self.workspace(workspace_id).is_none() {
+            return Vec::new();
+        }
+
+        let universe_ids: Vec<String> = self
+            .universes
+            .iter()
+            .filter(|u| u.workspace_id == workspace_id)
+            .map(|u| u.id.clone())
+            .collect();
+        for id in &universe_ids {
+            self.remove_universe(id);
+        }
+
+        self.manuscripts.retain(|m| m.workspace_id != workspace_id);
+        self.boards.retain(|b| b.workspace_id != workspace_id);
+
+        let (removed, kept) = std::mem::take(&mut self.assets)
+            .into_iter()
+            .partition(|a| a.workspace_id == workspace_id);
+        self.assets = kept;
+
+        self.workspaces.retain(|w| w.id != workspace_id);
+        if self.active_workspace == workspace_id {
+            self.active_workspace = self.workspaces[0].id.clone();
+        }
+
+        removed
+    }
+
+    pub fn universe(&self, id: &str) -> Option<&Universe> {
+        self.universes.iter().find(|u| u.id == id)
+    }
+
+    pub fn universe_mut(&mut self, id: &str) -> Option<&mut Universe> {
+        self.universes.iter_mut().find(|u| u.id == id)
+    }
+
+    pub fn creature(&self, id: &str) -> Option<&Creature> {
+        self.creatures.iter().find(|c| c.id == id)
+    }
+
+    /// Creatures that belong to `universe_id`.
+    pub fn creatures_in<'a>(&'a self, universe_id: &'a str) -> impl Iterator<Item = &'a Creature> + 'a {
+        self.creatures.iter().filter(move |c| c.universe_id == universe_id)
+    }
+
+    /// Tags already used by creatures of `universe_id`, sorted, without duplicates.
+    pub fn creature_tags_in<'a>(&'a self, universe_id: &'a str) -> Vec<&'a str> {
+        let mut tags: Vec<&str> = Vec::new();
+
+        for tag in self.creatures_in(universe_id).flat_map(|c| &c.tags) {
+            if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                tags.push(tag);
+            }
+        }
+
+        tags.sort_by_key(|t| t.to_lowercase());
+        tags
+    }
+
+    /// Active creatures of the same universe sharing tags or habitat with `creature`,
+    /// most shared first.
+    pub fn related_creatures<'a>(&'a self, creature: &Creature) -> Vec<&'a Creature> {
+        let habitat = creature.habitat.trim();
+
+        let mut related: Vec<(usize, &Creature)> = self
+            .creatures
+            .iter()
+            .filter(|c| c.universe_id == creature.universe_id && c.id != creature.id && !c.archived)
+            .map(|c| {
+                let shared_tags = c
+                    .tags
+                    .iter()
+                    .filter(|tag| creature.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+                    .count();
+                let same_habitat = (!habitat.is_empty() && c.habitat.trim().eq_ignore_ascii_case(habitat))
+                    || c.habitat_locations.iter().any(|id| creature.habitat_locations.contains(id));
+
+                (shared_tags * 2 + usize::from(same_habitat), c)
+            })
+            .filter(|(score, _)| *score > 0)
+            .collect();
+
+        related.sort_by(|(a, x), (b, y)| b.cmp(a).then_with(|| x.name.cmp(&y.name)));
+        related.into_iter().map(|(_, c)| c).collect()
+    }
+
+    /// Extra creature fields of `universe_id`; none unless a template was defined.
+    pub fn creature_fields(&self, universe_id: &str) -> &[FieldDef] {
+        self.creature_templates
+            .iter()
+            .find(|t| t.universe_id == universe_id)
+            .map(|t| t.fields.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Replaces the universe's template; an empty one is dropped.
+    pub fn set_creature_template(&mut self, template: CreatureTemplate) {
+        self.creature_templates.retain(|t| t.universe_id != template.universe_id);
+        if !template.fields.is_empty() {
+            self.creature_templates.push(template);
+        }
+    }
+
+    pub fn location(&self, id: &str) -> Option<&Location> {
+        self.locations.iter().find(|l| l.id == id)
+    }
+
+    /// Locations that belong to `universe_id`.
+    pub fn locations_in<'a>(&'a self, universe_id: &'a str) -> impl Iterator<Item = &'a Location> + 'a {
+        self.locations.iter().filter(move |l| l.universe_id == universe_id)
+    }
+
+    /// The location and the places enclosing it, outermost first.
+    pub fn location_path(&self, id: &str) -> Vec<&Location> {
+        let mut path: Vec<&Location> = Vec::new();
+        let mut next = self.location(id);
+
+        // Parents are kept acyclic on save, but a hand-edited store shouldn't hang the app.
+        while let Some(l) = next.filter(|l| !path.iter().any(|p| p.id == l.id)) {
+            path.push(l);
+            next = l.parent_id.as_deref().and_then(|p| self.location(p));
+        }
+
+        path.reverse();
+        path
+    }
+
+    /// "Ashen Vale › Brumehold › Old Lighthouse".
+    pub fn location_label(&self, id: &str) -> String {
+        self.location_path(id)
+            .iter()
+            .map(|l| l.name.as_str())
+            .collect::<Vec<_>>()
+            .join(" › ")
+    }
+
+    /// Whether `id` is `ancestor` or lies somewhere inside it.
+    pub fn location_within(&self, id: &str, ancestor: &str) -> bool {
+        self.location_path(id).iter().any(|l| l.id == ancestor)
+    }
+
+    /// Creatures whose habitat references `location_id`.
+    pub fn creatures_at<'a>(&'a self, location_id: &'a str) -> impl Iterator<Item = &'a Creature> + 'a {
+        self.creatures
+            .iter()
+            .filter(move |c| c.habitat_locations.iter().any(|id| id == location_id))
+    }
+
+    pub fn character(&self, id: &str) -> Option<&Character> {
+        self.characters.iter().find(|c| c.id == id)
+    }
+
+    /// Characters that belong to `universe_id`.
+    pub fn characters_in<'a>(&'a self, universe_id: &'a str) -> impl Iterator<Item = &'a Character> + 'a {
+        self.characters.iter().filter(move |c| c.universe_id == universe_id)
+    }
+
+    /// Relationships other characters hold towards `character_id`, with who holds them.
+    pub fn relationships_towards<'a>(
+        &'a self,
+        character_id: &'a str,
+    ) -> impl Iterator<Item = (&'a Character, &'a Relationship)> + 'a {
+        self.characters.iter().flat_map(move |c| {
+            c.relationships
+                .iter()
+                .filter(move |r| r.character_id == character_id)
+                .map(move |r| (c, r))
+        })
+    }
+
+    pub fn faction(&self, id: &str) -> Option<&Faction> {
+        self.factions.iter().find(|f| f.id == id)
+    }
+
+    /// Factions that belong to `universe_id`.
+    pub fn factions_in<'a>(&'a self, universe_id: &'a str) -> impl Iterator<Item = &'a Faction> + 'a {
+        self.factions.iter().filter(move |f| f.universe_id == universe_id)
+    }
+
+    /// Members of `faction_id` with their membership.
+    pub fn faction_members<'a>(
+        &'a self,
+        faction_id: &'a str,
+    ) -> impl Iterator<Item = (&'a Character, &'a Membership)> + 'a {
+        self.characters.iter().filter_map(move |c| {
+            c.memberships.iter().find(|m| m.faction_id == faction_id).map(|m| (c, m))
+        })
+    }
+
+    pub fn term(&self, id: &str) -> Option<&Term> {
+        self.terms.iter().find(|t| t.id == id)
+    }
+
+    /// Runomicon entries of `universe_id`, including archived ones.
+    pub fn terms_in<'a>(&'a self, universe_id: &'a str) -> impl Iterator<Item = &'a Term> + 'a {
+        self.terms.iter().filter(move |t| t.universe_id == universe_id)
+    }
+
+    /// Terms listing `term_id` under "see also".
+    pub fn terms_linking<'a>(&'a self, term_id: &'a str) -> impl Iterator<Item = &'a Term> + 'a {
+        self.terms.iter().filter(move |t| t.see_also.iter().any(|id| id == term_id))
+    }
+
+    pub fn artifact(&self, id: &str) -> Option<&Artifact> {
+        self.artifacts.iter().find(|a| a.id == id)
+    }
+
+    /// Artifacts of `universe_id`, including archived ones.
+    pub fn artifacts_in<'a>(&'a self, universe_id: &'a str) -> impl Iterator<Item = &'a Artifact> + 'a {
+        self.artifacts.iter().filter(move |a| a.universe_id == universe_id)
+    }
+
+    /// Artifacts currently held by `holder`.
+    pub fn artifacts_held_by<'a>(&'a self, holder: &'a Holder) -> impl Iterator<Item = &'a Artifact> + 'a {
+        self.artifacts.iter().filter(move |a| a.holder.as_ref() == Some(holder))
+    }
+
+    /// Display name of a holder, or `None` once it no longer exists.
+    pub fn holder_name(&self, holder: &Holder) -> Option<&str> {
+        match holder {
+            Holder::Character(id) => self.character(id).map(|c| c.name.as_str()),
+            Holder::Creature(id) => self.creature(id).map(|c| c.name.as_str()),
+        }
+    }
+
+    pub fn new_universe_id(&self, name: &str) -> String {
+        unique_id(name, "universe", |id| self.universe(id).is_some())
+    }
+
+    /// The universe's calendar, or the default twelve-month one if it was never customised.
+    pub fn calendar(&self, universe_id: &str) -> Calendar {
+        self.calendars
+            .iter()
+            .find(|c| c.universe_id == universe_id)
+            .cloned()
+            .unwrap_or_else(|| Calendar::default_for(universe_id))
+    }
+
+    /// Events dated past a month or day the calendar no longer has move onto its last one.
+    pub fn set_calendar(&mut self, calendar: Calendar) {
+        for e in self.events.iter_mut().filter(|e| e.universe_id == calendar.universe_id) {
+            e.date = calendar.clamp(e.date);
+        }
+        self.calendars.retain(|c| c.universe_id != calendar.universe_id);
+        self.calendars.push(calendar);
+    }
+
+    /// Eras of `universe_id`, oldest first.
+    pub fn eras_in(&self, universe_id: &str) -> Vec<&Era> {
+        let mut eras: Vec<&Era> = self.eras.iter().filter(|e| e.universe_id == universe_id).collect();
+        eras.sort_by_key(|e| e.start_year);
+        eras
+    }
+
+    /// Events of `universe_id` in chronological order.
+    pub fn events_in(&self, universe_id: &str) -> Vec<&TimelineEvent> {
+        let mut events: Vec<&TimelineEvent> =
+            self.events.iter().filter(|e| e.universe_id == universe_id).collect();
+        events.sort_by_key(|e| e.date);
+        events
+    }
+
+    pub fn new_era_id(&self, name: &str) -> String {
+        unique_id(name, "era", |id| self.eras.iter().any(|e| e.id == id))
+    }
+
+    pub fn new_event_id(&self, title: &str) -> String {
+        unique_id(title, "event", |id| self.events.iter().any(|e| e.id == id))
+    }
+
+    pub fn manuscript(&self, id: &str) -> Option<&Manuscript> {
+        self.manuscripts.iter().find(|m| m.id == id)
+    }
+
+    pub fn manuscript_mut(&mut self, id: &str) -> Option<&mut Manuscript> {
+        self.manuscripts.iter_mut().find(|m| m.id == id)
+    }
+
+    pub fn new_manuscript_id(&self, title: &str) -> String {
+        unique_id(title, "manuscript", |id| self.manuscript(id).is_some())
+    }
+
+    pub fn board(&self, id: &str) -> Option<&Board> {
+        self.boards.iter().find(|b| b.id == id)
+    }
+
+    pub fn board_mut(&mut self, id: &str) -> Option<&mut Board> {
+        self.boards.iter_mut().find(|b| b.id == id)
+    }
+
+    pub fn new_board_id(&self, name: &str) -> String {
+        unique_id(name, "board", |id| self.board(id).is_some())
+    }
+
+    pub fn boards_linked_to<'a>(&'a self, universe_id: &'a str) -> impl Iterator<Item = &'a Board> + 'a {
+        self.boards
+            .iter()
+            .filter(move |b| b.universe_id.as_deref() == Some(universe_id))
+    }
+
+    pub fn asset(&self, id: &str) -> Option<&Asset> {
+        self.assets.iter().find(|a| a.id == id)
+    }
+
+    pub fn asset_mut(&mut self, id: &str) -> Option<&mut Asset> {
+        self.assets.iter_mut().find(|a| a.id == id)
+    }
+
+    pub fn new_asset_id(&self, name: &str) -> String {
+        unique_id(name, "asset", |id| self.asset(id).is_some())
+    }
+
+    pub fn assets_for_universe<'a>(&'a self, universe_id: &'a str) -> impl Iterator<Item = &'a Asset> + 'a {
+        self.assets
+            .iter()
+            .filter(move |a| a.universe_id.as_deref() == Some(universe_id))
+    }
+
+    pub fn assets_for_creature<'a>(&'a self, creature_id: &'a str) -> impl Iterator<Item = &'a Asset> + 'a {
+        self.assets
+            .iter()
+            .filter(move |a| a.creature_id.as_deref() == Some(creature_id))
+    }
+
+    /// Removes a creature; assets attached to it stay in the library.
+    pub fn remove_creature(&mut self, id: &str) {
+        self.creatures.retain(|c| c.id != id);
+
+        for a in self.assets.iter_mut().filter(|a| a.creature_id.as_deref() == Some(id)) {
+            a.creature_id = None;
+        }
+        self.drop_holder(&Holder::Creature(id.to_string()));
+    }
+
+    /// Removes a location. Places inside it move up to its parent, and creatures
+    /// simply stop referencing it.
+    pub fn remove_location(&mut self, id: &str) {
+        let Some(parent_id) = self.location(id).map(|l| l.parent_id.clone()) else {
+            return;
+        };
+
+        self.locations.retain(|l| l.id != id);
+
+        for l in self.locations.iter_mut().filter(|l| l.parent_id.as_deref() == Some(id)) {
+            l.parent_id = parent_id.clone();
+        }
+        for c in &mut self.creatures {
+            c.habitat_locations.retain(|l| l != id);
+        }
+    }
+
+    /// Removes a character and every relationship pointing at it.
+    pub fn remove_character(&mut self, id: &str) {
+        self.characters.retain(|c| c.id != id);
+
+        for c in &mut self.characters {
+            c.relationships.retain(|r| r.character_id != id);
+        }
+        self.drop_holder(&Holder::Character(id.to_string()));
+    }
+
+    /// Removes a faction; its members stay, just without that membership.
+    pub fn remove_faction(&mut self, id: &str) {
+        self.factions.retain(|f| f.id != id);
+
+        for c in &mut self.characters {
+            c.memberships.retain(|m| m.faction_id != id);
+        }
+        for f in &mut self.factions {
+            f.allegiances.retain(|a| a.faction_id != id);
+        }
+    }
+
+    /// Removes a Runomicon entry and every "see also" link to it.
+    pub fn remove_term(&mut self, id: &str) {
+        self.terms.retain(|t| t.id != id);
+
+        for t in &mut self.terms {
+            t.see_also.retain(|other| other != id);
+        }
+    }
+
+    /// Removes an artifact.
+    pub fn remove_artifact(&mut self, id: &str) {
+        self.artifacts.retain(|a| a.id != id);
+    }
+
+    /// Artifacts held by a removed character or creature are left without a holder.
+    fn drop_holder(&mut self, holder: &Holder) {
+        for a in self.artifacts.iter_mut().filter(|a| a.holder.as_ref() == Some(holder)) {
+            a.holder = None;
+        }
+    }
+
+    /// Removes a universe together with everything scoped to it.
+    pub fn remove_universe(&mut self, universe_id: &str) {
+        let entries: Vec<(Codex, String)> = self
+            .universe_entries(universe_id)
+            .map(|e| (e.codex(), e.id().to_string()))
+            .collect();
+        for (codex, id) in &entries {
+            self.remove_entry(*codex, id);
+        }
+
+        self.universes.retain(|u| u.id != universe_id);
+        self.calendars.retain(|c| c.universe_id != universe_id);
+        self.creature_templates.retain(|t| t.universe_id != universe_id);
+        self.eras.retain(|e| e.universe_id != universe_id);
+        self.events.retain(|e| e.universe_id != universe_id);
+
+        // Boards and assets are only linked, not owned: they survive without the universe.
+        for b in self.boards.iter_mut().filter(|b| b.universe_id.as_deref() == Some(universe_id)) {
+            b.universe_id = None;
+        }
+        for a in self.assets.iter_mut().filter(|a| a.universe_id.as_deref() == Some(universe_id)) {
+            a.universe_id = None;
+        }
+    }
+
+    /// Older stores kept a single global creature list; hand those to the first universe.
+    fn adopt_orphan_creatures(&mut self) {
+        let Some(first) = self.universes.first().map(|u| u.id.clone()) else {
+            return;
+        };
+
+        for c in self.creatures.iter_mut().filter(|c| c.universe_id.is_empty()) {
+            c.universe_id = first.clone();
+        }
+    }
+
+    /// Stores from before workspaces get one, named like the header pill always read.
+    /// Anything without an owner joins the first workspace.
+    fn adopt_orphans_into_workspace(&mut self) {
+        if self.workspaces.is_empty() {
+            self.workspaces.push(Workspace {
+                id: "arhelis".to_string(),
+                name: "Arhelis".to_string(),
+            });
+        }
+
+        let first = self.workspaces[0].id.clone();
+
+        for u in self.universes.iter_mut().filter(|u| u.workspace_id.is_empty()) {
+            u.workspace_id = first.clone();
+        }
+        for m in self.manuscripts.iter_mut().filter(|m| m.workspace_id.is_empty()) {
+            m.workspace_id = first.clone();
+        }
+        for b in self.boards.iter_mut().filter(|b| b.workspace_id.is_empty()) {
+            b.workspace_id = first.clone();
+        }
+        for a in self.assets.iter_mut().filter(|a| a.workspace_id.is_empty()) {
+            a.workspace_id = first.clone();
+        }
+
+        if self.workspace(&self.active_workspace).is_none() {
+            self.active_workspace = first;
+        }
+    }
+
+    /// Free-text danger strings from older stores become custom levels of their universe.
+    fn register_custom_danger_levels(&mut self) {
+        for c in &self.creatures {
+            let DangerLevel::Custom(name) = &c.danger else {
+                continue;
+            };
+
+            if let Some(u) = self.universes.iter_mut().find(|u| u.id == c.universe_id)
+                && !u.danger_levels.iter().any(|l| l.eq_ignore_ascii_case(name))
+            {
+                u.danger_levels.push(name.clone());
+            }
+        }
+    }
+
+    fn assign_missing_creature_ids(&mut self) {
+        for i in 0..self.creatures.len() {
+            if self.creatures[i].id.is_empty() {
+                self.creatures[i].id = self.new_entry_id::<Creature>(&self.creatures[i].name);
+            }
+        }
+    }
+}
+
+/// Readable, collision-free id: "Fog Engulfer" → `fog-engulfer`, then `fog-engulfer-2`, ...
+///
+/// Common Latin accents are folded and anything else non-alphanumeric becomes `-`;
+/// names with nothing usable fall back to `fallback`.
+pub fn unique_id(name: &str, fallback: &str, taken: impl Fn(&str) -> bool) -> String {
+    let mut base = slug(name);
+    if base.is_empty() {
+        base = fallback.to_string();
+    }
+
+    if !taken(&base) {
+        return base;
+    }
+
+    (2..)
+        .map(|n| format!("{base}-{n}"))
+        .find(|id| !taken(id))
+        .expect("an unused suffix always exists")
+}
+
+fn slug(name: &str) -> String {
+    let mut out = String::new();
+
+    for c in name.chars().flat_map(char::to_lowercase).map(fold_accent) {
+        if c.is_ascii_alphanumeric() {
+            out.push(c);
+        } else if !out.is_empty() && !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+
+    out.trim_end_matches('-').to_string()
+}
+
+fn fold_accent(c: char) -> char {
+    match c {
+        'á' | 'à' | 'â' | 'ä' | 'ã' | 'å' => 'a',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'ó' | 'ò' | 'ô' | 'ö' | 'õ' | 'ø' => 'o',
+        'ú' | 'ù' | 'û' | 'ü' => 'u',
+        'ñ' => 'n',
+        'ç' => 'c',
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::fixture::project;
+
+    /// A store from before workspaces, creature ids and universe scoping, with the
+    /// old `kind` string and free-text danger.
+    const LEGACY: &str = r#"{
+        "universes": [
+            { "id": "arhelis", "name": "Arhelis", "description": "", "archived": false }
+        ],
+        "creatures": [
+            {
+                "name": "Fog Engulfer",
+                "kind": "Elemental · Relicto",
+                "habitat": "Marshes",
+                "description": "",
+                "danger": "Apocalyptic"
+            },
+            { "name": "Fog Engulfer", "kind": "", "habitat": "", "description": "", "danger": "high" }
+        ]
+    }"#;
+
+    fn legacy() -> Project {
+        let mut project: Project = serde_json::from_str(LEGACY).unwrap();
+        project.migrate();
+        project
+    }
+
+    #[test]
+    fn legacy_kind_becomes_tags() {
+        let project = legacy();
+        assert_eq!(project.creatures[0].tags, ["Elemental", "Relicto"]);
+        assert!(project.creatures[1].tags.is_empty());
+    }
+
+    #[test]
+    fn creatures_without_ids_or_universe_are_adopted() {
+        let project = legacy();
+        let ids: Vec<&str> = project.creatures.iter().map(|c| c.id.as_str()).collect();
+
+        assert_eq!(ids, ["fog-engulfer", "fog-engulfer-2"]);
+        assert!(project.creatures.iter().all(|c| c.universe_id == "arhelis"));
+    }
+
+    #[test]
+    fn data_from_before_workspaces_joins_one() {
+        let project = legacy();
+
+        assert_eq!(project.workspaces.len(), 1);
+        assert_eq!(project.active_workspace, project.workspaces[0].id);
+        assert_eq!(project.universes[0].workspace_id, project.workspaces[0].id);
+    }
+
+    #[test]
+    fn free_text_danger_becomes_a_custom_level_of_its_universe() {
+        let project = legacy();
+
+        assert_eq!(project.creatures[0].danger, DangerLevel::Custom("Apocalyptic".to_string()));
+        assert_eq!(project.creatures[1].danger, DangerLevel::High);
+        assert_eq!(project.universes[0].danger_levels, ["Apocalyptic"]);
+    }
+
+    #[test]
+    fn danger_level_is_stored_as_its_name() {
+        for level in [DangerLevel::Extreme, DangerLevel::Custom("Apocalyptic".to_string()), DangerLevel::Unrated] {
+            let json = serde_json::to_string(&level).unwrap();
+            assert_eq!(serde_json::from_str::<DangerLevel>(&json).unwrap(), level);
+        }
+        assert_eq!(DangerLevel::from("  ".to_string()), DangerLevel::Unrated);
+    }
+
+    #[test]
+    fn danger_rank_follows_the_universe_order_and_skips_unrated() {
+        let mut universe = legacy().universes.remove(0);
+        universe.danger_levels = vec!["Cataclysmic".to_string(), "Apocalyptic".to_string()];
+        let rank = |level: DangerLevel| universe.danger_rank(&level);
+
+        assert!(rank(DangerLevel::Low) < rank(DangerLevel::Extreme));
+        assert!(rank(DangerLevel::Extreme) < rank(DangerLevel::Custom("cataclysmic".to_string())));
+        assert!(rank(DangerLevel::Custom("Cataclysmic".to_string())) < rank(DangerLevel::Custom("Apocalyptic".to_string())));
+        assert_eq!(rank(DangerLevel::Unrated), None);
+    }
+
+    #[test]
+    fn migrating_twice_changes_nothing() {
+        let once = legacy();
+        let mut twice = once.clone();
+        twice.migrate();
+
+        assert_eq!(serde_json::to_value(&twice).unwrap(), serde_json::to_value(&once).unwrap());
+    }
+
+    #[test]
+    fn unique_id_slugs_names_and_skips_taken_ids() {
+        let taken = ["fog-engulfer", "fog-engulfer-2"];
+        let is_taken = |id: &str| taken.contains(&id);
+
+        assert_eq!(unique_id("Fog  Engulfer!", "creature", |_| false), "fog-engulfer");
+        assert_eq!(unique_id("Fog Engulfer", "creature", is_taken), "fog-engulfer-3");
+        assert_eq!(unique_id("Ñandú Álvarez", "creature", |_| false), "nandu-alvarez");
+        assert_eq!(unique_id("  ·  ", "creature", |_| false), "creature");
+    }
+
+    #[test]
+    fn lists_only_show_the_active_workspace() {
+        let mut project = project();
+        let universes = |p: &Project| p.workspace_universes().map(|u| u.id.clone()).collect::<Vec<_>>();
+
+        assert_eq!(universes(&project), ["arhelis", "sylvara"]);
+        assert_eq!(project.workspace_manuscripts().count(), 1);
+        assert_eq!(project.workspace_boards().count(), 1);
+        assert_eq!(project.workspace_assets().map(|a| a.id.as_str()).collect::<Vec<_>>(), ["rune"]);
+
+        project.active_workspace = "away".to_string();
+        assert_eq!(universes(&project), ["elsewhere"]);
+        assert_eq!(project.workspace_manuscripts().count(), 0);
+        assert_eq!(project.workspace_boards().count(), 0);
+        assert_eq!(project.workspace_assets().map(|a| a.id.as_str()).collect::<Vec<_>>(), ["notes"]);
+    }
+
+    #[test]
+    fn removing_a_workspace_takes_everything_it_owns() {
+        let mut project = project();
+        let removed = project.remove_workspace("home");
+
+        assert_eq!(removed.iter().map(|a| a.id.as_str()).collect::<Vec<_>>(), ["rune"]);
+        assert_eq!(project.active_workspace, "away");
+        assert_eq!(project.universes.len(), 1);
+        assert!(project.creatures.is_empty() && project.characters.is_empty() && project.locations.is_empty());
+        assert!(project.manuscripts.is_empty() && project.boards.is_empty());
+        assert_eq!(project.assets.len(), 1);
+
+        // The last workspace stays.
+        assert!(project.remove_workspace("away").is_empty());
+        assert!(project.workspace("away").is_some());
+        assert_eq!(project.universes.len(), 1);
+    }
+
+    #[test]
+    fn new_workspace_ids_skip_taken_ones() {
+        let project = project();
+        assert_eq!(project.new_workspace_id("Home"), "home-2");
+        assert_eq!(project.new_workspace_id("Third"), "third");
+    }
+}
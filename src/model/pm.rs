@@ -0,0 +1,231 @@
+use serde::{Deserialize, Serialize};
+
+use super::unique_id;
+
+/// A Kanban board. Optionally linked to a universe so it shows up on that universe's page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Board {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub workspace_id: String,
+    #[serde(default)]
+    pub universe_id: Option<String>,
+    pub columns: Vec<BoardColumn>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BoardColumn {
+    /// Unique within its board.
+    pub id: String,
+    pub title: String,
+    pub cards: Vec<Card>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Card {
+    /// Unique within its board.
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub assignee: String,
+    /// `YYYY-MM-DD`, validated by the card editor.
+    pub due: Option<String>,
+    pub labels: Vec<String>,
+}
+
+/// Direction for [`Board::move_card`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardMove {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl Board {
+    /// New board with the usual three columns.
+    pub fn new(id: String, workspace_id: String, name: String) -> Self {
+        let columns = ["To do", "In progress", "Done"]
+            .into_iter()
+            .map(|title| BoardColumn {
+                id: super::slug(title),
+                title: title.to_string(),
+                cards: Vec::new(),
+            })
+            .collect();
+
+        Self {
+            id,
+            workspace_id,
+            name,
+            universe_id: None,
+            columns,
+        }
+    }
+
+    pub fn card_count(&self) -> usize {
+        self.columns.iter().map(|c| c.cards.len()).sum()
+    }
+
+    pub fn column_mut(&mut self, id: &str) -> Option<&mut BoardColumn> {
+        self.columns.iter_mut().find(|c| c.id == id)
+    }
+
+    /// The card and the id of the column holding it.
+    pub fn card(&self, id: &str) -> Option<(&str, &Card)> {
+        self.columns
+            .iter()
+            .find_map(|col| col.cards.iter().find(|c| c.id == id).map(|c| (col.id.as_str(), c)))
+    }
+
+    pub fn card_mut(&mut self, id: &str) -> Option<&mut Card> {
+        self.columns.iter_mut().flat_map(|c| &mut c.cards).find(|c| c.id == id)
+    }
+
+    pub fn new_column_id(&self, title: &str) -> String {
+        unique_id(title, "column", |id| self.columns.iter().any(|c| c.id == id))
+    }
+
+    pub fn new_card_id(&self, title: &str) -> String {
+        unique_id(title, "card", |id| self.card(id).is_some())
+    }
+
+    pub fn move_column(&mut self, id: &str, left: bool) {
+        let Some(i) = self.columns.iter().position(|c| c.id == id) else {
+            return;
+        };
+
+        match left {
+            true if i > 0 => self.columns.swap(i, i - 1),
+            false if i + 1 < self.columns.len() => self.columns.swap(i, i + 1),
+            _ => {}
+        }
+    }
+
+    /// Left/Right moves the card to the end of the neighbouring column; Up/Down reorders it.
+    pub fn move_card(&mut self, id: &str, direction: CardMove) {
+        let Some((ci, i)) = self.columns.iter().enumerate().find_map(|(ci, col)| {
+            col.cards.iter().position(|c| c.id == id).map(|i| (ci, i))
+        }) else {
+            return;
+        };
+
+        let len = self.columns[ci].cards.len();
+
+        match direction {
+            CardMove::Left if ci > 0 => {
+                let card = self.columns[ci].cards.remove(i);
+                self.columns[ci - 1].cards.push(card);
+            }
+            CardMove::Right if ci + 1 < self.columns.len() => {
+                let card = self.columns[ci].cards.remove(i);
+                self.columns[ci + 1].cards.push(card);
+            }
+            CardMove::Up if i > 0 => self.columns[ci].cards.swap(i, i - 1),
+            CardMove::Down if i + 1 < len => self.columns[ci].cards.swap(i, i + 1),
+            _ => {}
+        }
+    }
+
+    pub fn remove_column(&mut self, id: &str) {
+        self.columns.retain(|c| c.id != id);
+    }
+
+    pub fn remove_card(&mut self, id: &str) {
+        for col in &mut self.columns {
+            col.cards.retain(|c| c.id != id);
+        }
+    }
+}
+
+/// Accepts `YYYY-MM-DD` with a plausible month and day.
+pub fn is_valid_due_date(s: &str) -> bool {
+    let parts: Vec<&str> = s.split('-').collect();
+
+    let [y, m, d] = parts.as_slice() else {
+        return false;
+    };
+
+    let digits = |p: &str, len: usize| p.len() == len && p.bytes().all(|b| b.is_ascii_digit());
+
+    if !(digits(y, 4) && digits(m, 2) && digits(d, 2)) {
+        return false;
+    }
+
+    let (m, d): (u32, u32) = (m.parse().unwrap_or(0), d.parse().unwrap_or(0));
+    (1..=12).contains(&m) && (1..=31).contains(&d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::fixture::project;
+
+    fn plans() -> Board {
+        project().boards.remove(0)
+    }
+
+    fn card_ids(b: &Board) -> Vec<Vec<&str>> {
+        b.columns.iter().map(|c| c.cards.iter().map(|c| c.id.as_str()).collect()).collect()
+    }
+
+    #[test]
+    fn new_boards_start_with_three_columns() {
+        let b = Board::new("b".into(), "home".into(), "B".into());
+        let titles: Vec<&str> = b.columns.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(titles, ["To do", "In progress", "Done"]);
+        assert_eq!(b.card_count(), 0);
+    }
+
+    #[test]
+    fn cards_move_across_columns_and_within_one() {
+        let mut b = plans();
+
+        b.move_card("names", CardMove::Up);
+        assert_eq!(card_ids(&b), [vec!["names", "map"], vec![]]);
+
+        b.move_card("names", CardMove::Right);
+        b.move_card("map", CardMove::Right);
+        assert_eq!(card_ids(&b), [vec![], vec!["names", "map"]]);
+        assert_eq!(b.card("map").map(|(col, _)| col), Some("done"));
+
+        // Edges leave the board as it is.
+        b.move_card("map", CardMove::Right);
+        b.move_card("map", CardMove::Down);
+        b.move_card("names", CardMove::Up);
+        assert_eq!(card_ids(&b), [vec![], vec!["names", "map"]]);
+    }
+
+    #[test]
+    fn columns_stop_at_either_end() {
+        let mut b = plans();
+        b.move_column("todo", true);
+        b.move_column("done", false);
+        assert_eq!(b, plans());
+
+        b.move_column("done", true);
+        assert_eq!(b.columns[0].id, "done");
+    }
+
+    #[test]
+    fn removing_a_column_takes_its_cards() {
+        let mut b = plans();
+        b.remove_card("map");
+        assert_eq!(b.card_count(), 1);
+        assert_ne!(b.new_card_id("Names"), "names");
+
+        b.remove_column("todo");
+        assert!(b.card("names").is_none());
+        assert_eq!(b.new_card_id("Names"), "names");
+        assert_ne!(b.new_column_id("Done"), "done");
+    }
+
+    #[test]
+    fn due_dates_are_iso_days() {
+        assert!(is_valid_due_date("2026-10-18"));
+        assert!(!is_valid_due_date("2026-13-01"));
+        assert!(!is_valid_due_date("2026-1-01"));
+        assert!(!is_valid_due_date("18/10/2026"));
+    }
+}
@@ -0,0 +1,386 @@
+use std::ops::Range;
+
+use super::{fold_accent, ActivityTarget, Codex, Project};
+
+/// Hits shown per group.
+const GROUP_LIMIT: usize = 5;
+
+/// Result groups, in the order used when scores tie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SearchGroup {
+    Universes,
+    Codex(Codex),
+    Forge,
+    Boards,
+    Assets,
+}
+
+impl SearchGroup {
+    pub fn label(self) -> &'static str {
+        match self {
+            SearchGroup::Universes => "Universes",
+            SearchGroup::Codex(codex) => codex.plural(),
+            SearchGroup::Forge => "The Forge",
+            SearchGroup::Boards => "Boards",
+            SearchGroup::Assets => "Assets",
+        }
+    }
+}
+
+/// Where the query matched outside the title, trimmed around the match.
+#[derive(Debug, Clone)]
+pub struct SearchSnippet {
+    pub field: &'static str,
+    pub text: String,
+    /// Byte range of the match inside `text`.
+    pub highlight: Range<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub target: ActivityTarget,
+    pub group: SearchGroup,
+    pub title: String,
+    /// Byte range of the match inside `title`, if the title matched.
+    pub title_highlight: Option<Range<usize>>,
+    pub snippet: Option<SearchSnippet>,
+    pub archived: bool,
+    score: u32,
+}
+
+/// Searches the active workspace. Groups come back ordered by their best hit,
+/// each holding at most a handful of hits, best first.
+pub fn search(project: &Project, query: &str) -> Vec<(SearchGroup, Vec<SearchHit>)> {
+    let needle: Vec<char> = query.trim().chars().map(fold).collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    let mut hits = Vec::new();
+    let mut push = |hit: Option<SearchHit>| hits.extend(hit);
+
+    for u in project.workspace_universes() {
+        push(match_item(
+            &needle,
+            ActivityTarget::Universe(u.id.clone()),
+            SearchGroup::Universes,
+            &u.name,
+            &[("Description", &u.description)],
+            u.archived,
+        ));
+
+        for e in project.universe_entries(&u.id) {
+            let fields = e.search_fields(project);
+            let fields: Vec<(&'static str, &str)> = fields.iter().map(|(field, text)| (*field, text.as_ref())).collect();
+            push(match_item(
+                &needle,
+                e.codex().target(e.id()),
+                SearchGroup::Codex(e.codex()),
+                e.name(),
+                &fields,
+                e.archived(),
+            ));
+        }
+    }
+
+    for m in project.workspace_manuscripts() {
+        push(match_item(
+            &needle,
+            ActivityTarget::Manuscript(m.id.clone()),
+            SearchGroup::Forge,
+            &m.title,
+            &[],
+            false,
+        ));
+
+        for s in m.chapters.iter().flat_map(|c| &c.scenes) {
+            let target = ActivityTarget::Scene {
+                manuscript_id: m.id.clone(),
+                scene_id: s.id.clone(),
+            };
+            push(match_item(&needle, target, SearchGroup::Forge, &s.title, &[("Scene", &s.body)], false));
+        }
+    }
+
+    for b in project.workspace_boards() {
+        push(match_item(&needle, ActivityTarget::Board(b.id.clone()), SearchGroup::Boards, &b.name, &[], false));
+    }
+
+    for a in project.workspace_assets() {
+        let tags = a.tags.join(", ");
+        push(match_item(
+            &needle,
+            ActivityTarget::Asset(a.id.clone()),
+            SearchGroup::Assets,
+            &a.name,
+            &[("Tags", &tags)],
+            false,
+        ));
+    }
+
+    hits.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.title.cmp(&b.title)));
+
+    let mut groups: Vec<(SearchGroup, Vec<SearchHit>)> = Vec::new();
+    for hit in hits {
+        match groups.iter_mut().find(|(g, _)| *g == hit.group) {
+            Some((_, list)) if list.len() >= GROUP_LIMIT => {}
+            Some((_, list)) => list.push(hit),
+            None => groups.push((hit.group, vec![hit])),
+        }
+    }
+
+    groups
+}
+
+/// An entry whose text names something, e.g. another creature's description.
+#[derive(Debug, Clone)]
+pub struct Mention {
+    pub target: ActivityTarget,
+    pub title: String,
+    pub snippet: SearchSnippet,
+}
+
+/// Entries of the active workspace whose text names `name` as a whole word,
+/// case- and accent-insensitively. `exclude` (the named entry itself) is skipped.
+pub fn mentions(project: &Project, name: &str, exclude: &ActivityTarget) -> Vec<Mention> {
+    let needle: Vec<char> = name.trim().chars().map(fold).collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    let mut found = Vec::new();
+    let mut check = |target: ActivityTarget, title: &str, fields: &[(&'static str, &str)]| {
+        if target == *exclude {
+            return;
+        }
+
+        let hit = fields
+            .iter()
+            .find_map(|(field, text)| find_word(text, &needle).map(|range| snippet(field, text, range)));
+
+        if let Some(snippet) = hit {
+            found.push(Mention {
+                target,
+                title: title.to_string(),
+                snippet,
+            });
+        }
+    };
+
+    for u in project.workspace_universes() {
+        check(ActivityTarget::Universe(u.id.clone()), &u.name, &[("Description", &u.description)]);
+
+        for e in project.universe_entries(&u.id) {
+            check(e.codex().target(e.id()), e.name(), &e.mention_fields());
+        }
+    }
+
+    for m in project.workspace_manuscripts() {
+        for s in m.chapters.iter().flat_map(|c| &c.scenes) {
+            let target = ActivityTarget::Scene {
+                manuscript_id: m.id.clone(),
+                scene_id: s.id.clone(),
+            };
+            check(target, &s.title, &[("Scene", &s.body)]);
+        }
+    }
+
+    for b in project.workspace_boards() {
+        for card in b.columns.iter().flat_map(|c| &c.cards) {
+            let title = format!("{} — {}", b.name, card.title);
+            check(
+                ActivityTarget::Board(b.id.clone()),
+                &title,
+                &[("Card", &card.title), ("Card", &card.description)],
+            );
+        }
+    }
+
+    found
+}
+
+/// Best match of `needle` in the item: title matches outrank any other field,
+/// and matches at the start of the text or of a word outrank ones mid-word.
+fn match_item(
+    needle: &[char],
+    target: ActivityTarget,
+    group: SearchGroup,
+    title: &str,
+    fields: &[(&'static str, &str)],
+    archived: bool,
+) -> Option<SearchHit> {
+    if let Some((range, at_word)) = find(title, needle) {
+        let score = if range.start == 0 && range.end == title.len() {
+            100
+        } else if range.start == 0 {
+            80
+        } else if at_word {
+            60
+        } else {
+            40
+        };
+
+        return Some(SearchHit {
+            target,
+            group,
+            title: title.to_string(),
+            title_highlight: Some(range),
+            snippet: None,
+            archived,
+            score,
+        });
+    }
+
+    let (field, text, range, at_word) = fields
+        .iter()
+        .filter_map(|(field, text)| find(text, needle).map(|(range, at_word)| (*field, *text, range, at_word)))
+        .max_by_key(|(_, _, _, at_word)| *at_word)?;
+
+    Some(SearchHit {
+        target,
+        group,
+        title: title.to_string(),
+        title_highlight: None,
+        snippet: Some(snippet(field, text, range)),
+        archived,
+        score: if at_word { 25 } else { 15 },
+    })
+}
+
+/// First case- and accent-insensitive occurrence of `needle`, preferring one that
+/// starts a word. Returns its byte range and whether it starts a word.
+fn find(hay: &str, needle: &[char]) -> Option<(Range<usize>, bool)> {
+    let chars: Vec<(usize, char)> = hay.char_indices().collect();
+    let mut first = None;
+
+    for start in 0..chars.len() {
+        let Some(window) = chars.get(start..start + needle.len()) else {
+            break;
+        };
+
+        if !window.iter().zip(needle).all(|((_, c), n)| fold(*c) == *n) {
+            continue;
+        }
+
+        let end = chars.get(start + needle.len()).map(|(i, _)| *i).unwrap_or(hay.len());
+        let range = chars[start].0..end;
+        let at_word = start == 0 || !chars[start - 1].1.is_alphanumeric();
+
+        if at_word {
+            return Some((range, true));
+        }
+        first.get_or_insert((range, false));
+    }
+
+    first
+}
+
+/// First occurrence of `needle` that is a whole word of `hay`.
+fn find_word(hay: &str, needle: &[char]) -> Option<Range<usize>> {
+    let chars: Vec<(usize, char)> = hay.char_indices().collect();
+    let is_word = |i: usize| chars.get(i).is_some_and(|(_, c)| c.is_alphanumeric());
+
+    (0..chars.len()).find_map(|start| {
+        let window = chars.get(start..start + needle.len())?;
+        let matches = window.iter().zip(needle).all(|((_, c), n)| fold(*c) == *n);
+        let bounded = (start == 0 || !is_word(start - 1)) && !is_word(start + needle.len());
+
+        (matches && bounded).then(|| {
+            let end = chars.get(start + needle.len()).map(|(i, _)| *i).unwrap_or(hay.len());
+            chars[start].0..end
+        })
+    })
+}
+
+fn fold(c: char) -> char {
+    fold_accent(c.to_lowercase().next().unwrap_or(c))
+}
+
+/// Cuts long text down to a window around the match, with ellipses where trimmed.
+fn snippet(field: &'static str, text: &str, range: Range<usize>) -> SearchSnippet {
+    const BEFORE: usize = 30;
+    const AFTER: usize = 60;
+
+    let start = text[..range.start]
+        .char_indices()
+        .rev()
+        .nth(BEFORE - 1)
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    let end = text[range.end..]
+        .char_indices()
+        .nth(AFTER)
+        .map(|(i, _)| range.end + i)
+        .unwrap_or(text.len());
+
+    let prefix = if start > 0 { "…" } else { "" };
+    let suffix = if end < text.len() { "…" } else { "" };
+
+    // Multi-line bodies read better flattened onto one line.
+    let flat = |s: &str| s.replace(['\n', '\r'], " ");
+    let before = format!("{prefix}{}", flat(&text[start..range.start]));
+    let matched = flat(&text[range.clone()]);
+    let after = format!("{}{suffix}", flat(&text[range.end..end]));
+
+    SearchSnippet {
+        field,
+        highlight: before.len()..before.len() + matched.len(),
+        text: format!("{before}{matched}{after}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::fixture::project;
+
+    fn hits(project: &Project, query: &str) -> Vec<SearchHit> {
+        search(project, query).into_iter().flat_map(|(_, hits)| hits).collect()
+    }
+
+    #[test]
+    fn accents_and_case_are_ignored_both_ways() {
+        let mut project = project();
+        project.creatures[0].name = "Éter".to_string();
+
+        let hit = hits(&project, "eter").into_iter().next().unwrap();
+        assert_eq!(hit.title, "Éter");
+        assert_eq!(hit.title_highlight, Some(0.."Éter".len()));
+
+        project.creatures[0].name = "Eter".to_string();
+        assert_eq!(hits(&project, "ÉTER")[0].title, "Eter");
+    }
+
+    #[test]
+    fn snippets_flatten_lines_and_trim_around_the_match() {
+        let mut project = project();
+        let padding = "mist ".repeat(20);
+        project.universes[0].description = format!("{padding}\nthe fog\r\nrolls in\n{padding}");
+
+        let hit = hits(&project, "fog").into_iter().next().unwrap();
+        let snippet = hit.snippet.unwrap();
+
+        assert_eq!(snippet.field, "Description");
+        assert!(!snippet.text.contains(['\n', '\r']));
+        assert!(snippet.text.starts_with('…') && snippet.text.ends_with('…'));
+        assert_eq!(&snippet.text[snippet.highlight], "fog");
+    }
+
+    #[test]
+    fn name_matches_rank_above_description_matches() {
+        let mut project = project();
+        project.creatures[1].description = "Feeds on wisps.".to_string();
+
+        let (group, found) = search(&project, "wisp").into_iter().next().unwrap();
+        let titles: Vec<&str> = found.iter().map(|h| h.title.as_str()).collect();
+
+        assert_eq!(group, SearchGroup::Codex(Codex::Creature));
+        assert_eq!(titles, ["Wisp", "Wyrm"]);
+        assert!(found[1].snippet.is_some());
+    }
+
+    #[test]
+    fn other_workspaces_are_left_out() {
+        assert!(hits(&project(), "Elsewhere").is_empty());
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Extra fields creature sheets of one universe carry on top of the built-in ones.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreatureTemplate {
+    pub universe_id: String,
+    pub fields: Vec<FieldDef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldDef {
+    /// Unique within its template; keys [`super::Creature::fields`].
+    pub id: String,
+    pub label: String,
+    pub kind: FieldKind,
+    /// Allowed values of an [`FieldKind::Enum`] field.
+    #[serde(default)]
+    pub options: Vec<String>,
+    /// Whether the creature card summarizes this field.
+    #[serde(default)]
+    pub on_card: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FieldKind {
+    Text,
+    Number,
+    Enum,
+    List,
+    LongText,
+}
+
+impl FieldKind {
+    pub const ALL: [FieldKind; 5] = [
+        FieldKind::Text,
+        FieldKind::Number,
+        FieldKind::Enum,
+        FieldKind::List,
+        FieldKind::LongText,
+    ];
+}
+
+impl fmt::Display for FieldKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FieldKind::Text => "Text",
+            FieldKind::Number => "Number",
+            FieldKind::Enum => "Choice",
+            FieldKind::List => "List",
+            FieldKind::LongText => "Long text",
+        })
+    }
+}
+
+/// A creature's value for one template field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FieldValue {
+    Text(String),
+    Number(f64),
+    List(Vec<String>),
+}
+
+impl FieldValue {
+    /// Parses form input for a field of `kind`. Empty input means "no value";
+    /// `Err` carries the message shown under the input.
+    pub fn parse(kind: FieldKind, input: &str) -> Result<Option<FieldValue>, String> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(match kind {
+            FieldKind::Text | FieldKind::Enum | FieldKind::LongText => FieldValue::Text(input.to_string()),
+            FieldKind::Number => FieldValue::Number(input.parse().map_err(|_| "Must be a number".to_string())?),
+            FieldKind::List => FieldValue::List(
+                input
+                    .split([',', '\n'])
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            ),
+        }))
+    }
+
+    /// Form input that parses back to this value.
+    pub fn to_input(&self) -> String {
+        match self {
+            FieldValue::Text(s) => s.clone(),
+            FieldValue::Number(n) => n.to_string(),
+            FieldValue::List(items) => items.join(", "),
+        }
+    }
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_input())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::fixture::project;
+
+    #[test]
+    fn input_parses_by_field_kind() {
+        assert_eq!(FieldValue::parse(FieldKind::Text, "  "), Ok(None));
+        assert_eq!(FieldValue::parse(FieldKind::Enum, " Meat "), Ok(Some(FieldValue::Text("Meat".into()))));
+        assert_eq!(FieldValue::parse(FieldKind::Number, "12.5"), Ok(Some(FieldValue::Number(12.5))));
+        assert_eq!(FieldValue::parse(FieldKind::Number, "twelve"), Err("Must be a number".to_string()));
+
+        let list = FieldValue::parse(FieldKind::List, "fire, ,ice\nsalt").unwrap().unwrap();
+        assert_eq!(list, FieldValue::List(vec!["fire".into(), "ice".into(), "salt".into()]));
+    }
+
+    #[test]
+    fn values_survive_a_trip_through_the_form() {
+        for (kind, value) in [
+            (FieldKind::Number, FieldValue::Number(-3.25)),
+            (FieldKind::List, FieldValue::List(vec!["fire".into(), "ice".into()])),
+            (FieldKind::LongText, FieldValue::Text("Line one\nline two".into())),
+        ] {
+            assert_eq!(FieldValue::parse(kind, &value.to_input()), Ok(Some(value)));
+        }
+    }
+
+    #[test]
+    fn an_empty_template_is_dropped() {
+        let mut project = project();
+        let field = FieldDef {
+            id: "diet".into(),
+            label: "Diet".into(),
+            kind: FieldKind::Text,
+            options: Vec::new(),
+            on_card: true,
+        };
+
+        project.set_creature_template(CreatureTemplate {
+            universe_id: "arhelis".into(),
+            fields: vec![field.clone()],
+        });
+        assert_eq!(project.creature_fields("arhelis"), [field]);
+        assert!(project.creature_fields("sylvara").is_empty());
+
+        project.set_creature_template(CreatureTemplate {
+            universe_id: "arhelis".into(),
+            fields: Vec::new(),
+        });
+        assert!(project.creature_templates.is_empty());
+    }
+}
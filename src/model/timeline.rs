@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+/// Per-universe calendar. Fantasy years rarely map to Gregorian, so months, their length
+/// and the year suffix ("AR", "Year of the Tide", ...) are all user-defined.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Calendar {
+    pub universe_id: String,
+    pub months: Vec<String>,
+    pub days_per_month: u32,
+    pub year_suffix: String,
+}
+
+impl Calendar {
+    pub fn default_for(universe_id: &str) -> Self {
+        Self {
+            universe_id: universe_id.to_string(),
+            months: (1..=12).map(|m| format!("Month {m}")).collect(),
+            days_per_month: 30,
+            year_suffix: String::new(),
+        }
+    }
+
+    pub fn month_name(&self, month: u32) -> Option<&str> {
+        let index = usize::try_from(month).ok()?.checked_sub(1)?;
+        self.months.get(index).map(String::as_str)
+    }
+
+    /// "12 Frostmoon 340 AR", "Frostmoon 340 AR" or "340 AR" depending on precision.
+    pub fn format(&self, date: FantasyDate) -> String {
+        let mut out = String::new();
+
+        if let Some(month) = self.month_name(date.month) {
+            if date.day > 0 {
+                out.push_str(&format!("{} ", date.day));
+            }
+            out.push_str(month);
+            out.push(' ');
+        }
+
+        out.push_str(&date.year.to_string());
+
+        if !self.year_suffix.is_empty() {
+            out.push(' ');
+            out.push_str(&self.year_suffix);
+        }
+
+        out
+    }
+
+    /// `date` with a month or day this calendar doesn't have moved onto its last one.
+    pub fn clamp(&self, date: FantasyDate) -> FantasyDate {
+        let months = u32::try_from(self.months.len()).unwrap_or(u32::MAX);
+        FantasyDate {
+            month: date.month.min(months),
+            day: date.day.min(self.days_per_month),
+            ..date
+        }
+    }
+}
+
+/// A date in a universe's [`Calendar`]. `month`/`day` are 1-based; `0` means "unknown",
+/// so events can be pinned to just a year or a month.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct FantasyDate {
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Era {
+    pub id: String,
+    pub universe_id: String,
+    pub name: String,
+    pub description: String,
+    pub start_year: i64,
+    /// `None` for an era that is still ongoing.
+    pub end_year: Option<i64>,
+}
+
+impl Era {
+    pub fn contains(&self, year: i64) -> bool {
+        year >= self.start_year && self.end_year.is_none_or(|end| year <= end)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimelineEvent {
+    pub id: String,
+    pub universe_id: String,
+    pub title: String,
+    pub description: String,
+    pub date: FantasyDate,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calendar() -> Calendar {
+        Calendar {
+            months: vec!["Thaw".to_string(), "Frostmoon".to_string()],
+            days_per_month: 20,
+            year_suffix: "AR".to_string(),
+            ..Calendar::default_for("arhelis")
+        }
+    }
+
+    fn date(year: i64, month: u32, day: u32) -> FantasyDate {
+        FantasyDate { year, month, day }
+    }
+
+    #[test]
+    fn format_shows_only_the_known_parts() {
+        let calendar = calendar();
+        assert_eq!(calendar.format(date(340, 0, 0)), "340 AR");
+        assert_eq!(calendar.format(date(340, 2, 0)), "Frostmoon 340 AR");
+        assert_eq!(calendar.format(date(340, 2, 12)), "12 Frostmoon 340 AR");
+
+        let plain = Calendar::default_for("arhelis");
+        assert_eq!(plain.format(date(-5, 1, 3)), "3 Month 1 -5");
+    }
+
+    #[test]
+    fn clamp_keeps_dates_inside_the_calendar() {
+        let calendar = calendar();
+        assert_eq!(calendar.clamp(date(340, 7, 28)), date(340, 2, 20));
+        assert_eq!(calendar.clamp(date(340, 1, 5)), date(340, 1, 5));
+        assert_eq!(calendar.clamp(date(340, 0, 0)), date(340, 0, 0));
+    }
+}
@@ -0,0 +1,418 @@
+//! Undo/redo of universe and codex edits: creatures, locations, characters, factions,
+//! Runomicon entries and artifacts.
+//!
+//! A [`Command`] keeps only the records an action changed, as they were before and
+//! after it, so undoing one never reverts unrelated edits made since.
+
+use std::collections::HashMap;
+
+use super::{Calendar, Codex, CreatureTemplate, Era, OwnedEntry, Project, TimelineEvent, Universe};
+
+/// Oldest commands are dropped past this many.
+const MAX_COMMANDS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Key {
+    Universe(String),
+    Entry(Codex, String),
+    /// By universe id.
+    CreatureTemplate(String),
+    /// By universe id.
+    Calendar(String),
+    Era(String),
+    Event(String),
+    Board(String),
+    Asset(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Record {
+    Universe(Universe),
+    Entry(OwnedEntry),
+    CreatureTemplate(CreatureTemplate),
+    Calendar(Calendar),
+    Era(Era),
+    Event(TimelineEvent),
+    /// [`super::Board::universe_id`]: boards are only linked to universes.
+    BoardLink(Option<String>),
+    /// [`super::Asset::universe_id`] and [`super::Asset::creature_id`].
+    AssetLink(Option<String>, Option<String>),
+}
+
+/// A record and its position in its list.
+type Slot = (Record, usize);
+
+/// Every record universe and codex actions can touch, taken before and after one.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    records: HashMap<Key, Slot>,
+}
+
+impl Snapshot {
+    pub fn take(project: &Project) -> Self {
+        let mut records = HashMap::new();
+        let mut add = |key: Key, record: Record, index: usize| {
+            records.insert(key, (record, index));
+        };
+
+        for (i, u) in project.universes.iter().enumerate() {
+            add(Key::Universe(u.id.clone()), Record::Universe(u.clone()), i);
+        }
+        // Indexes count within each codex's own list.
+        let mut next_index: HashMap<Codex, usize> = HashMap::new();
+        for e in project.entries() {
+            let index = next_index.entry(e.codex()).or_default();
+            add(Key::Entry(e.codex(), e.id().to_string()), Record::Entry(e.to_owned_entry()), *index);
+            *index += 1;
+        }
+        for (i, t) in project.creature_templates.iter().enumerate() {
+            add(Key::CreatureTemplate(t.universe_id.clone()), Record::CreatureTemplate(t.clone()), i);
+        }
+        for (i, c) in project.calendars.iter().enumerate() {
+            add(Key::Calendar(c.universe_id.clone()), Record::Calendar(c.clone()), i);
+        }
+        for (i, e) in project.eras.iter().enumerate() {
+            add(Key::Era(e.id.clone()), Record::Era(e.clone()), i);
+        }
+        for (i, e) in project.events.iter().enumerate() {
+            add(Key::Event(e.id.clone()), Record::Event(e.clone()), i);
+        }
+        for (i, b) in project.boards.iter().enumerate() {
+            add(Key::Board(b.id.clone()), Record::BoardLink(b.universe_id.clone()), i);
+        }
+        for (i, a) in project.assets.iter().enumerate() {
+            add(
+                Key::Asset(a.id.clone()),
+                Record::AssetLink(a.universe_id.clone(), a.creature_id.clone()),
+                i,
+            );
+        }
+
+        Self { records }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Change {
+    key: Key,
+    before: Option<Slot>,
+    after: Option<Slot>,
+}
+
+/// One undoable action, e.g. `Deleted creature "Silt Siren"`.
+#[derive(Debug, Clone)]
+pub struct Command {
+    pub label: String,
+    changes: Vec<Change>,
+    /// What the last undo or redo changed beyond `changes`, e.g. the timeline dropped
+    /// with a universe whose creation was undone. The next redo or undo puts it back.
+    collateral: Vec<Change>,
+}
+
+impl Command {
+    /// `None` when the action changed nothing.
+    pub fn between(label: String, before: &Snapshot, after: &Snapshot) -> Option<Self> {
+        let changes = changes(before, after);
+        (!changes.is_empty()).then_some(Self {
+            label,
+            changes,
+            collateral: Vec::new(),
+        })
+    }
+
+    fn undo(&mut self, project: &mut Project) {
+        self.replay(project, |c| c.before.as_ref());
+    }
+
+    fn redo(&mut self, project: &mut Project) {
+        self.replay(project, |c| c.after.as_ref());
+    }
+
+    /// Restores `slot` of every change, undoing the collateral of the previous replay.
+    fn replay(&mut self, project: &mut Project, slot: impl Fn(&Change) -> Option<&Slot>) {
+        let start = Snapshot::take(project);
+        restore(
+            project,
+            self.changes
+                .iter()
+                .map(|c| (&c.key, slot(c)))
+                .chain(self.collateral.iter().map(|c| (&c.key, c.before.as_ref()))),
+        );
+
+        let own = |key: &Key| self.changes.iter().any(|c| c.key == *key);
+        let collateral = changes(&start, &Snapshot::take(project)).into_iter().filter(|c| !own(&c.key)).collect();
+        self.collateral = collateral;
+    }
+}
+
+/// Every record that differs between two snapshots.
+fn changes(before: &Snapshot, after: &Snapshot) -> Vec<Change> {
+    let mut changes: Vec<Change> = before
+        .records
+        .iter()
+        .filter(|(key, slot)| after.records.get(*key).map(|s| &s.0) != Some(&slot.0))
+        .map(|(key, slot)| Change {
+            key: key.clone(),
+            before: Some(slot.clone()),
+            after: after.records.get(key).cloned(),
+        })
+        .collect();
+
+    changes.extend(
+        after
+            .records
+            .iter()
+            .filter(|(key, _)| !before.records.contains_key(*key))
+            .map(|(key, slot)| Change {
+                key: key.clone(),
+                before: None,
+                after: Some(slot.clone()),
+            }),
+    );
+
+    changes
+}
+
+/// Puts each record back in place; `None` removes it. Removals run first and
+/// insertions in list order, so restored records land at their old positions.
+fn restore<'a>(project: &mut Project, slots: impl Iterator<Item = (&'a Key, Option<&'a Slot>)>) {
+    let (mut present, absent): (Vec<_>, Vec<_>) = slots.partition(|(_, slot)| slot.is_some());
+
+    for (key, _) in absent {
+        put(project, key, None);
+    }
+
+    present.sort_by_key(|(_, slot)| slot.map(|(_, index)| *index));
+    for &(key, slot) in &present {
+        put(project, key, slot.cloned());
+    }
+
+    // A universe whose workspace is gone was not put back; nor is anything restored with it.
+    for (key, _) in present {
+        if let Key::Universe(id) = key
+            && project.universe(id).is_none()
+        {
+            project.remove_universe(id);
+        }
+    }
+}
+
+fn put(project: &mut Project, key: &Key, slot: Option<Slot>) {
+    let Some((record, index)) = slot else {
+        remove(project, key);
+        return;
+    };
+
+    match (key, record) {
+        (Key::Universe(id), Record::Universe(u)) if project.workspace(&u.workspace_id).is_some() => {
+            put_in(&mut project.universes, |x| x.id == *id, index, u)
+        }
+        (Key::Entry(..), Record::Entry(e)) => e.put(project, index),
+        (Key::CreatureTemplate(id), Record::CreatureTemplate(t)) => {
+            put_in(&mut project.creature_templates, |x| x.universe_id == *id, index, t)
+        }
+        (Key::Calendar(id), Record::Calendar(c)) => {
+            put_in(&mut project.calendars, |x| x.universe_id == *id, index, c)
+        }
+        (Key::Era(id), Record::Era(e)) => put_in(&mut project.eras, |x| x.id == *id, index, e),
+        (Key::Event(id), Record::Event(e)) => put_in(&mut project.events, |x| x.id == *id, index, e),
+        (Key::Board(id), Record::BoardLink(universe_id)) => {
+            if let Some(b) = project.board_mut(id) {
+                b.universe_id = universe_id;
+            }
+        }
+        (Key::Asset(id), Record::AssetLink(universe_id, creature_id)) => {
+            if let Some(a) = project.asset_mut(id) {
+                a.universe_id = universe_id;
+                a.creature_id = creature_id;
+            }
+        }
+        _ => {}
+    }
+}
+
+fn remove(project: &mut Project, key: &Key) {
+    match key {
+        // Also drops what the universe gained through actions that can't be undone, e.g. its
+        // timeline; the command keeps that as collateral.
+        Key::Universe(id) => project.remove_universe(id),
+        Key::Entry(codex, id) => project.drop_entry(*codex, id),
+        Key::CreatureTemplate(id) => project.creature_templates.retain(|t| t.universe_id != *id),
+        Key::Calendar(id) => project.calendars.retain(|c| c.universe_id != *id),
+        Key::Era(id) => project.eras.retain(|e| e.id != *id),
+        Key::Event(id) => project.events.retain(|e| e.id != *id),
+        // Boards and assets themselves aren't managed here, only their links.
+        Key::Board(_) | Key::Asset(_) => {}
+    }
+}
+
+/// Replaces the element matching `is`, or inserts `value` at `index`.
+fn put_in<T>(list: &mut Vec<T>, is: impl Fn(&T) -> bool, index: usize, value: T) {
+    match list.iter().position(is) {
+        Some(i) => list[i] = value,
+        None => list.insert(index.min(list.len()), value),
+    }
+}
+
+/// Session-only undo and redo stacks; nothing here is saved with the project.
+#[derive(Debug, Default)]
+pub struct UndoStack {
+    undo: Vec<Command>,
+    redo: Vec<Command>,
+}
+
+impl UndoStack {
+    /// A new action makes the redo stack meaningless, so it is dropped.
+    pub fn push(&mut self, command: Command) {
+        self.redo.clear();
+        self.undo.push(command);
+
+        if self.undo.len() > MAX_COMMANDS {
+            let excess = self.undo.len() - MAX_COMMANDS;
+            self.undo.drain(..excess);
+        }
+    }
+
+    /// Forgets every command, e.g. once the records they refer to can't come back.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    /// Reverts the latest command and returns its label.
+    pub fn undo(&mut self, project: &mut Project) -> Option<String> {
+        let mut command = self.undo.pop()?;
+        command.undo(project);
+        let label = command.label.clone();
+        self.redo.push(command);
+        Some(label)
+    }
+
+    /// Re-applies the latest undone command and returns its label.
+    pub fn redo(&mut self, project: &mut Project) -> Option<String> {
+        let mut command = self.redo.pop()?;
+        command.redo(project);
+        let label = command.label.clone();
+        self.undo.push(command);
+        Some(label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::fixture::project;
+
+    /// Runs `action` as one undoable command.
+    fn record(stack: &mut UndoStack, project: &mut Project, action: impl FnOnce(&mut Project)) {
+        let before = Snapshot::take(project);
+        action(project);
+        if let Some(command) = Command::between("Action".to_string(), &before, &Snapshot::take(project)) {
+            stack.push(command);
+        }
+    }
+
+    fn names(project: &Project) -> Vec<&str> {
+        project.creatures.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    #[test]
+    fn no_command_when_nothing_changed() {
+        let project = project();
+        let snapshot = Snapshot::take(&project);
+        assert!(Command::between("Nothing".to_string(), &snapshot, &Snapshot::take(&project)).is_none());
+    }
+
+    #[test]
+    fn undo_and_redo_leave_unrelated_edits_alone() {
+        let mut project = project();
+        let mut stack = UndoStack::default();
+
+        record(&mut stack, &mut project, |p| p.creatures[0].name = "Will-o'-wisp".to_string());
+        // Not recorded, e.g. made after the command.
+        project.creatures[1].name = "Elder Wyrm".to_string();
+
+        assert_eq!(stack.undo(&mut project).as_deref(), Some("Action"));
+        assert_eq!(names(&project), ["Wisp", "Elder Wyrm", "Ender"]);
+
+        assert_eq!(stack.redo(&mut project).as_deref(), Some("Action"));
+        assert_eq!(names(&project), ["Will-o'-wisp", "Elder Wyrm", "Ender"]);
+    }
+
+    #[test]
+    fn deleted_records_return_to_their_place() {
+        let mut project = project();
+        let mut stack = UndoStack::default();
+
+        record(&mut stack, &mut project, |p| p.remove_entry(Codex::Creature, "wyrm"));
+        assert_eq!(names(&project), ["Wisp", "Ender"]);
+
+        stack.undo(&mut project);
+        assert_eq!(names(&project), ["Wisp", "Wyrm", "Ender"]);
+
+        stack.redo(&mut project);
+        assert_eq!(names(&project), ["Wisp", "Ender"]);
+    }
+
+    #[test]
+    fn a_new_command_drops_the_redo_stack() {
+        let mut project = project();
+        let mut stack = UndoStack::default();
+
+        record(&mut stack, &mut project, |p| p.creatures[0].archived = true);
+        stack.undo(&mut project);
+        record(&mut stack, &mut project, |p| p.creatures[1].archived = true);
+
+        assert!(stack.redo(&mut project).is_none());
+    }
+
+    #[test]
+    fn universes_of_a_removed_workspace_stay_gone() {
+        let mut project = project();
+        let mut stack = UndoStack::default();
+
+        record(&mut stack, &mut project, |p| p.remove_universe("arhelis"));
+        project.workspaces.clear();
+
+        stack.undo(&mut project);
+        assert!(project.universe("arhelis").is_none());
+        assert!(project.creatures.is_empty());
+    }
+
+    #[test]
+    fn undo_and_redo_of_a_new_universe_keep_what_it_gained_since() {
+        let mut project = project();
+        let mut stack = UndoStack::default();
+
+        record(&mut stack, &mut project, |p| {
+            let mut universe = p.universes[0].clone();
+            universe.id = "morrow".to_string();
+            p.universes.push(universe);
+        });
+        // Timeline edits aren't undoable on their own.
+        let timeline: Project = serde_json::from_str(
+            r#"{
+                "calendars": [{ "universe_id": "morrow", "months": ["Thaw"], "days_per_month": 30, "year_suffix": "AM" }],
+                "eras": [{ "id": "first-age", "universe_id": "morrow", "name": "First Age", "description": "", "start_year": 1 }],
+                "events": [{ "id": "dawn", "universe_id": "morrow", "title": "Dawn", "description": "", "date": { "year": 1, "month": 0, "day": 0 } }]
+            }"#,
+        )
+        .unwrap();
+        project.calendars.extend(timeline.calendars);
+        project.eras.extend(timeline.eras);
+        project.events.extend(timeline.events);
+        let written = Snapshot::take(&project);
+
+        stack.undo(&mut project);
+        assert!(project.universe("morrow").is_none());
+        assert!(project.eras.is_empty());
+
+        stack.redo(&mut project);
+        assert!(Command::between("Nothing".to_string(), &written, &Snapshot::take(&project)).is_none());
+
+        // And again, now that the timeline came back through a redo.
+        stack.undo(&mut project);
+        stack.redo(&mut project);
+        assert!(Command::between("Nothing".to_string(), &written, &Snapshot::take(&project)).is_none());
+    }
+}
@@ -0,0 +1,476 @@
+//! Field-level checks the editors run before saving. Errors are plain messages
+//! shown under the offending input.
+
+use super::{Artifact, AssetKind, Character, DangerLevel, Faction, Holder, Project, Term};
+
+pub const NAME_MAX: usize = 80;
+pub const HABITAT_MAX: usize = 500;
+pub const CLIMATE_MAX: usize = 200;
+pub const ORIGIN_MAX: usize = 200;
+pub const DESCRIPTION_MAX: usize = 20_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Name,
+    Habitat,
+    Description,
+    Lore,
+    Danger,
+    Climate,
+    Parent,
+    Title,
+    Ranks,
+    Memberships,
+    Relationships,
+    Pronunciation,
+    Category,
+    Glyph,
+    Origin,
+    Holder,
+    History,
+    Properties,
+}
+
+/// Problems found in one form, at most one per field.
+#[derive(Debug, Clone, Default)]
+pub struct Errors {
+    errors: Vec<(Field, String)>,
+}
+
+impl Errors {
+    pub fn get(&self, field: Field) -> Option<&str> {
+        self.errors.iter().find(|(f, _)| *f == field).map(|(_, e)| e.as_str())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    fn add(&mut self, field: Field, message: impl Into<String>) {
+        if self.get(field).is_none() {
+            self.errors.push((field, message.into()));
+        }
+    }
+
+    fn max_len(&mut self, field: Field, value: &str, max: usize) {
+        if value.trim().chars().count() > max {
+            self.add(field, format!("At most {max} characters"));
+        }
+    }
+
+    fn required(&mut self, field: Field, value: &str) {
+        if value.trim().is_empty() {
+            self.add(field, "Required");
+        }
+    }
+}
+
+fn same_name(a: &str, b: &str) -> bool {
+    a.trim().to_lowercase() == b.trim().to_lowercase()
+}
+
+/// Workspace name, checked against the other workspaces. `id` is `None` for a workspace
+/// being created.
+pub fn workspace(project: &Project, id: Option<&str>, name: &str) -> Errors {
+    let mut errors = Errors::default();
+
+    errors.required(Field::Name, name);
+    errors.max_len(Field::Name, name, NAME_MAX);
+    if project
+        .workspaces
+        .iter()
+        .any(|w| Some(w.id.as_str()) != id && same_name(&w.name, name))
+    {
+        errors.add(Field::Name, "Another workspace has this name");
+    }
+
+    errors
+}
+
+/// Universe fields, checked against the other universes of the active workspace.
+/// `id` is `None` for a universe being created.
+pub fn universe(project: &Project, id: Option<&str>, name: &str, description: &str, lore: &str) -> Errors {
+    let mut errors = Errors::default();
+
+    errors.required(Field::Name, name);
+    errors.max_len(Field::Name, name, NAME_MAX);
+    if project
+        .workspace_universes()
+        .any(|u| Some(u.id.as_str()) != id && same_name(&u.name, name))
+    {
+        errors.add(Field::Name, "Another universe in this workspace has this name");
+    }
+
+    errors.max_len(Field::Description, description, DESCRIPTION_MAX);
+    errors.max_len(Field::Lore, lore, DESCRIPTION_MAX);
+    errors
+}
+
+/// Built-in creature fields, checked against the other creatures of `universe_id`.
+/// `id` is `None` for a creature being created.
+pub fn creature(
+    project: &Project,
+    id: Option<&str>,
+    universe_id: &str,
+    name: &str,
+    habitat: &str,
+    description: &str,
+    danger: &DangerLevel,
+) -> Errors {
+    let mut errors = Errors::default();
+
+    errors.required(Field::Name, name);
+    errors.max_len(Field::Name, name, NAME_MAX);
+    if let Some(message) = duplicate_creature(project, id, universe_id, name) {
+        errors.add(Field::Name, message);
+    }
+
+    errors.max_len(Field::Habitat, habitat, HABITAT_MAX);
+    errors.max_len(Field::Description, description, DESCRIPTION_MAX);
+
+    let known = project
+        .universe(universe_id)
+        .map(|u| u.danger_choices())
+        .unwrap_or_else(|| DangerLevel::BUILT_IN.to_vec());
+    let name = danger.to_string();
+    if *danger != DangerLevel::Unrated && !known.iter().any(|l| l.to_string().eq_ignore_ascii_case(&name)) {
+        errors.add(Field::Danger, format!("\"{name}\" is not a danger level of this universe"));
+    }
+
+    errors
+}
+
+/// Location fields, checked against the other locations of `universe_id`.
+/// `id` is `None` for a location being created.
+pub fn location(
+    project: &Project,
+    id: Option<&str>,
+    universe_id: &str,
+    name: &str,
+    parent_id: Option<&str>,
+    climate: &str,
+    description: &str,
+) -> Errors {
+    let mut errors = Errors::default();
+
+    errors.required(Field::Name, name);
+    errors.max_len(Field::Name, name, NAME_MAX);
+    if project
+        .locations_in(universe_id)
+        .any(|l| Some(l.id.as_str()) != id && same_name(&l.name, name))
+    {
+        errors.add(Field::Name, "Another location in this universe has this name");
+    }
+
+    if let Some(parent_id) = parent_id {
+        match project.location(parent_id) {
+            Some(parent) if parent.universe_id != universe_id => {
+                errors.add(Field::Parent, "The parent must be a location of this universe");
+            }
+            Some(_) if id.is_some_and(|id| project.location_within(parent_id, id)) => {
+                errors.add(Field::Parent, "A location can't lie inside itself or one of its own places");
+            }
+            Some(_) => {}
+            None => errors.add(Field::Parent, "The parent location no longer exists"),
+        }
+    }
+
+    errors.max_len(Field::Climate, climate, CLIMATE_MAX);
+    errors.max_len(Field::Description, description, DESCRIPTION_MAX);
+    errors
+}
+
+/// Character fields, checked against the other characters of its universe.
+/// An empty `c.id` marks a character being created.
+pub fn character(project: &Project, c: &Character) -> Errors {
+    let mut errors = Errors::default();
+
+    errors.required(Field::Name, &c.name);
+    errors.max_len(Field::Name, &c.name, NAME_MAX);
+    if project
+        .characters_in(&c.universe_id)
+        .any(|other| other.id != c.id && same_name(&other.name, &c.name))
+    {
+        errors.add(Field::Name, "Another character in this universe has this name");
+    }
+
+    errors.max_len(Field::Title, &c.title, NAME_MAX);
+    errors.max_len(Field::Description, &c.description, DESCRIPTION_MAX);
+
+    if c.memberships.iter().any(|m| m.rank.trim().chars().count() > NAME_MAX) {
+        errors.add(Field::Memberships, format!("Ranks are at most {NAME_MAX} characters"));
+    }
+
+    if c.relationships.iter().any(|r| r.kind.trim().is_empty()) {
+        errors.add(Field::Relationships, "Say what each relationship is, e.g. Mentor or Sister");
+    }
+    if c.relationships.iter().any(|r| r.kind.trim().chars().count() > NAME_MAX) {
+        errors.add(Field::Relationships, format!("Relationships are at most {NAME_MAX} characters"));
+    }
+
+    errors
+}
+
+/// Faction fields, checked against the other factions of its universe.
+/// An empty `f.id` marks a faction being created.
+pub fn faction(project: &Project, f: &Faction) -> Errors {
+    let mut errors = Errors::default();
+
+    errors.required(Field::Name, &f.name);
+    errors.max_len(Field::Name, &f.name, NAME_MAX);
+    if project
+        .factions_in(&f.universe_id)
+        .any(|other| other.id != f.id && same_name(&other.name, &f.name))
+    {
+        errors.add(Field::Name, "Another faction in this universe has this name");
+    }
+
+    errors.max_len(Field::Description, &f.description, DESCRIPTION_MAX);
+
+    for (i, rank) in f.ranks.iter().enumerate() {
+        if f.ranks[..i].iter().any(|r| same_name(r, rank)) {
+            errors.add(Field::Ranks, format!("\"{rank}\" is listed twice"));
+        }
+        errors.max_len(Field::Ranks, rank, NAME_MAX);
+    }
+
+    errors
+}
+
+/// Runomicon entry fields, checked against the other entries of its universe.
+/// An empty `term.id` marks an entry being created.
+pub fn term(project: &Project, term: &Term) -> Errors {
+    let mut errors = Errors::default();
+
+    errors.required(Field::Name, &term.name);
+    errors.max_len(Field::Name, &term.name, NAME_MAX);
+    if project
+        .terms_in(&term.universe_id)
+        .any(|other| other.id != term.id && same_name(&other.name, &term.name))
+    {
+        errors.add(Field::Name, "Another entry in this Runomicon has this name");
+    }
+
+    errors.max_len(Field::Pronunciation, &term.pronunciation, NAME_MAX);
+    errors.max_len(Field::Category, &term.category, NAME_MAX);
+    errors.max_len(Field::Description, &term.definition, DESCRIPTION_MAX);
+
+    if let Some(asset_id) = term.glyph_asset_id.as_deref() {
+        match project.asset(asset_id) {
+            Some(a) if !matches!(a.kind, AssetKind::Image | AssetKind::Vector) => {
+                errors.add(Field::Glyph, "A glyph must be an image or an SVG");
+            }
+            Some(_) => {}
+            None => errors.add(Field::Glyph, "The glyph file is no longer in the asset library"),
+        }
+    }
+
+    errors
+}
+
+/// Artifact fields, checked against the other artifacts of its universe.
+/// An empty `a.id` marks an artifact being created.
+pub fn artifact(project: &Project, a: &Artifact) -> Errors {
+    let mut errors = Errors::default();
+
+    errors.required(Field::Name, &a.name);
+    errors.max_len(Field::Name, &a.name, NAME_MAX);
+    if project
+        .artifacts_in(&a.universe_id)
+        .any(|other| other.id != a.id && same_name(&other.name, &a.name))
+    {
+        errors.add(Field::Name, "Another artifact in this universe has this name");
+    }
+
+    errors.max_len(Field::Origin, &a.origin, ORIGIN_MAX);
+
+    let holder_universe = match &a.holder {
+        Some(Holder::Character(id)) => Some(project.character(id).map(|c| c.universe_id.as_str())),
+        Some(Holder::Creature(id)) => Some(project.creature(id).map(|c| c.universe_id.as_str())),
+        None => None,
+    };
+    match holder_universe {
+        Some(None) => errors.add(Field::Holder, "The holder no longer exists"),
+        Some(Some(universe_id)) if universe_id != a.universe_id => {
+            errors.add(Field::Holder, "The holder must belong to this universe");
+        }
+        _ => {}
+    }
+
+    errors.max_len(Field::Description, &a.description, DESCRIPTION_MAX);
+    errors.max_len(Field::History, &a.history, DESCRIPTION_MAX);
+
+    for (i, p) in a.properties.iter().enumerate() {
+        if p.name.trim().is_empty() {
+            errors.add(Field::Properties, "Every property needs a name");
+        }
+        if a.properties[..i].iter().any(|other| same_name(&other.name, &p.name)) {
+            errors.add(Field::Properties, format!("\"{}\" is listed twice", p.name.trim()));
+        }
+        errors.max_len(Field::Properties, &p.name, NAME_MAX);
+        errors.max_len(Field::Properties, &p.value, ORIGIN_MAX);
+    }
+
+    errors
+}
+
+/// Error for a creature name already taken in `universe_id` by another creature.
+pub fn duplicate_creature(project: &Project, id: Option<&str>, universe_id: &str, name: &str) -> Option<String> {
+    let taken = project
+        .creatures
+        .iter()
+        .any(|c| c.universe_id == universe_id && Some(c.id.as_str()) != id && same_name(&c.name, name));
+
+    taken.then(|| {
+        let universe = project.universe(universe_id).map(|u| u.name.as_str()).unwrap_or("this universe");
+        format!("Another creature in {universe} has this name")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::fixture::project;
+    use crate::model::ArtifactProperty;
+
+    #[test]
+    fn workspace_names_are_required_and_unique() {
+        let project = project();
+
+        assert_eq!(workspace(&project, Some("home"), "  ").get(Field::Name), Some("Required"));
+        assert!(workspace(&project, Some("home"), "away").get(Field::Name).is_some());
+        assert!(workspace(&project, Some("home"), "Home").is_empty());
+        assert!(workspace(&project, None, "Studio").is_empty());
+    }
+
+    #[test]
+    fn universe_names_are_unique_per_workspace() {
+        let project = project();
+
+        assert_eq!(universe(&project, None, " ", "", "").get(Field::Name), Some("Required"));
+        assert!(universe(&project, None, " arhelis ", "", "").get(Field::Name).is_some());
+        assert!(universe(&project, Some("arhelis"), "Arhelis", "", "").is_empty());
+        assert!(universe(&project, None, "Elsewhere", "", "").is_empty());
+    }
+
+    #[test]
+    fn lengths_count_characters_of_the_trimmed_value() {
+        let project = project();
+        let at_limit = "é".repeat(NAME_MAX);
+
+        assert!(universe(&project, None, &format!(" {at_limit} "), "", "").is_empty());
+        assert_eq!(
+            universe(&project, None, &format!("{at_limit}é"), "", "").get(Field::Name),
+            Some(format!("At most {NAME_MAX} characters").as_str())
+        );
+    }
+
+    #[test]
+    fn creature_danger_must_be_a_level_of_its_universe() {
+        let project = project();
+        let check = |danger: DangerLevel| creature(&project, None, "arhelis", "Drake", "", "", &danger);
+
+        assert!(check(DangerLevel::Unrated).is_empty());
+        assert!(check(DangerLevel::Extreme).is_empty());
+        assert!(check(DangerLevel::Custom("apocalyptic".to_string())).is_empty());
+        assert!(check(DangerLevel::Custom("Cataclysmic".to_string())).get(Field::Danger).is_some());
+    }
+
+    #[test]
+    fn duplicate_creatures_name_their_universe() {
+        let project = project();
+
+        assert_eq!(
+            duplicate_creature(&project, None, "arhelis", "WISP"),
+            Some("Another creature in Arhelis has this name".to_string())
+        );
+        assert_eq!(duplicate_creature(&project, Some("wisp"), "arhelis", "Wisp"), None);
+        assert_eq!(duplicate_creature(&project, None, "sylvara", "Wisp"), None);
+    }
+
+    #[test]
+    fn location_parent_must_be_an_outer_place_of_the_same_universe() {
+        let project = project();
+        let parent_error = |id: &str, name: &str, parent: &str| {
+            location(&project, Some(id), "arhelis", name, Some(parent), "", "").get(Field::Parent).is_some()
+        };
+
+        assert!(!parent_error("harrow", "Harrow", "vey"));
+        assert!(parent_error("harrow", "Harrow", "harrow"));
+        assert!(parent_error("harrow", "Harrow", "thorn"));
+        assert!(parent_error("harrow", "Harrow", "gone"));
+        // Vey can't move inside Harrow, which lies inside Vey.
+        assert!(parent_error("vey", "Vey", "harrow"));
+    }
+
+    #[test]
+    fn character_relationships_need_a_kind() {
+        let project = project();
+        let mut c = project.character("ilsa").unwrap().clone();
+        assert!(character(&project, &c).is_empty());
+
+        c.id = String::new();
+        assert!(character(&project, &c).get(Field::Name).is_some());
+
+        c.name = "Oren".to_string();
+        c.relationships = serde_json::from_str(r#"[{ "character_id": "ilsa", "kind": " " }]"#).unwrap();
+        assert!(character(&project, &c).get(Field::Relationships).is_some());
+    }
+
+    #[test]
+    fn faction_ranks_are_listed_once() {
+        let project = project();
+        let f: Faction = serde_json::from_str(
+            r#"{ "id": "", "universe_id": "arhelis", "name": "Wardens", "ranks": ["Captain", "Warden", "captain "] }"#,
+        )
+        .unwrap();
+
+        assert_eq!(faction(&project, &f).get(Field::Ranks), Some("\"captain \" is listed twice"));
+    }
+
+    #[test]
+    fn term_glyph_must_be_an_image() {
+        let project = project();
+        let mut t: Term =
+            serde_json::from_str(r#"{ "id": "", "universe_id": "arhelis", "name": "Vael" }"#).unwrap();
+
+        t.glyph_asset_id = Some("rune".to_string());
+        assert!(term(&project, &t).is_empty());
+
+        t.glyph_asset_id = Some("notes".to_string());
+        assert!(term(&project, &t).get(Field::Glyph).is_some());
+
+        t.glyph_asset_id = Some("gone".to_string());
+        assert!(term(&project, &t).get(Field::Glyph).is_some());
+    }
+
+    #[test]
+    fn artifact_holder_belongs_to_its_universe() {
+        let project = project();
+        let mut a: Artifact =
+            serde_json::from_str(r#"{ "id": "", "universe_id": "arhelis", "name": "Lantern" }"#).unwrap();
+
+        a.holder = Some(Holder::Creature("wisp".to_string()));
+        assert!(artifact(&project, &a).is_empty());
+
+        a.holder = Some(Holder::Character("ilsa".to_string()));
+        assert_eq!(artifact(&project, &a).get(Field::Holder), Some("The holder must belong to this universe"));
+
+        a.holder = Some(Holder::Character("gone".to_string()));
+        assert_eq!(artifact(&project, &a).get(Field::Holder), Some("The holder no longer exists"));
+    }
+
+    #[test]
+    fn only_the_first_problem_of_a_field_is_kept() {
+        let project = project();
+        let mut a: Artifact =
+            serde_json::from_str(r#"{ "id": "", "universe_id": "arhelis", "name": "Lantern" }"#).unwrap();
+        let property = |name: &str| ArtifactProperty {
+            name: name.to_string(),
+            value: String::new(),
+        };
+
+        a.properties = vec![property("Weight"), property("weight"), property("")];
+        assert_eq!(artifact(&project, &a).get(Field::Properties), Some("\"weight\" is listed twice"));
+    }
+}
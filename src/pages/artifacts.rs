@@ -0,0 +1,201 @@
+use std::fmt;
+
+use iced::{Alignment, Length};
+use iced::widget::{container, pick_list, text, text_input, Column, Row};
+
+use crate::app::{AppState, ArtifactEditor, Message};
+use crate::forms::Form;
+use crate::model::validation::Field;
+use crate::model::{Artifact, Codex, Holder, Project, Rarity};
+use crate::pages::codex::{editor, entry_card, grid, header};
+use crate::{pages::E, ui};
+
+pub fn artifacts<'a>(state: &'a AppState, t: ui::Tokens, universe_id: &'a str) -> E<'a> {
+    let header = header(
+        state,
+        t,
+        Codex::Artifact,
+        universe_id,
+        "Relics, weapons and heirlooms: where they came from and whose hands they are in.",
+        None,
+    );
+
+    // Rarest first.
+    let mut artifacts: Vec<&Artifact> = state.project.artifacts_in(universe_id).collect();
+    artifacts.sort_by_key(|a| (std::cmp::Reverse(a.rarity), a.name.to_lowercase()));
+    let (archived, active): (Vec<&Artifact>, Vec<&Artifact>) = artifacts.into_iter().partition(|a| a.archived);
+
+    let mut body = Column::new()
+        .spacing(14)
+        .push(header)
+        .width(Length::Fill);
+
+    if let Some(editor) = state.artifact_editor.as_ref().filter(|ed| ed.universe_id == universe_id) {
+        body = body.push(editor_panel(state, t, editor));
+    }
+
+    let cards = |list: &[&'a Artifact]| -> Vec<E<'a>> {
+        list.iter()
+            .map(|a| artifact_card(state, t, a))
+            .collect()
+    };
+
+    body = body
+        .push(text(format!("Catalogue ({})", active.len())).size(12).color(t.muted_fg))
+        .push(grid(t, cards(&active), "No artifacts yet. Add the first one."))
+        .push(text(format!("Archived artifacts ({})", archived.len())).size(12).color(t.muted_fg))
+        .push(grid(t, cards(&archived), "No archived artifacts."));
+
+    ui::page_padding(body.into())
+}
+
+/// Message opening a holder's page.
+fn open_holder(holder: &Holder) -> Message {
+    match holder {
+        Holder::Character(id) => Message::OpenEntry(Codex::Character, id.clone()),
+        Holder::Creature(id) => Message::OpenEntry(Codex::Creature, id.clone()),
+    }
+}
+
+fn artifact_card<'a>(state: &'a AppState, t: ui::Tokens, a: &'a Artifact) -> E<'a> {
+    let project = &state.project;
+
+    let holder = a
+        .holder
+        .as_ref()
+        .and_then(|h| project.holder_name(h).map(|name| (h, name)));
+    let held_by: E<'a> = match holder {
+        Some((h, name)) => Row::new()
+            .spacing(6)
+            .align_y(Alignment::Center)
+            .push(text("Held by").size(12).color(t.muted_fg))
+            .push(ui::ghost_button(t, name.to_string(), open_holder(h)))
+            .into(),
+        None => text("Whereabouts unknown").size(12).color(t.muted_fg).into(),
+    };
+
+    let mut body = Column::new().push(held_by);
+
+    for p in &a.properties {
+        let line = if p.value.is_empty() {
+            p.name.clone()
+        } else {
+            format!("{}: {}", p.name, p.value)
+        };
+        body = body.push(text(line).size(12).color(t.foreground));
+    }
+
+    entry_card(state, t, a, body)
+}
+
+/// Entry of the holder picker; `holder == None` stands for "nobody".
+#[derive(Debug, Clone, PartialEq)]
+struct HolderChoice {
+    holder: Option<Holder>,
+    label: String,
+}
+
+impl fmt::Display for HolderChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+/// "Nobody", then the universe's active characters, then its active creatures.
+fn holder_choices(project: &Project, universe_id: &str) -> Vec<HolderChoice> {
+    let mut characters: Vec<HolderChoice> = project
+        .characters_in(universe_id)
+        .filter(|c| !c.archived)
+        .map(|c| HolderChoice {
+            holder: Some(Holder::Character(c.id.clone())),
+            label: format!("Character · {}", c.name),
+        })
+        .collect();
+    characters.sort_by_key(|c| c.label.to_lowercase());
+
+    let mut creatures: Vec<HolderChoice> = project
+        .creatures_in(universe_id)
+        .filter(|c| !c.archived)
+        .map(|c| HolderChoice {
+            holder: Some(Holder::Creature(c.id.clone())),
+            label: format!("Creature · {}", c.name),
+        })
+        .collect();
+    creatures.sort_by_key(|c| c.label.to_lowercase());
+
+    std::iter::once(HolderChoice {
+        holder: None,
+        label: "Nobody (lost or unclaimed)".to_string(),
+    })
+    .chain(characters)
+    .chain(creatures)
+    .collect()
+}
+
+fn editor_panel<'a>(state: &'a AppState, t: ui::Tokens, ed: &'a ArtifactEditor) -> E<'a> {
+    let errors = ed.errors(&state.project);
+
+    let holders = holder_choices(&state.project, &ed.universe_id);
+    let selected_holder = holders.iter().find(|c| c.holder == ed.holder).cloned();
+
+    let placement = Column::new()
+        .spacing(10)
+        .push(
+            Row::new()
+                .spacing(10)
+                .align_y(Alignment::Center)
+                .push(text("Rarity").size(12).color(t.muted_fg))
+                .push(pick_list(Rarity::ALL, Some(ed.rarity), Message::ArtifactEditorRarityChanged).padding(8))
+                .push(text("Held by").size(12).color(t.muted_fg))
+                .push(
+                    pick_list(holders, selected_holder, |c: HolderChoice| {
+                        Message::ArtifactEditorHolderChanged(c.holder)
+                    })
+                    .placeholder("Nobody (lost or unclaimed)")
+                    .padding(8),
+                ),
+        )
+        .push(errors.get(Field::Holder).map(ui::error_text));
+
+    let mut properties = Column::new()
+        .spacing(8)
+        .push(text("Properties").size(12).color(t.muted_fg));
+    for (i, p) in ed.properties.iter().enumerate() {
+        properties = properties.push(
+            Row::new()
+                .spacing(10)
+                .align_y(Alignment::Center)
+                .push(
+                    container(
+                        text_input("Property (e.g. Weight)", &p.name)
+                            .on_input(move |v| Message::ArtifactEditorPropertyNameChanged(i, v))
+                            .padding(8),
+                    )
+                    .width(Length::FillPortion(1)),
+                )
+                .push(
+                    container(
+                        text_input("Value (e.g. 2 kg)", &p.value)
+                            .on_input(move |v| Message::ArtifactEditorPropertyValueChanged(i, v))
+                            .padding(8),
+                    )
+                    .width(Length::FillPortion(2)),
+                )
+                .push(ui::ghost_button(t, "Remove".to_string(), Message::ArtifactEditorRemoveProperty(i))),
+        );
+    }
+    properties = properties
+        .push(ui::ghost_button(
+            t,
+            "+ Add property".to_string(),
+            Message::ArtifactEditorAddProperty,
+        ))
+        .push(errors.get(Field::Properties).map(ui::error_text));
+
+    editor(
+        state,
+        t,
+        ed,
+        vec![(Field::Name, placement.into()), (Field::History, properties.into())],
+    )
+}
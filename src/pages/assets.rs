@@ -0,0 +1,293 @@
+use std::fmt;
+
+use iced::{border, Alignment, Border, Color, ContentFit, Length};
+use iced::widget::{center, container, image, pick_list, svg, text, text_input, Column, Row};
+
+use crate::app::{AppState, AssetEditor, Message};
+use crate::model::assets::format_size;
+use crate::model::{Asset, AssetKind};
+use crate::{pages::E, ui};
+
+pub fn assets<'a>(state: &'a AppState, t: ui::Tokens) -> E<'a> {
+    let title = Column::new()
+        .spacing(4)
+        .push(text("Assets").size(26).color(t.foreground))
+        .push(text("Uploaded files and references.").size(12).color(t.muted_fg));
+
+    let path_input = text_input("Path to a local file (e.g. /home/me/maps/plateau.png)", &state.asset_import_path)
+        .on_input(Message::AssetImportPathChanged)
+        .on_submit(Message::ImportAsset)
+        .padding(10);
+
+    let form = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(container(path_input).width(Length::Fixed(420.0)))
+        .push(ui::primary_button(t, "Import".to_string(), Message::ImportAsset));
+
+    let header = Row::new()
+        .align_y(Alignment::Center)
+        .push(container(title).width(Length::Fill))
+        .push(form);
+
+    let mut body = Column::new()
+        .spacing(14)
+        .push(header)
+        .width(Length::Fill);
+
+    if let Some(err) = &state.asset_import_error {
+        body = body.push(text(err).size(12).color(Color::from_rgba8(0xFF, 0x5A, 0x5A, 1.0)));
+    }
+
+    if let Some(editor) = &state.asset_editor {
+        body = body.push(editor_panel(state, t, editor));
+    }
+
+    let filter = state.asset_tag_filter.trim().to_lowercase();
+    let library: Vec<&Asset> = state.project.workspace_assets().collect();
+    let visible: Vec<&Asset> = library
+        .iter()
+        .copied()
+        .filter(|a| filter.is_empty() || a.tags.iter().any(|tag| tag.to_lowercase().contains(&filter)))
+        .collect();
+
+    let filter_row = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(
+            text(format!("Library ({} of {})", visible.len(), library.len()))
+                .size(12)
+                .color(t.muted_fg),
+        )
+        .push(container(iced::widget::Space::new()).width(Length::Fill))
+        .push(
+            container(
+                text_input("Filter by tag", &state.asset_tag_filter)
+                    .on_input(Message::AssetTagFilterChanged)
+                    .padding(8),
+            )
+            .width(Length::Fixed(220.0)),
+        );
+
+    body = body.push(filter_row);
+
+    if visible.is_empty() {
+        let empty = if library.is_empty() {
+            "No assets yet. Paste a file path above to import maps, glyph sheets or references."
+        } else {
+            "No assets match this tag."
+        };
+
+        body = body.push(ui::card(
+            t,
+            container(text(empty).size(12).color(t.muted_fg))
+                .width(Length::Fill)
+                .padding([14, 14])
+                .into(),
+        ));
+    } else {
+        body = body.push(assets_grid(state, t, visible));
+    }
+
+    ui::page_padding(body.into())
+}
+
+fn assets_grid<'a>(state: &'a AppState, t: ui::Tokens, assets: Vec<&'a Asset>) -> E<'a> {
+    let mut col: Column<'a, Message> = Column::new().spacing(14).width(Length::Fill);
+
+    let mut row: Row<'a, Message> = Row::new().spacing(14).width(Length::Fill);
+    let mut count: usize = 0;
+
+    for a in assets {
+        row = row.push(container(asset_card(state, t, a)).width(Length::Fill));
+        count += 1;
+
+        if count == 3 {
+            col = col.push(row);
+            row = Row::new().spacing(14).width(Length::Fill);
+            count = 0;
+        }
+    }
+
+    if count != 0 {
+        col = col.push(row);
+    }
+
+    col.into()
+}
+
+fn asset_card<'a>(state: &'a AppState, t: ui::Tokens, a: &'a Asset) -> E<'a> {
+    let mut attached = Vec::new();
+    if let Some(u) = a.universe_id.as_deref().and_then(|id| state.project.universe(id)) {
+        attached.push(u.name.clone());
+    }
+    if let Some(c) = a.creature_id.as_deref().and_then(|id| state.project.creature(id)) {
+        attached.push(c.name.clone());
+    }
+
+    let mut body = Column::new()
+        .spacing(6)
+        .push(preview(state, t, a, 140.0))
+        .push(text(&a.name).size(14).color(t.foreground))
+        .push(text(format!("{} · {}", a.kind, format_size(a.size_bytes))).size(12).color(t.muted_fg));
+
+    if !attached.is_empty() {
+        body = body.push(text(format!("Attached to {}", attached.join(" › "))).size(12).color(t.muted_fg));
+    }
+
+    if !a.tags.is_empty() {
+        let mut tags = Row::new().spacing(6);
+        for tag in &a.tags {
+            tags = tags.push(tag_pill(t, tag));
+        }
+        body = body.push(tags);
+    }
+
+    let actions: E<'a> = if state.pending_asset_delete.as_deref() == Some(a.id.as_str()) {
+        ui::confirm_delete(
+            t,
+            format!("Delete \"{}\" and its stored file? This cannot be undone.", a.name),
+            Message::DeleteAssetCancel,
+            Message::DeleteAssetConfirm,
+        )
+    } else {
+        Row::new()
+            .spacing(10)
+            .push(ui::outline_button(t, "Tags & links".to_string(), Message::AssetEdit(a.id.clone())))
+            .push(ui::danger_button(t, "Delete".to_string(), Message::DeleteAsset(a.id.clone())))
+            .into()
+    };
+
+    ui::card(t, body.push(actions).into())
+}
+
+/// Thumbnail for images and SVGs; other kinds get a labelled placeholder tile.
+pub fn preview<'a>(state: &AppState, t: ui::Tokens, a: &Asset, height: f32) -> E<'a> {
+    let path = state.asset_path(a);
+
+    let content: E<'a> = match (path, a.kind) {
+        (Some(path), AssetKind::Image) => image(image::Handle::from_path(path))
+            .content_fit(ContentFit::Contain)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into(),
+        (Some(path), AssetKind::Vector) => svg(svg::Handle::from_path(path))
+            .content_fit(ContentFit::Contain)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into(),
+        _ => center(text(a.kind.to_string().to_uppercase()).size(14).color(t.muted_fg)).into(),
+    };
+
+    container(content)
+        .width(Length::Fill)
+        .height(Length::Fixed(height))
+        .padding(6)
+        .style(move |_| {
+            let mut s = ui::container_style(t.hover_bg, t.foreground);
+            s.border = Border {
+                color: t.border,
+                width: 1.0,
+                radius: border::Radius::from(12.0),
+            };
+            s
+        })
+        .into()
+}
+
+pub fn tag_pill<'a>(t: ui::Tokens, tag: &'a str) -> E<'a> {
+    container(text(tag).size(10).color(t.foreground))
+        .padding([3, 8])
+        .style(move |_| {
+            let mut s = ui::container_style(t.active_bg, t.foreground);
+            s.border = Border {
+                color: Color::from_rgba8(0xFF, 0xFF, 0xFF, 0.10),
+                width: 1.0,
+                radius: border::Radius::from(999.0),
+            };
+            s
+        })
+        .into()
+}
+
+/// Entry of the attachment pickers; `id == None` detaches.
+#[derive(Debug, Clone, PartialEq)]
+struct Choice {
+    id: Option<String>,
+    name: String,
+}
+
+impl fmt::Display for Choice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+fn editor_panel<'a>(state: &'a AppState, t: ui::Tokens, ed: &'a AssetEditor) -> E<'a> {
+    let name = state.project.asset(&ed.id).map(|a| a.name.as_str()).unwrap_or("Asset");
+
+    let universes: Vec<Choice> = std::iter::once(Choice {
+        id: None,
+        name: "No universe".to_string(),
+    })
+    .chain(state.project.workspace_universes().filter(|u| !u.archived).map(|u| Choice {
+        id: Some(u.id.clone()),
+        name: u.name.clone(),
+    }))
+    .collect();
+
+    let creatures: Vec<Choice> = std::iter::once(Choice {
+        id: None,
+        name: "No creature".to_string(),
+    })
+    .chain(
+        ed.universe_id
+            .as_deref()
+            .map(|u| state.project.creatures_in(u).filter(|c| !c.archived).collect::<Vec<_>>())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|c| Choice {
+                id: Some(c.id.clone()),
+                name: c.name.clone(),
+            }),
+    )
+    .collect();
+
+    let selected_universe = universes.iter().find(|c| c.id == ed.universe_id).cloned();
+    let selected_creature = creatures.iter().find(|c| c.id == ed.creature_id).cloned();
+
+    let links = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(text("Attach to").size(12).color(t.muted_fg))
+        .push(
+            pick_list(universes, selected_universe, |c: Choice| Message::AssetEditorUniverseChanged(c.id))
+                .padding(8),
+        )
+        .push(
+            pick_list(creatures, selected_creature, |c: Choice| Message::AssetEditorCreatureChanged(c.id))
+                .padding(8),
+        );
+
+    let actions = Row::new()
+        .spacing(10)
+        .push(ui::outline_button(t, "Cancel".to_string(), Message::AssetEditorCancel))
+        .push(ui::primary_button(t, "Save".to_string(), Message::AssetEditorSave));
+
+    let header = Row::new()
+        .align_y(Alignment::Center)
+        .push(container(text(format!("Tags & links — {name}")).size(16).color(t.foreground)).width(Length::Fill))
+        .push(actions);
+
+    let form = Column::new()
+        .spacing(10)
+        .push(header)
+        .push(
+            text_input("Tags, comma-separated (e.g. map, faction, reference)", &ed.tags)
+                .on_input(Message::AssetEditorTagsChanged)
+                .padding(10),
+        )
+        .push(links);
+
+    ui::card(t, form.into())
+}
@@ -1,65 +1,94 @@
-use iced::{Alignment, Color, Length};
-use iced::widget::{container, mouse_area, text, text_input, Column, Row};
+use std::fmt;
 
-use crate::app::{AppState, Message};
-use crate::model::Creature;
+use iced::{Alignment, Color, Length};
+use iced::widget::{button, checkbox, container, mouse_area, pick_list, text, text_editor, text_input, Column, Row};
+
+use crate::app::{AppState, BestiaryFilter, BestiarySort, CreatureEditor, Message, TemplateEditor};
+use crate::forms::Form;
+use crate::model::activity::format_timestamp;
+use crate::model::search::mentions;
+use crate::model::validation::Field;
+use crate::model::{ActivityTarget, Codex, Creature, DangerLevel, FieldDef, FieldKind, FieldValue, Holder, Universe};
+use crate::pages::assets::{preview, tag_pill};
+use crate::pages::codex::{
+    delete_prompt, editor as editor_form, entry_card, facet_choices, grid, header, tally, FacetChoice,
+};
+use crate::pages::locations::{location_choices, LocationChoice};
 use crate::{pages::E, ui};
 
+/// Used until the universe's filters are first touched.
+static NO_FILTER: BestiaryFilter = BestiaryFilter {
+    danger: None,
+    tag: None,
+    habitat: String::new(),
+    sort: BestiarySort::Name,
+};
+
 pub fn bestiary<'a>(state: &'a AppState, t: ui::Tokens, universe_id: &'a str) -> E<'a> {
-    let universe_name = state
-        .universes
-        .iter()
-        .find(|u| u.id == universe_id)
-        .map(|u| u.name.as_str())
-        .unwrap_or(universe_id);
+    let header = header(
+        state,
+        t,
+        Codex::Creature,
+        universe_id,
+        "Creatures, entities and beings that inhabit this universe.",
+        Some(ui::outline_button(
+            t,
+            "Sheet template".to_string(),
+            Message::TemplateEditorOpen(universe_id.to_string()),
+        )),
+    );
 
-    let header_left = Column::new()
-        .spacing(4)
-        .push(text(format!("Bestiary — {}", universe_name)).size(26).color(t.foreground))
-        .push(
-            text("Creatures, entities and beings that inhabit this universe.")
-                .size(12)
-                .color(t.muted_fg),
-        );
+    let filter = state.bestiary_filters.get(universe_id).unwrap_or(&NO_FILTER);
 
-    let header_right = Row::new()
-        .spacing(10)
-        .push(ui::outline_button(
-            t,
-            "Back to universe".to_string(),
-            Message::BackToUniverse(universe_id.to_string()),
-        ))
-        .push(ui::outline_button(
-            t,
-            "All universes".to_string(),
-            Message::BackToUniverses,
-        ))
-        .push(ui::primary_button(
-            t,
-            "Create creature".to_string(),
-            Message::CreatureEditorOpenCreate,
-        ));
+    let (all_archived, all_active): (Vec<&Creature>, Vec<_>) = state
+        .project
+        .creatures_in(universe_id)
+        .partition(|c| c.archived);
 
-    let header = Row::new()
-        .align_y(Alignment::Center)
-        .push(container(header_left).width(Length::Fill))
-        .push(header_right);
+    let universe = state.project.universe(universe_id);
+    let shown = |creatures: &[&'a Creature]| -> Vec<&'a Creature> {
+        let mut shown: Vec<&Creature> = creatures.iter().copied().filter(|c| filter.matches(c)).collect();
+        filter.sort(&mut shown, universe);
+        shown
+    };
 
-    let active_header = text(format!("Active creatures ({})", state.creatures.len()))
-        .size(12)
-        .color(t.muted_fg);
+    let active_creatures = shown(&all_active);
+    let archived_creatures = shown(&all_archived);
 
-    let cards = creatures_grid(t, &state.creatures);
+    let count_label = |shown: usize, total: usize| {
+        if filter.is_active() {
+            format!("{shown} of {total}")
+        } else {
+            total.to_string()
+        }
+    };
 
-    let archived_header = text("Archived creatures (0)").size(12).color(t.muted_fg);
+    let active_header = text(format!(
+        "Active creatures ({})",
+        count_label(active_creatures.len(), all_active.len())
+    ))
+    .size(12)
+    .color(t.muted_fg);
+
+    let archived_header = text(format!(
+        "Archived creatures ({})",
+        count_label(archived_creatures.len(), all_archived.len())
+    ))
+    .size(12)
+    .color(t.muted_fg);
+
+    let fields = state.project.creature_fields(universe_id);
+    let cards = |list: Vec<&'a Creature>| -> Vec<E<'a>> {
+        list.into_iter().map(|c| creature_card(state, t, c, fields)).collect()
+    };
 
-    let archived = ui::card(
-        t,
-        container(text("No archived creatures.").size(12).color(t.muted_fg))
-            .width(Length::Fill)
-            .padding([14, 14])
-            .into(),
-    );
+    let empty = if filter.is_active() {
+        "No creatures match these filters."
+    } else {
+        "No creatures yet. Add the first one."
+    };
+    let archived = grid(t, cards(archived_creatures), "No archived creatures.");
+    let cards = grid(t, cards(active_creatures), empty);
 
     let mut body = Column::new()
         .spacing(14)
@@ -67,10 +96,15 @@ pub fn bestiary<'a>(state: &'a AppState, t: ui::Tokens, universe_id: &'a str) ->
         .width(Length::Fill);
 
     if let Some(editor) = &state.creature_editor {
-        body = body.push(editor_panel(t, editor));
+        body = body.push(editor_panel(state, t, editor));
+    }
+
+    if let Some(editor) = &state.template_editor {
+        body = body.push(template_panel(t, editor));
     }
 
     body = body
+        .push(filter_bar(t, filter, universe, &all_active))
         .push(active_header)
         .push(cards)
         .push(archived_header)
@@ -79,148 +113,790 @@ pub fn bestiary<'a>(state: &'a AppState, t: ui::Tokens, universe_id: &'a str) ->
     ui::page_padding(body.into())
 }
 
-fn editor_panel<'a>(t: ui::Tokens, editor: &'a crate::app::CreatureEditor) -> E<'a> {
-    let title = if editor.index.is_some() {
-        "Edit creature"
-    } else {
-        "Create creature"
-    };
+fn filter_bar<'a>(
+    t: ui::Tokens,
+    filter: &'a BestiaryFilter,
+    universe: Option<&Universe>,
+    creatures: &[&Creature],
+) -> E<'a> {
+    // Least dangerous first, unrated last.
+    let mut dangers = tally(creatures.iter().map(|c| c.danger.clone()), |a, b| a == b);
+    dangers.sort_by_key(|(d, _)| {
+        let rank = universe.and_then(|u| u.danger_rank(d));
+        (rank.is_none(), rank, d.to_string().to_lowercase())
+    });
+
+    let mut tags = tally(creatures.iter().flat_map(|c| c.tags.iter().cloned()), |a, b| a.eq_ignore_ascii_case(b));
+    tags.sort_by_key(|(tag, _)| tag.to_lowercase());
+
+    let danger_choices = facet_choices("Any danger", dangers);
+    let tag_choices = facet_choices("Any tag", tags);
+
+    let selected_danger = danger_choices.iter().find(|c| c.value == filter.danger).cloned();
+    let selected_tag = tag_choices
+        .iter()
+        .find(|c| match (&c.value, &filter.tag) {
+            (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+            (None, None) => true,
+            _ => false,
+        })
+        .cloned();
+
+    let mut bar = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(
+            pick_list(danger_choices, selected_danger, |c: FacetChoice<DangerLevel>| {
+                Message::BestiaryDangerFilterChanged(c.value)
+            })
+            .padding(8),
+        )
+        .push(
+            pick_list(tag_choices, selected_tag, |c: FacetChoice<String>| {
+                Message::BestiaryTagFilterChanged(c.value)
+            })
+            .padding(8),
+        )
+        .push(
+            container(
+                text_input("Habitat contains…", &filter.habitat)
+                    .on_input(Message::BestiaryHabitatFilterChanged)
+                    .padding(8),
+            )
+            .width(Length::Fixed(220.0)),
+        );
 
-    let name_input = text_input("Name", &editor.name)
-        .on_input(Message::CreatureEditorNameChanged)
-        .padding(10);
+    if filter.is_active() {
+        bar = bar.push(ui::ghost_button(t, "Clear filters".to_string(), Message::BestiaryClearFilters));
+    }
 
-    let kind_input = text_input("Kind / tags", &editor.kind)
-        .on_input(Message::CreatureEditorKindChanged)
-        .padding(10);
+    bar.push(container(iced::widget::Space::new()).width(Length::Fill))
+        .push(text("Sort by").size(12).color(t.muted_fg))
+        .push(pick_list(BestiarySort::ALL, Some(filter.sort), Message::BestiarySortChanged).padding(8))
+        .into()
+}
 
-    let habitat_input = text_input("Habitat", &editor.habitat)
-        .on_input(Message::CreatureEditorHabitatChanged)
-        .padding(10);
+/// Entry of the "Move / copy to" picker.
+#[derive(Debug, Clone, PartialEq)]
+struct UniverseChoice {
+    id: String,
+    name: String,
+}
 
-    let desc_input = text_input("Description", &editor.description)
-        .on_input(Message::CreatureEditorDescriptionChanged)
-        .padding(10);
+impl fmt::Display for UniverseChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// Autocomplete suggestions shown under the tag input.
+const TAG_SUGGESTIONS: usize = 6;
+
+fn editor_panel<'a>(state: &'a AppState, t: ui::Tokens, editor: &'a CreatureEditor) -> E<'a> {
+    let errors = editor.errors(&state.project);
 
-    let danger_input = text_input("Danger (e.g. Low/Medium/High/Extreme)", &editor.danger)
-        .on_input(Message::CreatureEditorDangerChanged)
+    let tag_input = text_input("Add tags (e.g. Elemental, Relicto)", &editor.tag_input)
+        .on_input(Message::CreatureEditorTagInputChanged)
+        .on_submit(Message::CreatureEditorTagSubmit)
         .padding(10);
 
-    let quick_danger = Row::new()
-        .spacing(10)
-        .push(ui::outline_button(
+    let mut tag_row = Row::new().spacing(6).align_y(Alignment::Center);
+    if editor.tags.is_empty() {
+        tag_row = tag_row.push(text("No tags yet.").size(12).color(t.muted_fg));
+    }
+    for tag in &editor.tags {
+        tag_row = tag_row.push(ui::outline_button(
             t,
-            "Low".to_string(),
-            Message::CreatureEditorDangerChanged("Low".to_string()),
-        ))
-        .push(ui::outline_button(
+            format!("{tag}  ×"),
+            Message::CreatureEditorRemoveTag(tag.clone()),
+        ));
+    }
+
+    let typed = editor.tag_input.trim().to_lowercase();
+    let suggestions: Vec<&str> = if typed.is_empty() {
+        Vec::new()
+    } else {
+        state
+            .project
+            .creature_tags_in(&editor.universe_id)
+            .into_iter()
+            .filter(|tag| tag.to_lowercase().contains(&typed))
+            .filter(|tag| !editor.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+            .take(TAG_SUGGESTIONS)
+            .collect()
+    };
+
+    let mut suggestion_row = Row::new().spacing(6).align_y(Alignment::Center);
+    if !suggestions.is_empty() {
+        suggestion_row = suggestion_row.push(text("Existing tags:").size(12).color(t.muted_fg));
+    }
+    for tag in suggestions {
+        suggestion_row = suggestion_row.push(ui::ghost_button(
             t,
-            "Medium".to_string(),
-            Message::CreatureEditorDangerChanged("Medium".to_string()),
-        ))
+            format!("+ {tag}"),
+            Message::CreatureEditorAddTag(tag.to_string()),
+        ));
+    }
+
+    let mut places = Row::new()
+        .spacing(6)
+        .align_y(Alignment::Center)
+        .push(text("Places").size(12).color(t.muted_fg));
+    for id in &editor.habitat_locations {
+        let name = state.project.location(id).map(|l| l.name.as_str()).unwrap_or(id.as_str());
+        places = places.push(ui::outline_button(
+            t,
+            format!("{name}  ×"),
+            Message::CreatureEditorRemoveHabitatLocation(id.clone()),
+        ));
+    }
+    let place_choices = location_choices(&state.project, &editor.universe_id, |l| {
+        !editor.habitat_locations.contains(&l.id)
+    });
+    if !place_choices.is_empty() {
+        places = places.push(
+            pick_list(place_choices, None::<LocationChoice>, |c: LocationChoice| {
+                Message::CreatureEditorAddHabitatLocation(c.id.unwrap_or_default())
+            })
+            .placeholder("Add a location…")
+            .padding(8),
+        );
+    } else if editor.habitat_locations.is_empty() {
+        places = places.push(text("This universe has no locations yet.").size(12).color(t.muted_fg));
+    }
+
+    let levels = state
+        .project
+        .universe(&editor.universe_id)
+        .map(|u| u.danger_choices())
+        .unwrap_or_else(|| DangerLevel::BUILT_IN.to_vec());
+
+    let mut danger_row = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(text("Danger").size(12).color(t.muted_fg));
+    for level in levels {
+        let label = level.to_string();
+        let on_press = Message::CreatureEditorDangerChanged(level.clone());
+        danger_row = danger_row.push(if level == editor.danger {
+            ui::primary_button(t, label, on_press)
+        } else {
+            ui::outline_button(t, label, on_press)
+        });
+    }
+
+    let custom_danger = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(
+            container(
+                text_input("New danger level for this universe", &editor.new_danger_level)
+                    .on_input(Message::CreatureEditorNewDangerLevelChanged)
+                    .on_submit(Message::CreatureEditorAddDangerLevel)
+                    .padding(8),
+            )
+            .width(Length::Fixed(280.0)),
+        )
+        .push(ui::outline_button(t, "Add level".to_string(), Message::CreatureEditorAddDangerLevel));
+
+    let choices: Vec<UniverseChoice> = state
+        .project
+        .workspace_universes()
+        .filter(|u| u.id != editor.universe_id && !u.archived)
+        .map(|u| UniverseChoice {
+            id: u.id.clone(),
+            name: u.name.clone(),
+        })
+        .collect();
+
+    let selected = editor
+        .transfer_target
+        .as_ref()
+        .and_then(|id| choices.iter().find(|c| &c.id == id).cloned());
+
+    let transfer = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(text("Move / copy to").size(12).color(t.muted_fg))
+        .push(
+            pick_list(choices, selected, |c: UniverseChoice| {
+                Message::CreatureEditorTransferTargetChanged(c.id)
+            })
+            .placeholder("Another universe")
+            .padding(8),
+        )
         .push(ui::outline_button(
             t,
-            "High".to_string(),
-            Message::CreatureEditorDangerChanged("High".to_string()),
+            "Move".to_string(),
+            Message::CreatureEditorTransfer { copy: false },
         ))
         .push(ui::outline_button(
             t,
-            "Extreme".to_string(),
-            Message::CreatureEditorDangerChanged("Extreme".to_string()),
+            "Copy".to_string(),
+            Message::CreatureEditorTransfer { copy: true },
         ));
 
+    let tags = Column::new()
+        .spacing(10)
+        .push(tag_row)
+        .push(container(tag_input).width(Length::Fill))
+        .push(suggestion_row);
+
+    let danger = Column::new()
+        .spacing(10)
+        .push(danger_row)
+        .push(errors.get(Field::Danger).map(ui::error_text))
+        .push(custom_danger)
+        .push(template_fields(t, editor))
+        .push(container(transfer).width(Length::Fill))
+        .push(editor.transfer_error.as_deref().map(ui::error_text))
+        .push(
+            text("Tip: click any creature card to open its full sheet.")
+                .size(10)
+                .color(ui::muted_small()),
+        );
+
+    editor_form(
+        state,
+        t,
+        editor,
+        vec![
+            (Field::Name, tags.into()),
+            (Field::Habitat, places.into()),
+            (Field::Description, danger.into()),
+        ],
+    )
+}
+
+/// Inputs for the universe's template fields, in template order.
+fn template_fields<'a>(t: ui::Tokens, editor: &'a CreatureEditor) -> E<'a> {
+    let mut col = Column::new().spacing(10);
+
+    for def in &editor.field_defs {
+        let id = def.id.clone();
+
+        let input: E<'a> = match def.kind {
+            FieldKind::LongText => match editor.long_fields.get(&def.id) {
+                Some(content) => text_editor(content)
+                    .placeholder(def.label.as_str())
+                    .on_action(move |action| Message::CreatureEditorLongFieldEdit(id.clone(), action))
+                    .padding(10)
+                    .height(Length::Fixed(120.0))
+                    .into(),
+                None => continue,
+            },
+            FieldKind::Enum => {
+                let current = editor.field_inputs.get(&def.id).filter(|v| !v.is_empty()).cloned();
+                let mut row = Row::new().spacing(10).align_y(Alignment::Center).push(
+                    pick_list(def.options.as_slice(), current.clone(), move |v: String| {
+                        Message::CreatureEditorFieldChanged(id.clone(), v)
+                    })
+                    .placeholder(def.label.as_str())
+                    .padding(8),
+                );
+                if current.is_some() {
+                    row = row.push(ui::ghost_button(
+                        t,
+                        "Clear".to_string(),
+                        Message::CreatureEditorFieldChanged(def.id.clone(), String::new()),
+                    ));
+                }
+                row.into()
+            }
+            FieldKind::Text | FieldKind::Number | FieldKind::List => {
+                let placeholder = match def.kind {
+                    FieldKind::List => format!("{} (comma-separated)", def.label),
+                    _ => def.label.clone(),
+                };
+                let value = editor.field_inputs.get(&def.id).map(String::as_str).unwrap_or("");
+                text_input(&placeholder, value)
+                    .on_input(move |v| Message::CreatureEditorFieldChanged(id.clone(), v))
+                    .padding(10)
+                    .into()
+            }
+        };
+
+        let mut field = Column::new()
+            .spacing(4)
+            .push(text(&def.label).size(12).color(t.muted_fg))
+            .push(container(input).width(Length::Fill));
+        if let Some(err) = editor.field_error(def) {
+            field = field.push(ui::error_text(err));
+        }
+
+        col = col.push(field);
+    }
+
+    col.into()
+}
+
+fn template_panel<'a>(t: ui::Tokens, editor: &'a TemplateEditor) -> E<'a> {
     let actions = Row::new()
         .spacing(10)
-        .push(ui::outline_button(
-            t,
-            "Cancel".to_string(),
-            Message::CreatureEditorCancel,
-        ))
-        .push(ui::primary_button(
+        .push(ui::outline_button(t, "Cancel".to_string(), Message::TemplateEditorCancel))
+        .push(ui::primary_button_maybe(
             t,
-            "Save".to_string(),
-            Message::CreatureEditorSave,
+            "Save template".to_string(),
+            editor.is_valid().then_some(Message::TemplateEditorSave),
         ));
 
     let header = Row::new()
         .align_y(Alignment::Center)
-        .push(container(text(title).size(16).color(t.foreground)).width(Length::Fill))
+        .push(
+            Column::new()
+                .spacing(4)
+                .push(text("Creature sheet template").size(16).color(t.foreground))
+                .push(
+                    text("Extra fields every creature of this universe can fill in.")
+                        .size(12)
+                        .color(t.muted_fg),
+                ),
+        )
+        .push(container(iced::widget::Space::new()).width(Length::Fill))
         .push(actions);
 
-    let form = Column::new()
-        .spacing(10)
-        .push(header)
-        .push(container(name_input).width(Length::Fill))
-        .push(container(kind_input).width(Length::Fill))
-        .push(container(habitat_input).width(Length::Fill))
-        .push(container(desc_input).width(Length::Fill))
-        .push(container(danger_input).width(Length::Fill))
-        .push(container(quick_danger).width(Length::Fill))
+    let mut form = Column::new().spacing(10).push(header);
+
+    if editor.fields.is_empty() {
+        form = form.push(text("No extra fields yet.").size(12).color(t.muted_fg));
+    }
+
+    let last = editor.fields.len().saturating_sub(1);
+    for (index, field) in editor.fields.iter().enumerate() {
+        let mut row = Row::new()
+            .spacing(10)
+            .align_y(Alignment::Center)
+            .push(
+                container(
+                    text_input("Field label", &field.label)
+                        .on_input(move |v| Message::TemplateFieldLabelChanged(index, v))
+                        .padding(8),
+                )
+                .width(Length::Fill),
+            )
+            .push(
+                pick_list(FieldKind::ALL, Some(field.kind), move |k| {
+                    Message::TemplateFieldKindChanged(index, k)
+                })
+                .padding(8),
+            );
+
+        if field.kind == FieldKind::Enum {
+            row = row.push(
+                container(
+                    text_input("Options (comma-separated)", &field.options)
+                        .on_input(move |v| Message::TemplateFieldOptionsChanged(index, v))
+                        .padding(8),
+                )
+                .width(Length::Fill),
+            );
+        }
+
+        row = row.push(
+            checkbox(field.on_card)
+                .label("On card")
+                .on_toggle(move |on| Message::TemplateFieldOnCardToggled(index, on)),
+        );
+
+        if index > 0 {
+            row = row.push(ui::ghost_button(t, "↑".to_string(), Message::TemplateFieldMove { index, up: true }));
+        }
+        if index < last {
+            row = row.push(ui::ghost_button(t, "↓".to_string(), Message::TemplateFieldMove { index, up: false }));
+        }
+        row = row.push(ui::ghost_button(t, "Remove".to_string(), Message::TemplateFieldRemove(index)));
+
+        let mut entry = Column::new().spacing(4).push(row);
+        if let Some(err) = field.error() {
+            entry = entry.push(ui::error_text(err));
+        }
+        form = form.push(entry);
+    }
+
+    form = form.push(
+        Row::new()
+            .spacing(10)
+            .align_y(Alignment::Center)
+            .push(ui::outline_button(t, "Add field".to_string(), Message::TemplateFieldAdd))
+            .push(
+                text("Removing a field hides it from sheets; values already entered are kept.")
+                    .size(10)
+                    .color(ui::muted_small()),
+            ),
+    );
+
+    ui::card(t, form.into())
+}
+
+/// Longest long-text excerpt shown on a card.
+const CARD_EXCERPT: usize = 80;
+
+/// "Label: value" line for each template field marked `on_card` that has a value.
+fn field_summary<'a>(t: ui::Tokens, c: &Creature, fields: &[FieldDef]) -> Option<E<'a>> {
+    let lines: Vec<String> = fields
+        .iter()
+        .filter(|def| def.on_card)
+        .filter_map(|def| {
+            let value = c.fields.get(&def.id)?;
+            let shown = match (def.kind, value) {
+                (FieldKind::LongText, FieldValue::Text(s)) => {
+                    let line = s.lines().next().unwrap_or_default();
+                    if line.chars().count() > CARD_EXCERPT || line.len() < s.len() {
+                        format!("{}…", line.chars().take(CARD_EXCERPT).collect::<String>())
+                    } else {
+                        line.to_string()
+                    }
+                }
+                _ => value.to_string(),
+            };
+            Some(format!("{}: {}", def.label, shown))
+        })
+        .collect();
+
+    if lines.is_empty() {
+        return None;
+    }
+
+    let col = lines
+        .into_iter()
+        .fold(Column::new().spacing(2), |col, line| col.push(text(line).size(12).color(t.muted_fg)));
+    Some(col.into())
+}
+
+/// Linked location names, then the free-text habitat.
+fn habitat_line(state: &AppState, c: &Creature) -> String {
+    let mut parts: Vec<&str> = c
+        .habitat_locations
+        .iter()
+        .filter_map(|id| state.project.location(id))
+        .map(|l| l.name.as_str())
+        .collect();
+    if !c.habitat.is_empty() {
+        parts.push(&c.habitat);
+    }
+
+    parts.join(" · ")
+}
+
+fn creature_card<'a>(state: &'a AppState, t: ui::Tokens, c: &'a Creature, fields: &[FieldDef]) -> E<'a> {
+    let extras = Column::new()
+        .push(text(format!("Habitat: {}", habitat_line(state, c))).size(12).color(t.muted_fg))
+        .push(text(format!("Danger: {}", c.danger)).size(12).color(t.foreground))
+        .push(field_summary(t, c, fields))
         .push(
-            text("Tip: double-click any creature card below to edit it.")
+            text("Click card to open the full sheet.")
                 .size(10)
-                .color(Color::from_rgba8(0xA1, 0xA1, 0xA1, 0.70)),
+                .color(Color::from_rgba8(0xA1, 0xA1, 0xA1, 0.55)),
         );
 
-    ui::card(t, form.into())
+    mouse_area(entry_card(state, t, c, extras))
+        .on_press(Message::OpenEntry(Codex::Creature, c.id.clone()))
+        .into()
 }
 
-fn creatures_grid<'a>(t: ui::Tokens, creatures: &'a [Creature]) -> E<'a> {
-    let mut col: Column<'a, Message> = Column::new().spacing(14).width(Length::Fill);
+/// Related creatures listed on a sheet.
+const RELATED_LIMIT: usize = 6;
+
+/// Full page for one creature: its whole sheet plus everything that points at it.
+pub fn creature_detail<'a>(state: &'a AppState, t: ui::Tokens, creature_id: &'a str) -> E<'a> {
+    let Some(c) = state.project.creature(creature_id) else {
+        let body = Column::new()
+            .spacing(14)
+            .push(text("This creature no longer exists.").size(16).color(t.foreground))
+            .push(ui::outline_button(t, "All universes".to_string(), Message::BackToUniverses));
+        return ui::page_padding(body.into());
+    };
 
-    let mut row: Row<'a, Message> = Row::new().spacing(14).width(Length::Fill);
-    let mut count: usize = 0;
+    let universe_name = state
+        .project
+        .universe(&c.universe_id)
+        .map(|u| u.name.as_str())
+        .unwrap_or(c.universe_id.as_str());
 
-    for (idx, c) in creatures.iter().enumerate() {
-        row = row.push(container(creature_card(t, idx, c)).width(Length::Fill));
-        count += 1;
+    let mut subtitle = format!("Creature · {universe_name}");
+    if c.archived {
+        subtitle.push_str(" · Archived, restore it to edit");
+    }
+
+    let header_left = Column::new()
+        .spacing(4)
+        .push(text(&c.name).size(26).color(t.foreground))
+        .push(text(subtitle).size(12).color(t.muted_fg));
 
-        if count == 3 {
-            col = col.push(row);
-            row = Row::new().spacing(14).width(Length::Fill);
-            count = 0;
+    let editing = state.creature_editor.as_ref().filter(|ed| ed.id.as_deref() == Some(c.id.as_str()));
+
+    let mut header_right = Row::new().spacing(10).push(ui::outline_button(
+        t,
+        "Back to bestiary".to_string(),
+        Message::OpenCodex(Codex::Creature, c.universe_id.clone()),
+    ));
+
+    if c.archived {
+        header_right = header_right.push(ui::outline_button(
+            t,
+            "Restore".to_string(),
+            Message::RestoreEntry(Codex::Creature, c.id.clone()),
+        ));
+    } else {
+        header_right = header_right.push(ui::outline_button(
+            t,
+            "Archive".to_string(),
+            Message::ArchiveEntry(Codex::Creature, c.id.clone()),
+        ));
+        if editing.is_none() {
+            header_right = header_right.push(ui::primary_button(
+                t,
+                "Edit".to_string(),
+                Message::EditorOpenEdit(Codex::Creature, c.id.clone()),
+            ));
         }
     }
 
-    if count != 0 {
-        col = col.push(row);
+    header_right = header_right.push(ui::danger_button(
+        t,
+        "Delete".to_string(),
+        Message::DeleteEntry(Codex::Creature, c.id.clone()),
+    ));
+
+    let header = Row::new()
+        .align_y(Alignment::Center)
+        .push(container(header_left).width(Length::Fill))
+        .push(header_right);
+
+    let mut body = Column::new().spacing(14).push(header).width(Length::Fill);
+
+    if state.pending_delete(Codex::Creature) == Some(c.id.as_str()) {
+        body = body.push(ui::card(t, delete_prompt(t, c)));
     }
 
-    col.into()
+    if let Some(editor) = editing {
+        body = body.push(editor_panel(state, t, editor));
+    }
+
+    let fields = state.project.creature_fields(&c.universe_id);
+
+    let side = Column::new()
+        .spacing(14)
+        .push(images_card(state, t, c))
+        .push(related_card(state, t, c));
+
+    let top = Row::new()
+        .spacing(14)
+        .push(container(sheet_card(state, t, c, fields)).width(Length::FillPortion(2)))
+        .push(container(side).width(Length::FillPortion(1)));
+
+    body = body
+        .push(top)
+        .push(mentions_card(state, t, c))
+        .push(history_card(t, c));
+
+    ui::page_padding(body.into())
 }
 
-fn creature_card<'a>(t: ui::Tokens, index: usize, c: &'a Creature) -> E<'a> {
-    let body = Column::new()
-        .spacing(6)
-        .push(text(&c.name).size(16).color(t.foreground))
-        .push(text(&c.kind).size(12).color(t.muted_fg))
-        .push(text(format!("Habitat: {}", c.habitat)).size(12).color(t.muted_fg))
-        .push(text(&c.description).size(12).color(t.muted_fg))
-        .push(text(format!("Danger: {}", c.danger)).size(12).color(t.foreground))
+fn section_title<'a>(t: ui::Tokens, title: &'a str) -> E<'a> {
+    text(title).size(14).color(t.foreground).into()
+}
+
+fn muted_line<'a>(t: ui::Tokens, line: impl text::IntoFragment<'a>) -> E<'a> {
+    text(line).size(12).color(t.muted_fg).into()
+}
+
+fn sheet_card<'a>(state: &'a AppState, t: ui::Tokens, c: &'a Creature, fields: &'a [FieldDef]) -> E<'a> {
+    let mut tags = Row::new().spacing(6).align_y(Alignment::Center);
+    if c.tags.is_empty() {
+        tags = tags.push(muted_line(t, "No tags."));
+    }
+    for tag in &c.tags {
+        tags = tags.push(tag_pill(t, tag));
+    }
+
+    let entry = |label: &'a str, value: String| -> E<'a> {
+        Column::new()
+            .spacing(2)
+            .push(text(label).size(11).color(t.muted_fg))
+            .push(text(if value.is_empty() { "—".to_string() } else { value }).size(13).color(t.foreground))
+            .into()
+    };
+
+    let mut sheet = Column::new()
+        .spacing(12)
+        .push(section_title(t, "Sheet"))
+        .push(tags)
+        .push(entry("Danger", c.danger.to_string()))
+        .push(entry("Habitat", c.habitat.clone()))
+        .push(places_entry(state, t, c))
+        .push(carries_entry(state, t, c))
         .push(
+            Column::new()
+                .spacing(2)
+                .push(text("Description").size(11).color(t.muted_fg))
+                .push(if c.description.is_empty() {
+                    text("—").size(13).color(t.foreground).into()
+                } else {
+                    ui::markdown_text(state, t, &c.description, 13)
+                }),
+        );
+
+    for def in fields {
+        let value = c.fields.get(&def.id).map(FieldValue::to_string).unwrap_or_default();
+        sheet = sheet.push(entry(&def.label, value));
+    }
+
+    ui::card(t, sheet.into())
+}
+
+/// Linked locations, each opening its place in the Locations codex.
+fn places_entry<'a>(state: &'a AppState, t: ui::Tokens, c: &'a Creature) -> Option<E<'a>> {
+    let places: Vec<_> = c.habitat_locations.iter().filter_map(|id| state.project.location(id)).collect();
+    if places.is_empty() {
+        return None;
+    }
+
+    let row = places.into_iter().fold(Row::new().spacing(6), |row, l| {
+        row.push(ui::ghost_button(t, state.project.location_label(&l.id), Message::OpenEntry(Codex::Location, l.id.clone())))
+    });
+
+    Some(
+        Column::new()
+            .spacing(2)
+            .push(text("Places").size(11).color(t.muted_fg))
+            .push(row)
+            .into(),
+    )
+}
+
+/// Artifacts the creature holds, each opening its entry in the Artifacts catalogue.
+fn carries_entry<'a>(state: &'a AppState, t: ui::Tokens, c: &'a Creature) -> Option<E<'a>> {
+    let holder = Holder::Creature(c.id.clone());
+    let carried: Vec<_> = state.project.artifacts_held_by(&holder).filter(|a| !a.archived).collect();
+    if carried.is_empty() {
+        return None;
+    }
+
+    let row = carried.into_iter().fold(Row::new().spacing(6), |row, a| {
+        row.push(ui::ghost_button(t, a.name.clone(), Message::OpenEntry(Codex::Artifact, a.id.clone())))
+    });
+
+    Some(
+        Column::new()
+            .spacing(2)
+            .push(text("Carries").size(11).color(t.muted_fg))
+            .push(row)
+            .into(),
+    )
+}
+
+fn images_card<'a>(state: &'a AppState, t: ui::Tokens, c: &'a Creature) -> E<'a> {
+    let mut col = Column::new().spacing(10).push(section_title(t, "Images & assets"));
+
+    let mut any = false;
+    for a in state.project.assets_for_creature(&c.id) {
+        any = true;
+        col = col
+            .push(preview(state, t, a, 180.0))
+            .push(muted_line(t, a.name.as_str()));
+    }
+
+    if !any {
+        col = col
+            .push(muted_line(t, "Nothing attached yet."))
+            .push(ui::ghost_button(
+                t,
+                "Attach from Assets →".to_string(),
+                Message::Navigate(crate::app::Route::Assets),
+            ));
+    }
+
+    ui::card(t, col.into())
+}
+
+fn related_card<'a>(state: &'a AppState, t: ui::Tokens, c: &'a Creature) -> E<'a> {
+    let mut col = Column::new().spacing(6).push(section_title(t, "Related creatures"));
+
+    let related = state.project.related_creatures(c);
+    if related.is_empty() {
+        col = col.push(muted_line(t, "No creatures share its tags or habitat."));
+    }
+
+    for other in related.into_iter().take(RELATED_LIMIT) {
+        let label = Column::new()
+            .spacing(2)
+            .push(text(&other.name).size(13).color(t.foreground))
+            .push(text(other.tags.join(" · ")).size(11).color(t.muted_fg));
+
+        col = col.push(link_row(t, label.into(), Message::OpenEntry(Codex::Creature, other.id.clone())));
+    }
+
+    ui::card(t, col.into())
+}
+
+fn mentions_card<'a>(state: &'a AppState, t: ui::Tokens, c: &'a Creature) -> E<'a> {
+    let mut col = Column::new().spacing(6).push(section_title(t, "Mentioned in"));
+
+    let found = mentions(&state.project, &c.name, &ActivityTarget::Creature(c.id.clone()));
+    if found.is_empty() {
+        col = col.push(muted_line(t, "No other entry mentions it by name yet."));
+    }
+
+    for m in found {
+        let label = Column::new()
+            .spacing(2)
+            .push(text(m.title).size(13).color(t.foreground))
+            .push(
+                Row::new()
+                    .spacing(6)
+                    .push(text(format!("{}:", m.snippet.field)).size(11).color(t.muted_fg))
+                    .push(ui::highlighted(t, m.snippet.text, Some(m.snippet.highlight), 11, t.muted_fg)),
+            );
+
+        col = col.push(link_row(t, label.into(), Message::OpenActivity(m.target)));
+    }
+
+    ui::card(t, col.into())
+}
+
+fn history_card<'a>(t: ui::Tokens, c: &'a Creature) -> E<'a> {
+    let mut col = Column::new().spacing(6).push(section_title(t, "Edit history"));
+
+    for rev in c.history.recent() {
+        col = col.push(
             Row::new()
-                .spacing(10)
-                .push(ui::outline_button(
-                    t,
-                    "Archive".to_string(),
-                    Message::Navigate(crate::app::Route::UniverseList),
-                ))
-                .push(ui::danger_button(
-                    t,
-                    "Delete".to_string(),
-                    Message::Navigate(crate::app::Route::UniverseList),
-                )),
-        )
-        .push(
-            text("Double-click card to edit creature.")
-                .size(10)
-                .color(Color::from_rgba8(0xA1, 0xA1, 0xA1, 0.55)),
+                .spacing(12)
+                .push(text(format_timestamp(rev.at)).size(12).color(t.muted_fg))
+                .push(text(&rev.summary).size(12).color(t.foreground)),
         );
+    }
 
-    let card = ui::card(t, body.into());
+    if c.history.is_empty() {
+        col = col.push(muted_line(
+            t,
+            if c.edited_at > 0 {
+                format!("Last saved {}; earlier edits were not recorded.", format_timestamp(c.edited_at))
+            } else {
+                "No edits recorded yet.".to_string()
+            },
+        ));
+    }
+
+    ui::card(t, col.into())
+}
 
-    // Single click goes to AppState for timing; 2 clicks inside the window becomes a "double-click".
-    mouse_area(card)
-        .on_press(Message::BestiaryCardClicked(index))
+/// Full-width clickable row with a hover background.
+fn link_row<'a>(t: ui::Tokens, content: E<'a>, on_press: Message) -> E<'a> {
+    button(content)
+        .width(Length::Fill)
+        .padding([6, 10])
+        .style(move |_, status| {
+            let mut s = button::Style::default();
+            let bg = match status {
+                button::Status::Hovered => t.hover_bg,
+                button::Status::Pressed => t.active_bg,
+                _ => Color::TRANSPARENT,
+            };
+            s.background = Some(iced::Background::Color(bg));
+            s.border.radius = iced::border::Radius::from(8.0);
+            s
+        })
+        .on_press(on_press)
         .into()
 }
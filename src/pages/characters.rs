@@ -0,0 +1,379 @@
+use std::fmt;
+
+use iced::{Alignment, Length};
+use iced::widget::{container, pick_list, text, text_input, Column, Row};
+
+use crate::app::{AppState, CharacterEditor, FactionEditor, Message};
+use crate::forms::Form;
+use crate::model::validation::Field;
+use crate::model::{Artifact, Character, Codex, Faction, Holder, Stance};
+use crate::pages::codex::{editor, entry_card, grid, header};
+use crate::{pages::E, ui};
+
+pub fn characters<'a>(state: &'a AppState, t: ui::Tokens, universe_id: &'a str) -> E<'a> {
+    let header = header(
+        state,
+        t,
+        Codex::Character,
+        universe_id,
+        "The cast of this universe, the factions they serve and the ties between them.",
+        None,
+    );
+
+    let (archived, active): (Vec<&Character>, Vec<&Character>) =
+        sorted(state.project.characters_in(universe_id).collect(), |c| &c.name)
+            .into_iter()
+            .partition(|c| c.archived);
+
+    let mut body = Column::new()
+        .spacing(14)
+        .push(header)
+        .width(Length::Fill);
+
+    if let Some(editor) = state.character_editor.as_ref().filter(|ed| ed.universe_id == universe_id) {
+        body = body.push(character_editor_panel(state, t, editor));
+    }
+
+    let cards = |list: &[&'a Character]| -> Vec<E<'a>> {
+        list.iter()
+            .map(|c| character_card(state, t, c))
+            .collect()
+    };
+
+    body = body
+        .push(text(format!("Cast ({})", active.len())).size(12).color(t.muted_fg))
+        .push(grid(t, cards(&active), "No characters yet. Add the first one."))
+        .push(text(format!("Archived characters ({})", archived.len())).size(12).color(t.muted_fg))
+        .push(grid(t, cards(&archived), "No archived characters."));
+
+    ui::page_padding(body.into())
+}
+
+pub fn factions<'a>(state: &'a AppState, t: ui::Tokens, universe_id: &'a str) -> E<'a> {
+    let header = header(
+        state,
+        t,
+        Codex::Faction,
+        universe_id,
+        "Guilds, houses and orders: their ranks, their members and where they stand with each other.",
+        None,
+    );
+
+    let (archived, active): (Vec<&Faction>, Vec<&Faction>) =
+        sorted(state.project.factions_in(universe_id).collect(), |f| &f.name)
+            .into_iter()
+            .partition(|f| f.archived);
+
+    let mut body = Column::new()
+        .spacing(14)
+        .push(header)
+        .width(Length::Fill);
+
+    if let Some(editor) = state.faction_editor.as_ref().filter(|ed| ed.universe_id == universe_id) {
+        body = body.push(faction_editor_panel(state, t, editor));
+    }
+
+    let cards = |list: &[&'a Faction]| -> Vec<E<'a>> {
+        list.iter()
+            .map(|f| faction_card(state, t, f))
+            .collect()
+    };
+
+    body = body
+        .push(text(format!("Factions ({})", active.len())).size(12).color(t.muted_fg))
+        .push(grid(t, cards(&active), "No factions yet. Add the first one."))
+        .push(text(format!("Archived factions ({})", archived.len())).size(12).color(t.muted_fg))
+        .push(grid(t, cards(&archived), "No archived factions."));
+
+    ui::page_padding(body.into())
+}
+
+fn sorted<T>(mut list: Vec<&T>, name: impl Fn(&T) -> &String) -> Vec<&T> {
+    list.sort_by_key(|x| name(x).to_lowercase());
+    list
+}
+
+fn character_card<'a>(state: &'a AppState, t: ui::Tokens, c: &'a Character) -> E<'a> {
+    let project = &state.project;
+
+    let mut body = Column::new();
+
+    let memberships: Vec<String> = c
+        .memberships
+        .iter()
+        .filter_map(|m| {
+            let faction = project.faction(&m.faction_id)?;
+            Some(if m.rank.is_empty() {
+                faction.name.clone()
+            } else {
+                format!("{}, {}", m.rank, faction.name)
+            })
+        })
+        .collect();
+    if !memberships.is_empty() {
+        body = body.push(text(memberships.join(" · ")).size(12).color(t.foreground));
+    }
+
+    let ties = c
+        .relationships
+        .iter()
+        .filter_map(|r| project.character(&r.character_id).map(|other| (format!("{}:", r.kind), other)))
+        .chain(
+            project
+                .relationships_towards(&c.id)
+                .filter(|(other, _)| other.universe_id == c.universe_id)
+                .map(|(other, r)| (format!("{} of", r.kind), other)),
+        );
+    for (label, other) in ties {
+        body = body.push(
+            Row::new()
+                .spacing(6)
+                .align_y(Alignment::Center)
+                .push(text(label).size(12).color(t.muted_fg))
+                .push(ui::ghost_button(
+                    t,
+                    other.name.clone(),
+                    Message::OpenEntry(Codex::Character, other.id.clone()),
+                )),
+        );
+    }
+
+    let holder = Holder::Character(c.id.clone());
+    let carried: Vec<&Artifact> = project.artifacts_held_by(&holder).filter(|a| !a.archived).collect();
+    if !carried.is_empty() {
+        let row = carried.into_iter().fold(
+            Row::new()
+                .spacing(6)
+                .align_y(Alignment::Center)
+                .push(text("Carries").size(12).color(t.muted_fg)),
+            |row, a| {
+                row.push(ui::ghost_button(
+                    t,
+                    a.name.clone(),
+                    Message::OpenEntry(Codex::Artifact, a.id.clone()),
+                ))
+            },
+        );
+        body = body.push(row.wrap());
+    }
+
+    entry_card(state, t, c, body)
+}
+
+fn faction_card<'a>(state: &'a AppState, t: ui::Tokens, f: &'a Faction) -> E<'a> {
+    let project = &state.project;
+
+    let mut body = Column::new();
+
+    for stance in Stance::ALL {
+        let names: Vec<&str> = f
+            .allegiances
+            .iter()
+            .filter(|a| a.stance == stance)
+            .filter_map(|a| project.faction(&a.faction_id).map(|o| o.name.as_str()))
+            .collect();
+        if !names.is_empty() {
+            body = body.push(text(format!("{stance}: {}", names.join(", "))).size(12).color(t.foreground));
+        }
+    }
+
+    // Members in rank order, unranked last.
+    let mut members: Vec<_> = project.faction_members(&f.id).filter(|(c, _)| !c.archived).collect();
+    members.sort_by_key(|(c, m)| {
+        let rank = f.ranks.iter().position(|r| *r == m.rank).unwrap_or(f.ranks.len());
+        (rank, c.name.to_lowercase())
+    });
+    if !members.is_empty() {
+        let row = members.into_iter().fold(
+            Row::new()
+                .spacing(6)
+                .align_y(Alignment::Center)
+                .push(text("Members").size(12).color(t.muted_fg)),
+            |row, (c, m)| {
+                let label = if m.rank.is_empty() {
+                    c.name.clone()
+                } else {
+                    format!("{} ({})", c.name, m.rank)
+                };
+                row.push(ui::ghost_button(t, label, Message::OpenEntry(Codex::Character, c.id.clone())))
+            },
+        );
+        body = body.push(row.wrap());
+    }
+
+    entry_card(state, t, f, body)
+}
+
+/// Entry of a character or faction picker.
+#[derive(Debug, Clone, PartialEq)]
+struct Choice {
+    id: String,
+    label: String,
+}
+
+impl fmt::Display for Choice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+fn choices<'a>(items: impl Iterator<Item = (&'a String, &'a String)>) -> Vec<Choice> {
+    let mut choices: Vec<Choice> = items
+        .map(|(id, label)| Choice {
+            id: id.clone(),
+            label: label.clone(),
+        })
+        .collect();
+    choices.sort_by_key(|c| c.label.to_lowercase());
+    choices
+}
+
+fn character_editor_panel<'a>(state: &'a AppState, t: ui::Tokens, ed: &'a CharacterEditor) -> E<'a> {
+    let project = &state.project;
+    let errors = ed.errors(project);
+
+    // Factions: one row per membership, with a rank picker when the faction defines ranks.
+    let mut memberships = Column::new()
+        .spacing(8)
+        .push(text("Factions").size(12).color(t.muted_fg));
+    for (i, m) in ed.memberships.iter().enumerate() {
+        let Some(faction) = project.faction(&m.faction_id) else {
+            continue;
+        };
+
+        let rank: E<'a> = if faction.ranks.is_empty() {
+            text_input("Rank (optional)", &m.rank)
+                .on_input(move |v| Message::CharacterEditorRankChanged(i, v))
+                .padding(8)
+                .into()
+        } else {
+            let selected = faction.ranks.iter().find(|r| **r == m.rank).cloned();
+            pick_list(faction.ranks.clone(), selected, move |r| Message::CharacterEditorRankChanged(i, r))
+                .placeholder("Rank")
+                .padding(8)
+                .into()
+        };
+
+        memberships = memberships.push(
+            Row::new()
+                .spacing(10)
+                .align_y(Alignment::Center)
+                .push(container(text(&faction.name).size(14).color(t.foreground)).width(Length::Fill))
+                .push(container(rank).width(Length::FillPortion(1)))
+                .push(ui::ghost_button(t, "Remove".to_string(), Message::CharacterEditorRemoveMembership(i))),
+        );
+    }
+
+    let joinable = choices(
+        project
+            .factions_in(&ed.universe_id)
+            .filter(|f| !f.archived && !ed.memberships.iter().any(|m| m.faction_id == f.id))
+            .map(|f| (&f.id, &f.name)),
+    );
+    if !joinable.is_empty() {
+        memberships = memberships.push(
+            pick_list(joinable, None::<Choice>, |c| Message::CharacterEditorAddMembership(c.id))
+                .placeholder("Join a faction…")
+                .padding(8),
+        );
+    }
+
+    // Relationships: what each other character is to this one.
+    let mut relationships = Column::new()
+        .spacing(8)
+        .push(text("Relationships").size(12).color(t.muted_fg));
+    for (i, r) in ed.relationships.iter().enumerate() {
+        let Some(other) = project.character(&r.character_id) else {
+            continue;
+        };
+
+        relationships = relationships.push(
+            Row::new()
+                .spacing(10)
+                .align_y(Alignment::Center)
+                .push(container(text(&other.name).size(14).color(t.foreground)).width(Length::Fill))
+                .push(
+                    container(
+                        text_input("Is their… (e.g. Mentor, Sister)", &r.kind)
+                            .on_input(move |v| Message::CharacterEditorRelationshipKindChanged(i, v))
+                            .padding(8),
+                    )
+                    .width(Length::FillPortion(1)),
+                )
+                .push(ui::ghost_button(
+                    t,
+                    "Remove".to_string(),
+                    Message::CharacterEditorRemoveRelationship(i),
+                )),
+        );
+    }
+
+    let others = choices(
+        project
+            .characters_in(&ed.universe_id)
+            .filter(|c| !c.archived && ed.id.as_deref() != Some(c.id.as_str()))
+            .map(|c| (&c.id, &c.name)),
+    );
+    if !others.is_empty() {
+        relationships = relationships.push(
+            pick_list(others, None::<Choice>, |c| Message::CharacterEditorAddRelationship(c.id))
+                .placeholder("Add a relationship with…")
+                .padding(8),
+        );
+    }
+
+    let memberships = memberships.push(errors.get(Field::Memberships).map(ui::error_text));
+    let relationships = relationships.push(errors.get(Field::Relationships).map(ui::error_text));
+
+    editor(
+        state,
+        t,
+        ed,
+        vec![
+            (Field::Description, memberships.into()),
+            (Field::Description, relationships.into()),
+        ],
+    )
+}
+
+fn faction_editor_panel<'a>(state: &'a AppState, t: ui::Tokens, ed: &'a FactionEditor) -> E<'a> {
+    let project = &state.project;
+
+    let mut allegiances = Column::new()
+        .spacing(8)
+        .push(text("Stance towards other factions").size(12).color(t.muted_fg));
+    for (i, a) in ed.allegiances.iter().enumerate() {
+        let Some(other) = project.faction(&a.faction_id) else {
+            continue;
+        };
+
+        allegiances = allegiances.push(
+            Row::new()
+                .spacing(10)
+                .align_y(Alignment::Center)
+                .push(container(text(&other.name).size(14).color(t.foreground)).width(Length::Fill))
+                .push(pick_list(Stance::ALL, Some(a.stance), move |s| Message::FactionEditorStanceChanged(i, s)).padding(8))
+                .push(ui::ghost_button(t, "Remove".to_string(), Message::FactionEditorRemoveAllegiance(i))),
+        );
+    }
+
+    let others = choices(
+        project
+            .factions_in(&ed.universe_id)
+            .filter(|f| {
+                !f.archived
+                    && ed.id.as_deref() != Some(f.id.as_str())
+                    && !ed.allegiances.iter().any(|a| a.faction_id == f.id)
+            })
+            .map(|f| (&f.id, &f.name)),
+    );
+    if !others.is_empty() {
+        allegiances = allegiances.push(
+            pick_list(others, None::<Choice>, |c| Message::FactionEditorAddAllegiance(c.id))
+                .placeholder("Add a stance towards…")
+                .padding(8),
+        );
+    }
+
+    editor(state, t, ed, vec![(Field::Description, allegiances.into())])
+}
@@ -0,0 +1,262 @@
+//! What every codex page shares: its header, the card grid, an entry's card and actions,
+//! and the editor panel built from the form's [`Form::FIELDS`]. Each page only adds
+//! what its kind of entry has on top.
+
+use std::fmt;
+
+use iced::{Alignment, Length};
+use iced::widget::{container, text, text_editor, text_input, Column, Row};
+
+use crate::app::{AppState, Message};
+use crate::forms::{Form, Input};
+use crate::model::validation::Field;
+use crate::model::{Codex, Entity, Entry};
+use crate::pages::{artifacts, bestiary, characters, locations, runomicon};
+use crate::{pages::E, ui};
+
+pub fn codex<'a>(state: &'a AppState, t: ui::Tokens, codex: Codex, universe_id: &'a str) -> E<'a> {
+    match codex {
+        Codex::Creature => bestiary::bestiary(state, t, universe_id),
+        Codex::Location => locations::locations(state, t, universe_id),
+        Codex::Character => characters::characters(state, t, universe_id),
+        Codex::Faction => characters::factions(state, t, universe_id),
+        Codex::Term => runomicon::runomicon(state, t, universe_id),
+        Codex::Artifact => artifacts::artifacts(state, t, universe_id),
+    }
+}
+
+/// Title, subtitle and navigation of a codex page; `extra` sits before the add button.
+pub fn header<'a>(
+    state: &'a AppState,
+    t: ui::Tokens,
+    codex: Codex,
+    universe_id: &'a str,
+    subtitle: &'a str,
+    extra: Option<E<'a>>,
+) -> E<'a> {
+    let universe_name = state
+        .project
+        .universe(universe_id)
+        .map(|u| u.name.as_str())
+        .unwrap_or(universe_id);
+
+    let header_left = Column::new()
+        .spacing(4)
+        .push(text(format!("{} — {universe_name}", codex.title())).size(26).color(t.foreground))
+        .push(text(subtitle).size(12).color(t.muted_fg));
+
+    let header_right = Row::new()
+        .spacing(10)
+        .push(ui::outline_button(
+            t,
+            "Back to universe".to_string(),
+            Message::BackToUniverse(universe_id.to_string()),
+        ))
+        .push(ui::outline_button(
+            t,
+            "All universes".to_string(),
+            Message::BackToUniverses,
+        ))
+        .push(extra)
+        .push(ui::primary_button(
+            t,
+            format!("Add {}", codex.noun()),
+            Message::EditorOpenCreate(codex, universe_id.to_string()),
+        ));
+
+    Row::new()
+        .align_y(Alignment::Center)
+        .push(container(header_left).width(Length::Fill))
+        .push(header_right)
+        .into()
+}
+
+/// Cards three to a row, or an empty-state card.
+pub fn grid<'a>(t: ui::Tokens, cards: Vec<E<'a>>, empty: &'a str) -> E<'a> {
+    if cards.is_empty() {
+        return ui::card(
+            t,
+            container(text(empty).size(12).color(t.muted_fg))
+                .width(Length::Fill)
+                .padding([14, 14])
+                .into(),
+        );
+    }
+
+    let mut col: Column<'a, Message> = Column::new().spacing(14).width(Length::Fill);
+    let mut cards = cards.into_iter().peekable();
+
+    while cards.peek().is_some() {
+        let mut row: Row<'a, Message> = Row::new().spacing(14).width(Length::Fill);
+        for card in cards.by_ref().take(3) {
+            row = row.push(container(card).width(Length::Fill));
+        }
+        col = col.push(row);
+    }
+
+    col.into()
+}
+
+/// Name, summary and first paragraph of an entry, then `extras` and its actions.
+pub fn entry_card<'a>(state: &'a AppState, t: ui::Tokens, entry: &'a dyn Entry, extras: Column<'a, Message>) -> E<'a> {
+    let summary = entry.summary(&state.project);
+
+    let body = Column::new()
+        .spacing(6)
+        .push(text(entry.name()).size(16).color(t.foreground))
+        .push((!summary.is_empty()).then(|| text(summary).size(12).color(t.muted_fg)))
+        .push(ui::markdown_text(state, t, ui::first_paragraph(entry.description()), 12))
+        .push(extras.spacing(6))
+        .push(actions(state, t, entry));
+
+    ui::card(t, body.into())
+}
+
+/// Confirmation shown once Delete was pressed on `entry`.
+pub fn delete_prompt<'a>(t: ui::Tokens, entry: &dyn Entry) -> E<'a> {
+    let note = match entry.codex() {
+        Codex::Location => "Places inside it move up one level.",
+        _ => "Links to it are removed too.",
+    };
+
+    ui::confirm_delete(
+        t,
+        format!("Delete \"{}\"? {note}", entry.name()),
+        Message::DeleteEntryCancel,
+        Message::DeleteEntryConfirm,
+    )
+}
+
+/// Edit/Archive/Delete, Restore/Delete, or the inline delete confirmation.
+pub fn actions<'a>(state: &AppState, t: ui::Tokens, entry: &dyn Entry) -> E<'a> {
+    let codex = entry.codex();
+    let id = entry.id().to_string();
+
+    if state.pending_delete(codex) == Some(entry.id()) {
+        return delete_prompt(t, entry);
+    }
+
+    let row = if entry.archived() {
+        Row::new()
+            .spacing(10)
+            .push(ui::outline_button(t, "Restore".to_string(), Message::RestoreEntry(codex, id.clone())))
+    } else {
+        Row::new()
+            .spacing(10)
+            .push(ui::outline_button(t, "Edit".to_string(), Message::EditorOpenEdit(codex, id.clone())))
+            .push(ui::outline_button(t, "Archive".to_string(), Message::ArchiveEntry(codex, id.clone())))
+    };
+
+    row.push(ui::danger_button(t, "Delete".to_string(), Message::DeleteEntry(codex, id)))
+        .into()
+}
+
+/// Editor panel of `form`: each of its fields with its error, followed by the page's
+/// `sections` anchored to that field. Sections anchored to no field go last.
+pub fn editor<'a, F: Form>(state: &'a AppState, t: ui::Tokens, form: &'a F, sections: Vec<(Field, E<'a>)>) -> E<'a> {
+    let codex = F::Entity::CODEX;
+    let errors = form.errors(&state.project);
+
+    let title = if form.id().is_some() {
+        format!("Edit {}", codex.noun())
+    } else {
+        format!("Add {}", codex.noun())
+    };
+
+    let actions = Row::new()
+        .spacing(10)
+        .push(ui::outline_button(t, "Cancel".to_string(), Message::EditorCancel(codex)))
+        .push(ui::primary_button_maybe(
+            t,
+            "Save".to_string(),
+            form.can_save(&state.project).then_some(Message::EditorSave(codex)),
+        ));
+
+    let header = Row::new()
+        .align_y(Alignment::Center)
+        .push(container(text(title).size(16).color(t.foreground)).width(Length::Fill))
+        .push(actions);
+
+    // The Markdown hint follows the last input that takes Markdown.
+    let hinted = F::FIELDS
+        .iter()
+        .rposition(|f| matches!(f.input, Input::Text { markdown: true, .. }));
+
+    let mut sections = sections;
+    let mut col = Column::new().spacing(10).push(header);
+
+    for (i, f) in F::FIELDS.iter().enumerate() {
+        let field = f.field;
+        let input: E<'a> = match f.input {
+            Input::Line { get, .. } => text_input(f.placeholder, get(form))
+                .on_input(move |v| Message::EditorLineChanged(codex, field, v))
+                .padding(10)
+                .into(),
+            Input::Text { get, height, .. } => text_editor(get(form))
+                .placeholder(f.placeholder)
+                .on_action(move |action| Message::EditorTextEdit(codex, field, action))
+                .padding(10)
+                .height(Length::Fixed(height))
+                .into(),
+        };
+
+        col = col
+            .push(container(input).width(Length::Fill))
+            .push(errors.get(field).map(ui::error_text));
+        if hinted == Some(i) {
+            col = col.push(ui::markdown_hint());
+        }
+
+        let (here, rest): (Vec<_>, Vec<_>) = sections.into_iter().partition(|(anchor, _)| *anchor == field);
+        sections = rest;
+        for (_, section) in here {
+            col = col.push(section);
+        }
+    }
+
+    for (_, section) in sections {
+        col = col.push(section);
+    }
+
+    ui::card(t, col.into())
+}
+
+/// Entry of facet pickers such as danger or tag; `value == None` means "any".
+#[derive(Debug, Clone, PartialEq)]
+pub struct FacetChoice<T> {
+    pub value: Option<T>,
+    pub label: String,
+}
+
+impl<T> fmt::Display for FacetChoice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+/// "Any" followed by each value with the number of entries carrying it.
+pub fn facet_choices<T: fmt::Display>(any: &str, values: Vec<(T, usize)>) -> Vec<FacetChoice<T>> {
+    std::iter::once(FacetChoice {
+        value: None,
+        label: any.to_string(),
+    })
+    .chain(values.into_iter().map(|(value, n)| FacetChoice {
+        label: format!("{value} ({n})"),
+        value: Some(value),
+    }))
+    .collect()
+}
+
+/// Counts values equal under `same`, keeping the first one seen.
+pub fn tally<T: Clone>(values: impl Iterator<Item = T>, same: impl Fn(&T, &T) -> bool) -> Vec<(T, usize)> {
+    let mut counts: Vec<(T, usize)> = Vec::new();
+
+    for v in values {
+        match counts.iter_mut().find(|(seen, _)| same(seen, &v)) {
+            Some((_, n)) => *n += 1,
+            None => counts.push((v, 1)),
+        }
+    }
+
+    counts
+}
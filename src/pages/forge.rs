@@ -0,0 +1,331 @@
+use iced::{Alignment, Length};
+use iced::widget::{container, pick_list, text, text_editor, text_input, Column, Row};
+
+use crate::app::{AppState, ForgeEntry, Message, Route, SceneEditor, TitleField};
+use crate::model::{Chapter, Manuscript, ManuscriptKind};
+use crate::{pages::E, ui};
+
+pub fn forge<'a>(state: &'a AppState, t: ui::Tokens) -> E<'a> {
+    let title = Column::new()
+        .spacing(4)
+        .push(text("The Forge").size(26).color(t.foreground))
+        .push(text("Novels, outlines and scenes.").size(12).color(t.muted_fg));
+
+    let title_input = text_input("Manuscript title", &state.new_manuscript_title)
+        .on_input(Message::ForgeNewTitleChanged)
+        .padding(10);
+
+    let kind_pick = pick_list(
+        &ManuscriptKind::ALL[..],
+        Some(state.new_manuscript_kind),
+        Message::ForgeNewKindChanged,
+    )
+    .padding(10);
+
+    let form = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(container(title_input).width(Length::Fixed(260.0)))
+        .push(kind_pick)
+        .push(ui::primary_button(t, "Create manuscript".to_string(), Message::CreateManuscript));
+
+    let header = Row::new()
+        .align_y(Alignment::Center)
+        .push(container(title).width(Length::Fill))
+        .push(form);
+
+    let manuscripts: Vec<&Manuscript> = state.project.workspace_manuscripts().collect();
+
+    let list: E<'a> = if manuscripts.is_empty() {
+        ui::card(
+            t,
+            container(text("No manuscripts yet.").size(12).color(t.muted_fg))
+                .width(Length::Fill)
+                .padding([14, 14])
+                .into(),
+        )
+    } else {
+        let mut col = Column::new().spacing(10);
+        for m in manuscripts {
+            col = col.push(manuscript_card(t, m, state.pending_forge_delete.as_ref()));
+        }
+        col.into()
+    };
+
+    let body = Column::new()
+        .spacing(14)
+        .push(header)
+        .push(text("Manuscripts").size(12).color(t.muted_fg))
+        .push(list)
+        .width(Length::Fill);
+
+    ui::page_padding(body.into())
+}
+
+fn manuscript_card<'a>(t: ui::Tokens, m: &'a Manuscript, pending: Option<&ForgeEntry>) -> E<'a> {
+    let entry = ForgeEntry::Manuscript(m.id.clone());
+
+    let left = Column::new()
+        .spacing(4)
+        .push(text(&m.title).size(16).color(t.foreground))
+        .push(text(stats(m)).size(12).color(t.muted_fg));
+
+    let actions = if pending == Some(&entry) {
+        ui::confirm_delete(
+            t,
+            format!("Delete \"{}\" and everything in it? This cannot be undone.", m.title),
+            Message::DeleteForgeCancel,
+            Message::DeleteForgeConfirm,
+        )
+    } else {
+        Row::new()
+            .spacing(10)
+            .push(ui::outline_button(t, "Open".to_string(), Message::OpenManuscript(m.id.clone())))
+            .push(ui::danger_button(t, "Delete".to_string(), Message::DeleteForgeEntry(entry)))
+            .into()
+    };
+
+    let body = Column::new().spacing(10).push(left).push(actions);
+
+    ui::card(t, body.into())
+}
+
+fn stats(m: &Manuscript) -> String {
+    format!(
+        "{} · {} chapter(s) · {} scene(s) · {} words",
+        m.kind,
+        m.chapters.len(),
+        m.scene_count(),
+        m.word_count()
+    )
+}
+
+pub fn manuscript<'a>(state: &'a AppState, t: ui::Tokens, manuscript_id: &'a str) -> E<'a> {
+    let Some(m) = state.project.manuscript(manuscript_id) else {
+        let missing = Column::new()
+            .spacing(10)
+            .push(text("This manuscript no longer exists.").size(12).color(t.muted_fg))
+            .push(ui::outline_button(t, "Back to The Forge".to_string(), Message::Navigate(Route::Forge)));
+
+        return ui::page_padding(ui::card(t, missing.into()));
+    };
+
+    let pending = state.pending_forge_delete.as_ref();
+    let entry = ForgeEntry::Manuscript(m.id.clone());
+
+    let header_left = Column::new()
+        .spacing(4)
+        .push(text(&m.title).size(26).color(t.foreground))
+        .push(text(stats(m)).size(12).color(t.muted_fg));
+
+    let header_right: E<'a> = if pending == Some(&entry) {
+        ui::confirm_delete(
+            t,
+            format!("Delete \"{}\" and everything in it? This cannot be undone.", m.title),
+            Message::DeleteForgeCancel,
+            Message::DeleteForgeConfirm,
+        )
+    } else {
+        Row::new()
+            .spacing(10)
+            .push(ui::outline_button(
+                t,
+                "Back to The Forge".to_string(),
+                Message::Navigate(Route::Forge),
+            ))
+            .push(ui::danger_button(t, "Delete manuscript".to_string(), Message::DeleteForgeEntry(entry)))
+            .push(ui::primary_button(t, "Add chapter".to_string(), Message::ForgeAddChapter))
+            .into()
+    };
+
+    let header = Row::new()
+        .align_y(Alignment::Center)
+        .push(container(header_left).width(Length::Fill))
+        .push(header_right);
+
+    let open_scene = state.scene_editor.as_ref().map(|ed| ed.scene_id.as_str());
+
+    let mut outline = Column::new().spacing(10).width(Length::Fixed(360.0));
+
+    if m.chapters.is_empty() {
+        outline = outline.push(ui::card(
+            t,
+            text("No chapters yet. Add one to start writing.").size(12).color(t.muted_fg).into(),
+        ));
+    }
+
+    for c in &m.chapters {
+        outline = outline.push(chapter_card(state, t, c, open_scene, pending));
+    }
+
+    let editor = match &state.scene_editor {
+        Some(ed) => scene_panel(state, t, m, ed),
+        None => ui::card(
+            t,
+            container(text("Select or add a scene to start writing.").size(12).color(t.muted_fg))
+                .width(Length::Fill)
+                .padding([14, 14])
+                .into(),
+        ),
+    };
+
+    let columns = Row::new()
+        .spacing(14)
+        .push(outline)
+        .push(container(editor).width(Length::Fill));
+
+    let body = Column::new()
+        .spacing(14)
+        .push(header)
+        .push(columns)
+        .width(Length::Fill);
+
+    ui::page_padding(body.into())
+}
+
+fn chapter_card<'a>(
+    state: &'a AppState,
+    t: ui::Tokens,
+    c: &'a Chapter,
+    open_scene: Option<&str>,
+    pending: Option<&ForgeEntry>,
+) -> E<'a> {
+    let chapter_entry = ForgeEntry::Chapter(c.id.clone());
+
+    let title = state.title_text(&TitleField::Chapter(c.id.clone()), &c.title);
+    let title_input = text_input("Chapter title", title)
+        .on_input({
+            let id = c.id.clone();
+            move |v| Message::ForgeChapterTitleChanged(id.clone(), v)
+        })
+        .on_submit(Message::TitleSubmit)
+        .padding(8);
+
+    let title_row = Row::new()
+        .spacing(6)
+        .align_y(Alignment::Center)
+        .push(container(title_input).width(Length::Fill))
+        .push(ui::ghost_button(
+            t,
+            "↑".to_string(),
+            Message::ForgeMoveChapter { chapter_id: c.id.clone(), up: true },
+        ))
+        .push(ui::ghost_button(
+            t,
+            "↓".to_string(),
+            Message::ForgeMoveChapter { chapter_id: c.id.clone(), up: false },
+        ));
+
+    let mut col = Column::new()
+        .spacing(8)
+        .push(title_row)
+        .push(text(format!("{} words", c.word_count())).size(10).color(ui::muted_small()));
+
+    for s in &c.scenes {
+        let label = format!("{} · {} words", s.title, s.word_count());
+        let open = if open_scene == Some(s.id.as_str()) {
+            ui::outline_button(t, label, Message::ForgeOpenScene(s.id.clone()))
+        } else {
+            ui::ghost_button(t, label, Message::ForgeOpenScene(s.id.clone()))
+        };
+
+        let scene_entry = ForgeEntry::Scene(s.id.clone());
+
+        let row: E<'a> = if pending == Some(&scene_entry) {
+            ui::confirm_delete(
+                t,
+                format!("Delete \"{}\" and everything in it? This cannot be undone.", s.title),
+                Message::DeleteForgeCancel,
+                Message::DeleteForgeConfirm,
+            )
+        } else {
+            Row::new()
+                .spacing(6)
+                .align_y(Alignment::Center)
+                .push(container(open).width(Length::Fill))
+                .push(ui::ghost_button(
+                    t,
+                    "↑".to_string(),
+                    Message::ForgeMoveScene { scene_id: s.id.clone(), up: true },
+                ))
+                .push(ui::ghost_button(
+                    t,
+                    "↓".to_string(),
+                    Message::ForgeMoveScene { scene_id: s.id.clone(), up: false },
+                ))
+                .push(ui::ghost_button(t, "✕".to_string(), Message::DeleteForgeEntry(scene_entry)))
+                .into()
+        };
+
+        col = col.push(row);
+    }
+
+    let footer: E<'a> = if pending == Some(&chapter_entry) {
+        ui::confirm_delete(
+            t,
+            format!("Delete \"{}\" and everything in it? This cannot be undone.", c.title),
+            Message::DeleteForgeCancel,
+            Message::DeleteForgeConfirm,
+        )
+    } else {
+        Row::new()
+            .spacing(10)
+            .push(ui::ghost_button(t, "+ Add scene".to_string(), Message::ForgeAddScene(c.id.clone())))
+            .push(ui::danger_button(t, "Delete chapter".to_string(), Message::DeleteForgeEntry(chapter_entry)))
+            .into()
+    };
+
+    ui::card(t, col.push(footer).into())
+}
+
+fn scene_panel<'a>(state: &'a AppState, t: ui::Tokens, m: &'a Manuscript, ed: &'a SceneEditor) -> E<'a> {
+    let Some((chapter, scene)) = m
+        .chapters
+        .iter()
+        .find_map(|c| c.scenes.iter().find(|s| s.id == ed.scene_id).map(|s| (c, s)))
+    else {
+        return ui::card(t, text("This scene no longer exists.").size(12).color(t.muted_fg).into());
+    };
+
+    let title = state.title_text(&TitleField::Scene(scene.id.clone()), &scene.title);
+    let title_input = text_input("Scene title", title)
+        .on_input(Message::ForgeSceneTitleChanged)
+        .on_submit(Message::TitleSubmit)
+        .padding(10);
+
+    let title_row = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(container(title_input).width(Length::Fill))
+        .push(ui::primary_button_maybe(
+            t,
+            "Save".to_string(),
+            ed.unsaved.then_some(Message::ForgeSceneSave),
+        ));
+
+    // Counts cover the saved text; anything else clicked saves it too.
+    let unsaved = if ed.unsaved { " · unsaved changes" } else { "" };
+    let counts = text(format!(
+        "{} words in scene · {} in \"{}\" · {} in book{unsaved}",
+        scene.word_count(),
+        chapter.word_count(),
+        chapter.title,
+        m.word_count()
+    ))
+    .size(11)
+    .color(ui::muted_small());
+
+    let editor = text_editor(&ed.content)
+        .placeholder("Write the scene…")
+        .on_action(Message::ForgeSceneEdit)
+        .padding(12)
+        .height(Length::Fixed(520.0));
+
+    let col = Column::new()
+        .spacing(10)
+        .push(title_row)
+        .push(counts)
+        .push(editor);
+
+    ui::card(t, col.into())
+}
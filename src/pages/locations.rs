@@ -0,0 +1,222 @@
+use std::fmt;
+
+use iced::{Alignment, Length};
+use iced::widget::{container, pick_list, text, Column, Row, Space};
+
+use crate::app::{AppState, LocationEditor, Message};
+use crate::forms::Form;
+use crate::model::validation::Field;
+use crate::model::{Codex, Entry, Location, LocationKind, Project};
+use crate::pages::codex::{actions, editor, header};
+use crate::{pages::E, ui};
+
+/// Indent per nesting level of the location tree.
+const INDENT: f32 = 28.0;
+
+pub fn locations<'a>(state: &'a AppState, t: ui::Tokens, universe_id: &'a str) -> E<'a> {
+    let header = header(
+        state,
+        t,
+        Codex::Location,
+        universe_id,
+        "Regions, cities and landmarks, nested the way they sit on the map.",
+        None,
+    );
+
+    let (archived, active): (Vec<&Location>, Vec<&Location>) =
+        state.project.locations_in(universe_id).partition(|l| l.archived);
+
+    let mut body = Column::new()
+        .spacing(14)
+        .push(header)
+        .width(Length::Fill);
+
+    if let Some(editor) = state.location_editor.as_ref().filter(|ed| ed.universe_id == universe_id) {
+        body = body.push(editor_panel(state, t, editor));
+    }
+
+    let tree: E<'a> = if active.is_empty() {
+        ui::card(
+            t,
+            container(
+                text("No locations yet. Add a region, then the cities and landmarks inside it.")
+                    .size(12)
+                    .color(t.muted_fg),
+            )
+            .width(Length::Fill)
+            .padding([14, 14])
+            .into(),
+        )
+    } else {
+        let mut col = Column::new().spacing(10).width(Length::Fill);
+
+        // A place whose parent is archived is shown at the top level until it is restored.
+        let is_root = |l: &&Location| l.parent_id.as_deref().is_none_or(|p| !active.iter().any(|a| a.id == p));
+        for root in sorted(active.iter().copied().filter(is_root).collect()) {
+            col = push_tree(col, state, t, &active, root, 0);
+        }
+
+        col.into()
+    };
+
+    let archived_list: E<'a> = if archived.is_empty() {
+        ui::card(
+            t,
+            container(text("No archived locations.").size(12).color(t.muted_fg))
+                .width(Length::Fill)
+                .padding([14, 14])
+                .into(),
+        )
+    } else {
+        sorted(archived.clone())
+            .into_iter()
+            .fold(Column::new().spacing(10), |col, l| {
+                col.push(location_row(state, t, l, 0))
+            })
+            .into()
+    };
+
+    body = body
+        .push(text(format!("Places ({})", active.len())).size(12).color(t.muted_fg))
+        .push(tree)
+        .push(text(format!("Archived locations ({})", archived.len())).size(12).color(t.muted_fg))
+        .push(archived_list);
+
+    ui::page_padding(body.into())
+}
+
+fn sorted(mut locations: Vec<&Location>) -> Vec<&Location> {
+    locations.sort_by_key(|l| l.name.to_lowercase());
+    locations
+}
+
+/// `location` followed by everything inside it, each level indented further.
+fn push_tree<'a>(
+    mut col: Column<'a, Message>,
+    state: &'a AppState,
+    t: ui::Tokens,
+    active: &[&'a Location],
+    location: &'a Location,
+    depth: usize,
+) -> Column<'a, Message> {
+    col = col.push(location_row(state, t, location, depth));
+
+    let children = active
+        .iter()
+        .copied()
+        .filter(|l| l.parent_id.as_deref() == Some(location.id.as_str()))
+        .collect();
+    for child in sorted(children) {
+        col = push_tree(col, state, t, active, child, depth + 1);
+    }
+
+    col
+}
+
+fn location_row<'a>(state: &'a AppState, t: ui::Tokens, l: &'a Location, depth: usize) -> E<'a> {
+    let mut left = Column::new()
+        .spacing(4)
+        .push(text(&l.name).size(16).color(t.foreground))
+        .push(text(l.summary(&state.project)).size(12).color(t.muted_fg))
+        .push(ui::markdown_text(state, t, ui::first_paragraph(&l.description), 12));
+
+    let creatures: Vec<_> = state.project.creatures_at(&l.id).filter(|c| !c.archived).collect();
+    if !creatures.is_empty() {
+        let row = creatures.into_iter().fold(
+            Row::new()
+                .spacing(6)
+                .align_y(Alignment::Center)
+                .push(text("Home to").size(12).color(t.muted_fg)),
+            |row, c| {
+                row.push(ui::ghost_button(
+                    t,
+                    c.name.clone(),
+                    Message::OpenEntry(Codex::Creature, c.id.clone()),
+                ))
+            },
+        );
+        left = left.push(row);
+    }
+
+    let confirming = state.pending_delete(Codex::Location) == Some(l.id.as_str());
+    let inside = (!l.archived && !confirming).then(|| {
+        ui::ghost_button(t, "+ Inside".to_string(), Message::LocationEditorAddInside(l.id.clone()))
+    });
+
+    let row = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(container(left).width(Length::Fill))
+        .push(inside)
+        .push(actions(state, t, l));
+
+    Row::new()
+        .push(container(Space::new()).width(Length::Fixed(INDENT * depth as f32)))
+        .push(container(ui::card(t, row.into())).width(Length::Fill))
+        .into()
+}
+
+/// Entry of a location picker; `id == None` stands for "none".
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocationChoice {
+    pub id: Option<String>,
+    pub label: String,
+}
+
+impl fmt::Display for LocationChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+/// Active locations of `universe_id` accepted by `keep`, labelled with their full path.
+pub fn location_choices(project: &Project, universe_id: &str, keep: impl Fn(&Location) -> bool) -> Vec<LocationChoice> {
+    let mut choices: Vec<LocationChoice> = project
+        .locations_in(universe_id)
+        .filter(|l| !l.archived && keep(l))
+        .map(|l| LocationChoice {
+            id: Some(l.id.clone()),
+            label: project.location_label(&l.id),
+        })
+        .collect();
+
+    choices.sort_by_key(|c| c.label.to_lowercase());
+    choices
+}
+
+fn editor_panel<'a>(state: &'a AppState, t: ui::Tokens, ed: &'a LocationEditor) -> E<'a> {
+    let project = &state.project;
+    let errors = ed.errors(project);
+
+    // A place can't move inside itself or anything it contains.
+    let parents: Vec<LocationChoice> = std::iter::once(LocationChoice {
+        id: None,
+        label: "Top level".to_string(),
+    })
+    .chain(location_choices(project, &ed.universe_id, |l| {
+        ed.id.as_deref().is_none_or(|id| !project.location_within(&l.id, id))
+    }))
+    .collect();
+    let selected_parent = parents.iter().find(|c| c.id == ed.parent_id).cloned();
+
+    let placement = Column::new()
+        .spacing(10)
+        .push(
+            Row::new()
+                .spacing(10)
+                .align_y(Alignment::Center)
+                .push(text("Kind").size(12).color(t.muted_fg))
+                .push(pick_list(LocationKind::ALL, Some(ed.kind), Message::LocationEditorKindChanged).padding(8))
+                .push(text("Inside").size(12).color(t.muted_fg))
+                .push(
+                    pick_list(parents, selected_parent, |c: LocationChoice| {
+                        Message::LocationEditorParentChanged(c.id)
+                    })
+                    .placeholder("Top level")
+                    .padding(8),
+                ),
+        )
+        .push(errors.get(Field::Parent).map(ui::error_text));
+
+    editor(state, t, ed, vec![(Field::Name, placement.into())])
+}
@@ -1,7 +1,17 @@
 pub mod overview;
 pub mod universe_list;
 pub mod universe_detail;
+pub mod codex;
 pub mod bestiary;
+pub mod locations;
+pub mod characters;
+pub mod runomicon;
+pub mod artifacts;
+pub mod timeline;
+pub mod forge;
+pub mod pm;
+pub mod assets;
+pub mod workspaces;
 pub mod stubs;
 
 use iced::Element;
@@ -12,8 +22,12 @@ pub type E<'a> = Element<'a, Message>;
 pub use overview::overview;
 pub use universe_list::universe_list;
 pub use universe_detail::universe_detail;
-pub use bestiary::bestiary;
+pub use codex::codex;
+pub use bestiary::creature_detail;
+pub use timeline::timeline;
+pub use forge::{forge, manuscript};
+pub use pm::{board, pm_tools};
+pub use assets::assets;
+pub use workspaces::workspaces;
 
-pub use stubs::{
-    workspaces_stub, timeline_stub, forge_stub, pm_stub, assets_stub, account_stub,
-};
+pub use stubs::account_stub;
@@ -1,10 +1,12 @@
-use iced::{border, Alignment, Border, Color, Length};
-use iced::widget::{container, text, Column, Row, Space};
+use iced::{border, Alignment, Background, Border, Color, Length};
+use iced::widget::{button, container, text, Column, Row, Space};
 
 use crate::app::{AppState, Message, Route};
+use crate::model::activity::format_timestamp;
+use crate::model::{Activity, ActivityKind, ActivityTarget, Project};
 use crate::{pages::E, ui};
 
-pub fn overview<'a>(_state: &'a AppState, t: ui::Tokens) -> E<'a> {
+pub fn overview<'a>(state: &'a AppState, t: ui::Tokens) -> E<'a> {
     // Header row: page title on the left, one primary action on the right.
     let head = Row::new()
         .align_y(Alignment::Center)
@@ -28,14 +30,14 @@ pub fn overview<'a>(_state: &'a AppState, t: ui::Tokens) -> E<'a> {
     // Grids layout
     let grid_top = Row::new()
         .spacing(14)
-        .push(recents_card_universe(t))
-        .push(recents_card_forge(t))
+        .push(recents_card_universe(state, t))
+        .push(recents_card_forge(state, t))
         .width(Length::Fill);
 
     let grid_bottom = Row::new()
         .spacing(14)
-        .push(recents_card_pm(t))
-        .push(recents_card_assets(t))
+        .push(recents_card_pm(state, t))
+        .push(recents_card_assets(state, t))
         .width(Length::Fill);
 
     let body = Column::new()
@@ -50,88 +52,117 @@ pub fn overview<'a>(_state: &'a AppState, t: ui::Tokens) -> E<'a> {
     ui::page_padding(body.into())
 }
 
-fn recents_card_universe(t: ui::Tokens) -> iced::Element<'static, Message> {
+/// Rows shown per recents card.
+const RECENTS_LIMIT: usize = 5;
+
+fn recents_card_universe<'a>(state: &'a AppState, t: ui::Tokens) -> E<'a> {
     recents_card(
+        state,
         t,
         "Universe — Recents",
         "Latest worldbuilding artifacts.",
-        &[
-            ("Arhelis — Core Lore", "Updated 2025-09-20", "World"),
-            ("Runomicon — Glyphs v2", "Updated 2025-09-18", "Magic"),
-            ("Bestiary — Shadows", "Updated 2025-09-15", "Creature"),
-            ("Factions — Marekhan", "Updated 2025-09-12", "Faction"),
-            ("Locations — Unharier", "Updated 2025-09-10", "Location"),
-        ],
+        |target| matches!(target, ActivityTarget::Universe(_)) || target.entry().is_some(),
+        Route::UniverseList,
     )
 }
 
-fn recents_card_forge(t: ui::Tokens) -> iced::Element<'static, Message> {
+fn recents_card_forge<'a>(state: &'a AppState, t: ui::Tokens) -> E<'a> {
     recents_card(
+        state,
         t,
         "The Forge — Recents",
         "Novels and outlines.",
-        &[
-            ("Novel: Eventum Arhalen", "Updated 2025-09-20", "Novel"),
-            ("Novella: The Unseen Tide", "Updated 2025-09-16", "Novella"),
-            ("Outline: Book II", "Updated 2025-09-14", "Outline"),
-            ("Scene: The Oath", "Updated 2025-09-11", "Scene"),
-        ],
+        |target| matches!(target, ActivityTarget::Manuscript(_) | ActivityTarget::Scene { .. }),
+        Route::Forge,
     )
 }
 
-fn recents_card_pm(t: ui::Tokens) -> iced::Element<'static, Message> {
+fn recents_card_pm<'a>(state: &'a AppState, t: ui::Tokens) -> E<'a> {
     recents_card(
+        state,
         t,
         "PM Tools — Recents",
         "Boards, columns and cards.",
-        &[
-            ("TAS — PM Roadmap", "Updated 2025-09-21", "Roadmap"),
-            ("Universe Cleanup Sprint", "Updated 2025-09-17", "Sprint"),
-            ("Assets Backlog", "Updated 2025-09-13", "Backlog"),
-            ("Forge Editing Tasks", "Updated 2025-09-11", "Tasks"),
-        ],
+        |target| matches!(target, ActivityTarget::Board(_)),
+        Route::PmTools,
     )
 }
 
-fn recents_card_assets(t: ui::Tokens) -> iced::Element<'static, Message> {
+fn recents_card_assets<'a>(state: &'a AppState, t: ui::Tokens) -> E<'a> {
     recents_card(
+        state,
         t,
         "Assets — Recents",
         "Uploaded files and references.",
-        &[
-            ("Map — Central Plateau.png", "Updated 2025-09-22", "Image"),
-            ("Glyph Sheet v3.svg", "Updated 2025-09-20", "Vector"),
-            ("Theme Moodboard.pdf", "Updated 2025-09-15", "Doc"),
-            ("Faction Seals.zip", "Updated 2025-09-09", "Archive"),
-        ],
+        |target| matches!(target, ActivityTarget::Asset(_)),
+        Route::Assets,
     )
 }
 
+/// Current name and tag of the item behind an activity entry.
+fn describe(p: &Project, target: &ActivityTarget) -> Option<(String, String)> {
+    match target {
+        ActivityTarget::Universe(id) => p.universe(id).map(|u| (u.name.clone(), "World".to_string())),
+        ActivityTarget::Manuscript(id) => p
+            .manuscript(id)
+            .map(|m| (format!("{}: {}", m.kind, m.title), m.kind.to_string())),
+        ActivityTarget::Scene { manuscript_id, scene_id } => p
+            .manuscript(manuscript_id)
+            .and_then(|m| m.scene(scene_id))
+            .map(|s| (format!("Scene: {}", s.title), "Scene".to_string())),
+        ActivityTarget::Board(id) => p.board(id).map(|b| (b.name.clone(), "Board".to_string())),
+        ActivityTarget::Asset(id) => p.asset(id).map(|a| (a.name.clone(), a.kind.to_string())),
+        entry => entry
+            .entry()
+            .and_then(|(codex, id)| p.find_entry(codex, id))
+            .map(|e| (e.name().to_string(), e.kind())),
+    }
+}
+
 fn recents_card<'a>(
+    state: &'a AppState,
     t: ui::Tokens,
     title: &'a str,
     subtitle: &'a str,
-    items: &'a [(&'a str, &'a str, &'a str)],
+    module: impl Fn(&ActivityTarget) -> bool,
+    view_all: Route,
 ) -> E<'a> {
     let head = Row::new()
         .align_y(Alignment::Center)
         .push(text(title).size(14).color(t.foreground))
         .push(container(Space::new()).width(Length::Fill))
-        .push(ui::ghost_button(
-            t,
-            "View all →".to_string(),
-            Message::Navigate(Route::Overview),
-        ));
+        .push(ui::ghost_button(t, "View all →".to_string(), Message::Navigate(view_all)));
+
+    let items: Vec<(&Activity, String, String)> = state
+        .project
+        .workspace_activity()
+        .filter(|a| module(&a.target))
+        .filter_map(|a| describe(&state.project, &a.target).map(|(name, tag)| (a, name, tag)))
+        .take(RECENTS_LIMIT)
+        .collect();
 
-    let mut list = Column::new().spacing(10);
+    let mut list = Column::new().spacing(4);
+
+    if items.is_empty() {
+        list = list.push(text("Nothing here yet.").size(12).color(t.muted_fg));
+    }
+
+    for (activity, name, tag) in items {
+        let verb = match activity.kind {
+            ActivityKind::Created => "Created",
+            ActivityKind::Edited => "Updated",
+        };
 
-    for (name, updated, tag) in items {
         let left = Column::new()
             .spacing(2)
-            .push(text(*name).size(12).color(t.foreground))
-            .push(text(*updated).size(10).color(Color::from_rgba8(0xA1, 0xA1, 0xA1, 0.70)));
-
-        let tag_pill = container(text(*tag).size(10).color(t.foreground))
+            .push(text(name).size(12).color(t.foreground))
+            .push(
+                text(format!("{verb} {}", format_timestamp(activity.at)))
+                    .size(10)
+                    .color(ui::muted_small()),
+            );
+
+        let tag_pill = container(text(tag).size(10).color(t.foreground))
             .padding([4, 8])
             .style(move |_| {
                 let mut s = ui::container_style(t.active_bg, t.foreground);
@@ -148,13 +179,29 @@ fn recents_card<'a>(
             .push(container(left).width(Length::Fill))
             .push(tag_pill);
 
+        let row_item = button(row_item)
+            .padding([4, 6])
+            .width(Length::Fill)
+            .style(move |_, status| {
+                let mut s = button::Style::default();
+                let bg = match status {
+                    button::Status::Hovered => t.hover_bg,
+                    button::Status::Pressed => t.active_bg,
+                    _ => Color::TRANSPARENT,
+                };
+                s.background = Some(Background::Color(bg));
+                s.border.radius = border::Radius::from(8.0);
+                s
+            })
+            .on_press(Message::OpenActivity(activity.target.clone()));
+
         list = list.push(row_item);
     }
 
     let body = Column::new()
         .spacing(8)
         .push(head)
-        .push(text(subtitle).size(11).color(Color::from_rgba8(0xA1, 0xA1, 0xA1, 0.70)))
+        .push(text(subtitle).size(11).color(ui::muted_small()))
         .push(list);
 
     ui::card(t, body.into())
@@ -0,0 +1,399 @@
+use std::fmt;
+
+use iced::{border, Alignment, Border, Color, Length};
+use iced::widget::{container, pick_list, scrollable, text, text_input, Column, Row};
+
+use crate::app::{AppState, BoardEntry, CardEditor, Message, Route, TitleField};
+use crate::model::{Board, BoardColumn, Card, CardMove, Universe};
+use crate::{pages::E, ui};
+
+pub fn pm_tools<'a>(state: &'a AppState, t: ui::Tokens) -> E<'a> {
+    let title = Column::new()
+        .spacing(4)
+        .push(text("PM Tools").size(26).color(t.foreground))
+        .push(text("Boards, columns and cards.").size(12).color(t.muted_fg));
+
+    let name_input = text_input("Board name", &state.new_board_name)
+        .on_input(Message::PmNewBoardNameChanged)
+        .padding(10);
+
+    let form = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(container(name_input).width(Length::Fixed(260.0)))
+        .push(ui::primary_button(t, "Create board".to_string(), Message::CreateBoard));
+
+    let header = Row::new()
+        .align_y(Alignment::Center)
+        .push(container(title).width(Length::Fill))
+        .push(form);
+
+    let boards: Vec<&Board> = state.project.workspace_boards().collect();
+
+    let list: E<'a> = if boards.is_empty() {
+        ui::card(
+            t,
+            container(text("No boards yet.").size(12).color(t.muted_fg))
+                .width(Length::Fill)
+                .padding([14, 14])
+                .into(),
+        )
+    } else {
+        let mut col = Column::new().spacing(10);
+        for b in boards {
+            col = col.push(board_card(t, state, b));
+        }
+        col.into()
+    };
+
+    let body = Column::new()
+        .spacing(14)
+        .push(header)
+        .push(text("Boards").size(12).color(t.muted_fg))
+        .push(list)
+        .width(Length::Fill);
+
+    ui::page_padding(body.into())
+}
+
+fn board_card<'a>(t: ui::Tokens, state: &'a AppState, b: &'a Board) -> E<'a> {
+    let entry = BoardEntry::Board(b.id.clone());
+
+    let linked = b
+        .universe_id
+        .as_deref()
+        .and_then(|id| state.project.universe(id))
+        .map(|u| format!(" · Linked to {}", u.name))
+        .unwrap_or_default();
+
+    let left = Column::new()
+        .spacing(4)
+        .push(text(&b.name).size(16).color(t.foreground))
+        .push(text(format!("{}{}", stats(b), linked)).size(12).color(t.muted_fg));
+
+    let actions = if state.pending_board_delete.as_ref() == Some(&entry) {
+        ui::confirm_delete(
+            t,
+            format!("Delete \"{}\"? This cannot be undone.", b.name),
+            Message::DeleteBoardCancel,
+            Message::DeleteBoardConfirm,
+        )
+    } else {
+        Row::new()
+            .spacing(10)
+            .push(ui::outline_button(t, "Open".to_string(), Message::OpenBoard(b.id.clone())))
+            .push(ui::danger_button(t, "Delete".to_string(), Message::DeleteBoardEntry(entry)))
+            .into()
+    };
+
+    ui::card(t, Column::new().spacing(10).push(left).push(actions).into())
+}
+
+fn stats(b: &Board) -> String {
+    format!("{} column(s) · {} card(s)", b.columns.len(), b.card_count())
+}
+
+/// Entry of the "Linked universe" picker; `id == None` unlinks the board.
+#[derive(Debug, Clone, PartialEq)]
+struct UniverseChoice {
+    id: Option<String>,
+    name: String,
+}
+
+impl fmt::Display for UniverseChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+fn universe_picker<'a>(b: &Board, universes: Vec<&Universe>) -> E<'a> {
+    let choices: Vec<UniverseChoice> = std::iter::once(UniverseChoice {
+        id: None,
+        name: "No linked universe".to_string(),
+    })
+    .chain(universes.into_iter().filter(|u| !u.archived).map(|u| UniverseChoice {
+        id: Some(u.id.clone()),
+        name: u.name.clone(),
+    }))
+    .collect();
+
+    let selected = choices.iter().find(|c| c.id == b.universe_id).cloned();
+
+    pick_list(choices, selected, |c: UniverseChoice| Message::BoardUniverseChanged(c.id))
+        .padding(8)
+        .into()
+}
+
+pub fn board<'a>(state: &'a AppState, t: ui::Tokens, board_id: &'a str) -> E<'a> {
+    let Some(b) = state.project.board(board_id) else {
+        let missing = Column::new()
+            .spacing(10)
+            .push(text("This board no longer exists.").size(12).color(t.muted_fg))
+            .push(ui::outline_button(t, "Back to PM Tools".to_string(), Message::Navigate(Route::PmTools)));
+
+        return ui::page_padding(ui::card(t, missing.into()));
+    };
+
+    let pending = state.pending_board_delete.as_ref();
+    let entry = BoardEntry::Board(b.id.clone());
+
+    let name_input = text_input("Board name", state.title_text(&TitleField::Board, &b.name))
+        .on_input(Message::BoardNameChanged)
+        .on_submit(Message::TitleSubmit)
+        .size(20)
+        .padding(8);
+
+    let header_left = Column::new()
+        .spacing(4)
+        .push(container(name_input).width(Length::Fixed(420.0)))
+        .push(text(stats(b)).size(12).color(t.muted_fg));
+
+    let header_right: E<'a> = if pending == Some(&entry) {
+        ui::confirm_delete(
+            t,
+            format!("Delete \"{}\"? This cannot be undone.", b.name),
+            Message::DeleteBoardCancel,
+            Message::DeleteBoardConfirm,
+        )
+    } else {
+        Row::new()
+            .spacing(10)
+            .align_y(Alignment::Center)
+            .push(ui::outline_button(
+                t,
+                "Back to PM Tools".to_string(),
+                Message::Navigate(Route::PmTools),
+            ))
+            .push(universe_picker(b, state.project.workspace_universes().collect()))
+            .push(ui::danger_button(t, "Delete board".to_string(), Message::DeleteBoardEntry(entry)))
+            .push(ui::primary_button(t, "Add column".to_string(), Message::BoardAddColumn))
+            .into()
+    };
+
+    let header = Row::new()
+        .align_y(Alignment::Center)
+        .push(container(header_left).width(Length::Fill))
+        .push(header_right);
+
+    let mut body = Column::new()
+        .spacing(14)
+        .push(header)
+        .width(Length::Fill);
+
+    if let Some(editor) = &state.card_editor {
+        body = body.push(card_editor_panel(t, editor));
+    }
+
+    let mut lane = Row::new().spacing(14);
+    for col in &b.columns {
+        lane = lane.push(column_panel(state, t, col, pending));
+    }
+
+    body = body.push(scrollable(lane).horizontal());
+
+    ui::page_padding(body.into())
+}
+
+fn column_panel<'a>(
+    state: &'a AppState,
+    t: ui::Tokens,
+    col: &'a BoardColumn,
+    pending: Option<&BoardEntry>,
+) -> E<'a> {
+    let entry = BoardEntry::Column(col.id.clone());
+
+    let title = state.title_text(&TitleField::Column(col.id.clone()), &col.title);
+    let title_input = text_input("Column title", title)
+        .on_input({
+            let id = col.id.clone();
+            move |v| Message::BoardColumnTitleChanged(id.clone(), v)
+        })
+        .on_submit(Message::TitleSubmit)
+        .padding(8);
+
+    let head = Row::new()
+        .spacing(6)
+        .align_y(Alignment::Center)
+        .push(container(title_input).width(Length::Fill))
+        .push(ui::ghost_button(
+            t,
+            "←".to_string(),
+            Message::BoardMoveColumn { column_id: col.id.clone(), left: true },
+        ))
+        .push(ui::ghost_button(
+            t,
+            "→".to_string(),
+            Message::BoardMoveColumn { column_id: col.id.clone(), left: false },
+        ));
+
+    let mut cards = Column::new()
+        .spacing(8)
+        .push(head)
+        .push(text(format!("{} card(s)", col.cards.len())).size(10).color(ui::muted_small()));
+
+    for c in &col.cards {
+        cards = cards.push(card_tile(t, c, pending));
+    }
+
+    let footer: E<'a> = if pending == Some(&entry) {
+        ui::confirm_delete(
+            t,
+            format!("Delete \"{}\"? This cannot be undone.", col.title),
+            Message::DeleteBoardCancel,
+            Message::DeleteBoardConfirm,
+        )
+    } else {
+        Row::new()
+            .spacing(10)
+            .push(ui::ghost_button(t, "+ Add card".to_string(), Message::CardEditorOpenCreate(col.id.clone())))
+            .push(ui::danger_button(t, "Delete column".to_string(), Message::DeleteBoardEntry(entry)))
+            .into()
+    };
+
+    container(ui::card(t, cards.push(footer).into()))
+        .width(Length::Fixed(300.0))
+        .into()
+}
+
+fn card_tile<'a>(t: ui::Tokens, c: &'a Card, pending: Option<&BoardEntry>) -> E<'a> {
+    let entry = BoardEntry::Card(c.id.clone());
+
+    let mut col = Column::new()
+        .spacing(4)
+        .push(text(&c.title).size(14).color(t.foreground));
+
+    if !c.description.is_empty() {
+        col = col.push(text(&c.description).size(12).color(t.muted_fg));
+    }
+
+    let mut meta = Vec::new();
+    if !c.assignee.is_empty() {
+        meta.push(format!("@{}", c.assignee));
+    }
+    if let Some(due) = &c.due {
+        meta.push(format!("Due {due}"));
+    }
+    if !meta.is_empty() {
+        col = col.push(text(meta.join(" · ")).size(11).color(t.muted_fg));
+    }
+
+    if !c.labels.is_empty() {
+        let mut labels = Row::new().spacing(6);
+        for l in &c.labels {
+            labels = labels.push(label_pill(t, l));
+        }
+        col = col.push(labels);
+    }
+
+    let actions: E<'a> = if pending == Some(&entry) {
+        ui::confirm_delete(
+            t,
+            format!("Delete \"{}\"? This cannot be undone.", c.title),
+            Message::DeleteBoardCancel,
+            Message::DeleteBoardConfirm,
+        )
+    } else {
+        let mv = |label: &str, direction| {
+            ui::ghost_button(t, label.to_string(), Message::MoveCard { card_id: c.id.clone(), direction })
+        };
+
+        Row::new()
+            .spacing(2)
+            .align_y(Alignment::Center)
+            .push(mv("←", CardMove::Left))
+            .push(mv("↑", CardMove::Up))
+            .push(mv("↓", CardMove::Down))
+            .push(mv("→", CardMove::Right))
+            .push(ui::ghost_button(t, "Edit".to_string(), Message::CardEdit(c.id.clone())))
+            .push(ui::ghost_button(t, "✕".to_string(), Message::DeleteBoardEntry(entry)))
+            .into()
+    };
+
+    let tile = container(col.push(actions))
+        .padding(10)
+        .width(Length::Fill)
+        .style(move |_| {
+            let mut s = ui::container_style(t.hover_bg, t.foreground);
+            s.border = Border {
+                color: t.border,
+                width: 1.0,
+                radius: border::Radius::from(12.0),
+            };
+            s
+        });
+
+    tile.into()
+}
+
+fn label_pill<'a>(t: ui::Tokens, label: &'a str) -> E<'a> {
+    container(text(label).size(10).color(t.foreground))
+        .padding([3, 8])
+        .style(move |_| {
+            let mut s = ui::container_style(t.active_bg, t.foreground);
+            s.border = Border {
+                color: Color::from_rgba8(0xFF, 0xFF, 0xFF, 0.10),
+                width: 1.0,
+                radius: border::Radius::from(999.0),
+            };
+            s
+        })
+        .into()
+}
+
+fn card_editor_panel<'a>(t: ui::Tokens, ed: &'a CardEditor) -> E<'a> {
+    let title = if ed.id.is_some() { "Edit card" } else { "Add card" };
+
+    let actions = Row::new()
+        .spacing(10)
+        .push(ui::outline_button(t, "Cancel".to_string(), Message::CardEditorCancel))
+        .push(ui::primary_button(t, "Save".to_string(), Message::CardEditorSave));
+
+    let header = Row::new()
+        .align_y(Alignment::Center)
+        .push(container(text(title).size(16).color(t.foreground)).width(Length::Fill))
+        .push(actions);
+
+    let details = Row::new()
+        .spacing(10)
+        .push(
+            container(
+                text_input("Assignee", &ed.assignee)
+                    .on_input(Message::CardEditorAssigneeChanged)
+                    .padding(10),
+            )
+            .width(Length::Fixed(220.0)),
+        )
+        .push(
+            container(
+                text_input("Due date (YYYY-MM-DD)", &ed.due)
+                    .on_input(Message::CardEditorDueChanged)
+                    .padding(10),
+            )
+            .width(Length::Fixed(220.0)),
+        )
+        .push(
+            container(
+                text_input("Labels, comma-separated", &ed.labels)
+                    .on_input(Message::CardEditorLabelsChanged)
+                    .padding(10),
+            )
+            .width(Length::Fill),
+        );
+
+    let form = Column::new()
+        .spacing(10)
+        .push(header)
+        .push(
+            text_input("Title", &ed.title)
+                .on_input(Message::CardEditorTitleChanged)
+                .padding(10),
+        )
+        .push(
+            text_input("Description", &ed.description)
+                .on_input(Message::CardEditorDescriptionChanged)
+                .padding(10),
+        )
+        .push(details);
+
+    ui::card(t, form.into())
+}
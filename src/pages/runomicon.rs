@@ -0,0 +1,263 @@
+use std::fmt;
+
+use iced::{Alignment, Length};
+use iced::widget::{container, pick_list, text, Column, Row};
+
+use crate::app::{AppState, Message, TermEditor};
+use crate::forms::Form;
+use crate::model::glossary::SUGGESTED_CATEGORIES;
+use crate::model::validation::Field;
+use crate::model::{AssetKind, Codex, Term};
+use crate::pages::assets::preview;
+use crate::pages::codex::{editor, entry_card, facet_choices, grid, header, tally, FacetChoice};
+use crate::{pages::E, ui};
+
+/// Height of the glyph drawn on a card.
+const GLYPH_HEIGHT: f32 = 96.0;
+
+pub fn runomicon<'a>(state: &'a AppState, t: ui::Tokens, universe_id: &'a str) -> E<'a> {
+    let header = header(
+        state,
+        t,
+        Codex::Term,
+        universe_id,
+        "Magic systems, spells, glyphs and the invented words of this universe.",
+        None,
+    );
+
+    let mut terms: Vec<&Term> = state.project.terms_in(universe_id).collect();
+    terms.sort_by_key(|term| term.name.to_lowercase());
+    let (archived, active): (Vec<&Term>, Vec<&Term>) = terms.into_iter().partition(|term| term.archived);
+
+    let category = state.runomicon_categories.get(universe_id);
+    let shown: Vec<&Term> = active
+        .iter()
+        .copied()
+        .filter(|term| category.is_none_or(|c| term.category.eq_ignore_ascii_case(c)))
+        .collect();
+
+    let mut body = Column::new()
+        .spacing(14)
+        .push(header)
+        .width(Length::Fill);
+
+    if let Some(editor) = state.term_editor.as_ref().filter(|ed| ed.universe_id == universe_id) {
+        body = body.push(editor_panel(state, t, editor));
+    }
+
+    let mut categories = tally(
+        active.iter().filter(|term| !term.category.is_empty()).map(|term| term.category.clone()),
+        |a, b| a.eq_ignore_ascii_case(b),
+    );
+    categories.sort_by_key(|(c, _)| c.to_lowercase());
+    let choices = facet_choices("Every category", categories);
+    let selected = choices
+        .iter()
+        .find(|c| match (&c.value, category) {
+            (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+            (None, None) => true,
+            _ => false,
+        })
+        .cloned();
+
+    let cards = |list: &[&'a Term]| -> Vec<E<'a>> {
+        list.iter()
+            .map(|term| term_card(state, t, term))
+            .collect()
+    };
+
+    let count = if shown.len() == active.len() {
+        format!("Entries ({})", active.len())
+    } else {
+        format!("Entries ({} of {})", shown.len(), active.len())
+    };
+
+    body = body
+        .push(
+            Row::new()
+                .spacing(10)
+                .align_y(Alignment::Center)
+                .push(container(text(count).size(12).color(t.muted_fg)).width(Length::Fill))
+                .push(
+                    pick_list(choices, selected, |c: FacetChoice<String>| {
+                        Message::RunomiconCategoryChanged(c.value)
+                    })
+                    .padding(8),
+                ),
+        )
+        .push(grid(t, cards(&shown), "No entries here yet. Add a spell, a glyph or a word."))
+        .push(text(format!("Archived entries ({})", archived.len())).size(12).color(t.muted_fg))
+        .push(grid(t, cards(&archived), "No archived entries."));
+
+    ui::page_padding(body.into())
+}
+
+fn term_card<'a>(state: &'a AppState, t: ui::Tokens, term: &'a Term) -> E<'a> {
+    let project = &state.project;
+
+    let glyph = term
+        .glyph_asset_id
+        .as_deref()
+        .and_then(|id| project.asset(id))
+        .map(|a| preview(state, t, a, GLYPH_HEIGHT));
+
+    let mut body = Column::new().push(glyph);
+
+    // Links both ways: what this entry points to and what points back at it.
+    let see_also: Vec<&Term> = term.see_also.iter().filter_map(|id| project.term(id)).collect();
+    let linked_from: Vec<&Term> = project
+        .terms_linking(&term.id)
+        .filter(|other| !term.see_also.contains(&other.id))
+        .collect();
+    for (label, links) in [("See also", see_also), ("Linked from", linked_from)] {
+        if links.is_empty() {
+            continue;
+        }
+
+        let row = links.into_iter().fold(
+            Row::new()
+                .spacing(6)
+                .align_y(Alignment::Center)
+                .push(text(label).size(12).color(t.muted_fg)),
+            |row, other| {
+                row.push(ui::ghost_button(
+                    t,
+                    other.name.clone(),
+                    Message::OpenEntry(Codex::Term, other.id.clone()),
+                ))
+            },
+        );
+        body = body.push(row.wrap());
+    }
+
+    entry_card(state, t, term, body)
+}
+
+/// Entry of the glyph and "see also" pickers; `id == None` stands for "none".
+#[derive(Debug, Clone, PartialEq)]
+struct Choice {
+    id: Option<String>,
+    label: String,
+}
+
+impl fmt::Display for Choice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+fn editor_panel<'a>(state: &'a AppState, t: ui::Tokens, ed: &'a TermEditor) -> E<'a> {
+    let project = &state.project;
+    let errors = ed.errors(project);
+
+    // Categories already used in this universe first, then the stock ones.
+    let mut known: Vec<String> = Vec::new();
+    let used = project.terms_in(&ed.universe_id).map(|term| term.category.clone());
+    for c in used.chain(SUGGESTED_CATEGORIES.iter().map(|c| c.to_string())) {
+        if !c.is_empty() && !known.iter().any(|k| k.eq_ignore_ascii_case(&c)) {
+            known.push(c);
+        }
+    }
+    let suggestions = known
+        .into_iter()
+        .filter(|c| !c.eq_ignore_ascii_case(ed.category.trim()))
+        .fold(Row::new().spacing(6), |row, c| {
+            row.push(ui::ghost_button(
+                t,
+                c.clone(),
+                Message::EditorLineChanged(Codex::Term, Field::Category, c),
+            ))
+        });
+
+    // Glyph: any image or SVG of the asset library.
+    let mut glyphs: Vec<Choice> = project
+        .workspace_assets()
+        .filter(|a| matches!(a.kind, AssetKind::Image | AssetKind::Vector))
+        .map(|a| Choice {
+            id: Some(a.id.clone()),
+            label: a.name.clone(),
+        })
+        .collect();
+    glyphs.sort_by_key(|c| c.label.to_lowercase());
+    glyphs.insert(
+        0,
+        Choice {
+            id: None,
+            label: "No glyph".to_string(),
+        },
+    );
+    let selected_glyph = glyphs.iter().find(|c| c.id == ed.glyph_asset_id).cloned();
+
+    let glyph_row = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(text("Glyph").size(12).color(t.muted_fg))
+        .push(
+            pick_list(glyphs, selected_glyph, |c: Choice| Message::TermEditorGlyphChanged(c.id))
+                .placeholder("No glyph")
+                .padding(8),
+        )
+        .push(
+            text("Import images or SVGs on the Assets page.")
+                .size(10)
+                .color(t.muted_fg),
+        );
+    let glyph_preview = ed
+        .glyph_asset_id
+        .as_deref()
+        .and_then(|id| project.asset(id))
+        .map(|a| container(preview(state, t, a, GLYPH_HEIGHT)).width(Length::Fixed(GLYPH_HEIGHT * 2.0)));
+
+    // See also: chips for current links plus a picker for the rest of the universe's entries.
+    let mut see_also = Row::new()
+        .spacing(6)
+        .align_y(Alignment::Center)
+        .push(text("See also").size(12).color(t.muted_fg));
+    for (i, id) in ed.see_also.iter().enumerate() {
+        if let Some(other) = project.term(id) {
+            see_also = see_also.push(ui::ghost_button(
+                t,
+                format!("{} ×", other.name),
+                Message::TermEditorRemoveSeeAlso(i),
+            ));
+        }
+    }
+
+    let mut linkable: Vec<Choice> = project
+        .terms_in(&ed.universe_id)
+        .filter(|term| {
+            !term.archived && ed.id.as_deref() != Some(term.id.as_str()) && !ed.see_also.contains(&term.id)
+        })
+        .map(|term| Choice {
+            id: Some(term.id.clone()),
+            label: term.name.clone(),
+        })
+        .collect();
+    linkable.sort_by_key(|c| c.label.to_lowercase());
+    if !linkable.is_empty() {
+        see_also = see_also.push(
+            pick_list(linkable, None::<Choice>, |c: Choice| {
+                Message::TermEditorAddSeeAlso(c.id.unwrap_or_default())
+            })
+            .placeholder("Link an entry…")
+            .padding(8),
+        );
+    }
+
+    let glyph = Column::new()
+        .spacing(10)
+        .push(glyph_row)
+        .push(glyph_preview)
+        .push(errors.get(Field::Glyph).map(ui::error_text));
+
+    editor(
+        state,
+        t,
+        ed,
+        vec![
+            (Field::Category, suggestions.wrap().into()),
+            (Field::Description, glyph.into()),
+            (Field::Description, see_also.wrap().into()),
+        ],
+    )
+}
@@ -0,0 +1,443 @@
+use std::fmt;
+
+use iced::{Alignment, Length};
+use iced::widget::{container, pick_list, text, text_input, Column, Row};
+
+use crate::app::{
+    AppState, CalendarEditor, EraEditor, EventEditor, Message, TimelineEditor, TimelineEntry,
+};
+use crate::model::{Calendar, Era, FantasyDate, TimelineEvent};
+use crate::{pages::E, ui};
+
+pub fn timeline<'a>(state: &'a AppState, t: ui::Tokens, universe_id: &'a str) -> E<'a> {
+    let universe_name = state
+        .project
+        .universe(universe_id)
+        .map(|u| u.name.as_str())
+        .unwrap_or(universe_id);
+
+    let calendar = state.project.calendar(universe_id);
+
+    let header_left = Column::new()
+        .spacing(4)
+        .push(text(format!("Timeline — {}", universe_name)).size(26).color(t.foreground))
+        .push(
+            text("Eras and events, dated in this universe's own calendar.")
+                .size(12)
+                .color(t.muted_fg),
+        );
+
+    let header_right = Row::new()
+        .spacing(10)
+        .push(ui::outline_button(
+            t,
+            "Back to universe".to_string(),
+            Message::BackToUniverse(universe_id.to_string()),
+        ))
+        .push(ui::outline_button(
+            t,
+            "All universes".to_string(),
+            Message::BackToUniverses,
+        ))
+        .push(ui::outline_button(
+            t,
+            "Add era".to_string(),
+            Message::TimelineEraEditorOpenCreate(universe_id.to_string()),
+        ))
+        .push(ui::primary_button(
+            t,
+            "Add event".to_string(),
+            Message::TimelineEventEditorOpenCreate(universe_id.to_string()),
+        ));
+
+    let header = Row::new()
+        .align_y(Alignment::Center)
+        .push(container(header_left).width(Length::Fill))
+        .push(header_right);
+
+    let mut body = Column::new()
+        .spacing(14)
+        .push(header)
+        .width(Length::Fill);
+
+    if let Some(editor) = &state.timeline_editor {
+        body = body.push(editor_panel(t, editor, &calendar));
+    }
+
+    body = body
+        .push(calendar_card(t, &calendar, universe_id))
+        .push(track(state, t, universe_id, &calendar));
+
+    ui::page_padding(body.into())
+}
+
+fn calendar_card<'a>(t: ui::Tokens, calendar: &Calendar, universe_id: &str) -> E<'a> {
+    let suffix = if calendar.year_suffix.is_empty() {
+        "no year suffix".to_string()
+    } else {
+        format!("years suffixed \"{}\"", calendar.year_suffix)
+    };
+
+    let left = Column::new()
+        .spacing(4)
+        .push(text("Calendar").size(14).color(t.foreground))
+        .push(
+            text(format!(
+                "{} months · {} days per month · {}",
+                calendar.months.len(),
+                calendar.days_per_month,
+                suffix
+            ))
+            .size(12)
+            .color(t.muted_fg),
+        )
+        .push(text(calendar.months.join(" · ")).size(11).color(t.muted_fg));
+
+    let row = Row::new()
+        .align_y(Alignment::Center)
+        .push(container(left).width(Length::Fill))
+        .push(ui::outline_button(
+            t,
+            "Edit calendar".to_string(),
+            Message::TimelineCalendarEdit(universe_id.to_string()),
+        ));
+
+    ui::card(t, row.into())
+}
+
+/// Vertical track: each era in chronological order with its events, then loose events.
+fn track<'a>(state: &'a AppState, t: ui::Tokens, universe_id: &str, calendar: &Calendar) -> E<'a> {
+    let eras = state.project.eras_in(universe_id);
+    let events = state.project.events_in(universe_id);
+    let pending = state.pending_timeline_delete.as_ref();
+
+    if eras.is_empty() && events.is_empty() {
+        return ui::card(
+            t,
+            container(
+                text("No eras or events yet. Add an era to frame your history, then pin events to dates.")
+                    .size(12)
+                    .color(t.muted_fg),
+            )
+            .width(Length::Fill)
+            .padding([14, 14])
+            .into(),
+        );
+    }
+
+    let mut col: Column<'a, Message> = Column::new().spacing(14).width(Length::Fill);
+
+    // Overlapping eras: an event is listed under the first (oldest) era containing it.
+    let era_of = |e: &TimelineEvent| eras.iter().position(|era| era.contains(e.date.year));
+
+    for (i, era) in eras.iter().enumerate() {
+        col = col.push(era_band(t, era, calendar, pending));
+
+        for e in events.iter().filter(|e| era_of(e) == Some(i)) {
+            col = col.push(event_row(t, e, calendar, pending));
+        }
+    }
+
+    let loose: Vec<&&TimelineEvent> = events.iter().filter(|e| era_of(e).is_none()).collect();
+
+    if !loose.is_empty() {
+        if !eras.is_empty() {
+            col = col.push(text("Outside any era").size(12).color(t.muted_fg));
+        }
+
+        for e in loose {
+            col = col.push(event_row(t, e, calendar, pending));
+        }
+    }
+
+    col.into()
+}
+
+fn era_band<'a>(
+    t: ui::Tokens,
+    era: &'a Era,
+    calendar: &Calendar,
+    pending: Option<&TimelineEntry>,
+) -> E<'a> {
+    let start = calendar.format(FantasyDate { year: era.start_year, ..Default::default() });
+    let end = match era.end_year {
+        Some(year) => calendar.format(FantasyDate { year, ..Default::default() }),
+        None => "present".to_string(),
+    };
+
+    let entry = TimelineEntry::Era(era.id.clone());
+
+    let left = Column::new()
+        .spacing(4)
+        .push(text(&era.name).size(18).color(t.foreground))
+        .push(text(format!("{start} – {end}")).size(12).color(t.muted_fg))
+        .push(text(&era.description).size(12).color(t.muted_fg));
+
+    let row = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(container(left).width(Length::Fill))
+        .push(entry_actions(
+            t,
+            &era.name,
+            Message::TimelineEraEdit(era.id.clone()),
+            entry.clone(),
+            pending == Some(&entry),
+        ));
+
+    ui::card(t, row.into())
+}
+
+fn event_row<'a>(
+    t: ui::Tokens,
+    e: &'a TimelineEvent,
+    calendar: &Calendar,
+    pending: Option<&TimelineEntry>,
+) -> E<'a> {
+    let entry = TimelineEntry::Event(e.id.clone());
+
+    let date = container(text(calendar.format(e.date)).size(12).color(t.muted_fg))
+        .width(Length::Fixed(160.0))
+        .padding([0, 8]);
+
+    let marker = text("●").size(12).color(ui::muted_small());
+
+    let content = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(
+            container(
+                Column::new()
+                    .spacing(4)
+                    .push(text(&e.title).size(14).color(t.foreground))
+                    .push(text(&e.description).size(12).color(t.muted_fg)),
+            )
+            .width(Length::Fill),
+        )
+        .push(entry_actions(
+            t,
+            &e.title,
+            Message::TimelineEventEdit(e.id.clone()),
+            entry.clone(),
+            pending == Some(&entry),
+        ));
+
+    Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(date)
+        .push(marker)
+        .push(ui::card(t, content.into()))
+        .into()
+}
+
+fn entry_actions<'a>(
+    t: ui::Tokens,
+    label: &str,
+    edit: Message,
+    entry: TimelineEntry,
+    confirming: bool,
+) -> E<'a> {
+    if confirming {
+        return ui::confirm_delete(
+            t,
+            format!("Delete \"{label}\"? This cannot be undone."),
+            Message::DeleteTimelineCancel,
+            Message::DeleteTimelineConfirm,
+        );
+    }
+
+    Row::new()
+        .spacing(10)
+        .push(ui::outline_button(t, "Edit".to_string(), edit))
+        .push(ui::danger_button(
+            t,
+            "Delete".to_string(),
+            Message::DeleteTimelineEntry(entry),
+        ))
+        .into()
+}
+
+fn editor_panel<'a>(t: ui::Tokens, editor: &'a TimelineEditor, calendar: &Calendar) -> E<'a> {
+    let (title, form) = match editor {
+        TimelineEditor::Event(ed) => (
+            if ed.id.is_some() { "Edit event" } else { "Add event" },
+            event_form(ed, calendar),
+        ),
+        TimelineEditor::Era(ed) => (
+            if ed.id.is_some() { "Edit era" } else { "Add era" },
+            era_form(ed),
+        ),
+        TimelineEditor::Calendar(ed) => ("Edit calendar", calendar_form(ed)),
+    };
+
+    let actions = Row::new()
+        .spacing(10)
+        .push(ui::outline_button(
+            t,
+            "Cancel".to_string(),
+            Message::TimelineEditorCancel,
+        ))
+        .push(ui::primary_button(
+            t,
+            "Save".to_string(),
+            Message::TimelineEditorSave,
+        ));
+
+    let header = Row::new()
+        .align_y(Alignment::Center)
+        .push(container(text(title).size(16).color(t.foreground)).width(Length::Fill))
+        .push(actions);
+
+    let col = Column::new().spacing(10).push(header).push(form);
+
+    ui::card(t, col.into())
+}
+
+/// Entry of the month picker; `number == 0` is "no month".
+#[derive(Debug, Clone, PartialEq)]
+struct MonthChoice {
+    number: u32,
+    name: String,
+}
+
+impl fmt::Display for MonthChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+fn event_form<'a>(ed: &'a EventEditor, calendar: &Calendar) -> E<'a> {
+    let choices: Vec<MonthChoice> = std::iter::once(MonthChoice {
+        number: 0,
+        name: "No month".to_string(),
+    })
+    .chain(calendar.months.iter().zip(1..).map(|(name, number)| MonthChoice {
+        number,
+        name: name.clone(),
+    }))
+    .collect();
+
+    let selected = choices.iter().find(|c| c.number == ed.month).cloned();
+
+    let date = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(
+            container(
+                text_input("Year", &ed.year)
+                    .on_input(Message::TimelineEventYearChanged)
+                    .padding(10),
+            )
+            .width(Length::Fixed(140.0)),
+        )
+        .push(
+            pick_list(choices, selected, |c: MonthChoice| {
+                Message::TimelineEventMonthChanged(c.number)
+            })
+            .padding(10),
+        )
+        .push(
+            container(
+                text_input("Day", &ed.day)
+                    .on_input(Message::TimelineEventDayChanged)
+                    .padding(10),
+            )
+            .width(Length::Fixed(100.0)),
+        );
+
+    Column::new()
+        .spacing(10)
+        .push(
+            text_input("Title", &ed.title)
+                .on_input(Message::TimelineEventTitleChanged)
+                .padding(10),
+        )
+        .push(
+            text_input("Description", &ed.description)
+                .on_input(Message::TimelineEventDescriptionChanged)
+                .padding(10),
+        )
+        .push(date)
+        .push(
+            text(format!(
+                "Years can be negative. Days run 1–{}; leave the month empty for year-only events.",
+                calendar.days_per_month
+            ))
+            .size(10)
+            .color(ui::muted_small()),
+        )
+        .into()
+}
+
+fn era_form<'a>(ed: &'a EraEditor) -> E<'a> {
+    let years = Row::new()
+        .spacing(10)
+        .push(
+            container(
+                text_input("Start year", &ed.start_year)
+                    .on_input(Message::TimelineEraStartChanged)
+                    .padding(10),
+            )
+            .width(Length::Fixed(160.0)),
+        )
+        .push(
+            container(
+                text_input("End year (empty = ongoing)", &ed.end_year)
+                    .on_input(Message::TimelineEraEndChanged)
+                    .padding(10),
+            )
+            .width(Length::Fixed(220.0)),
+        );
+
+    Column::new()
+        .spacing(10)
+        .push(
+            text_input("Name", &ed.name)
+                .on_input(Message::TimelineEraNameChanged)
+                .padding(10),
+        )
+        .push(
+            text_input("Description", &ed.description)
+                .on_input(Message::TimelineEraDescriptionChanged)
+                .padding(10),
+        )
+        .push(years)
+        .into()
+}
+
+fn calendar_form<'a>(ed: &'a CalendarEditor) -> E<'a> {
+    Column::new()
+        .spacing(10)
+        .push(
+            text_input("Months, comma-separated (e.g. Frostmoon, Thawing, Seedfall)", &ed.months)
+                .on_input(Message::TimelineCalendarMonthsChanged)
+                .padding(10),
+        )
+        .push(
+            Row::new()
+                .spacing(10)
+                .push(
+                    container(
+                        text_input("Days per month", &ed.days_per_month)
+                            .on_input(Message::TimelineCalendarDaysChanged)
+                            .padding(10),
+                    )
+                    .width(Length::Fixed(160.0)),
+                )
+                .push(
+                    container(
+                        text_input("Year suffix (e.g. AR)", &ed.year_suffix)
+                            .on_input(Message::TimelineCalendarSuffixChanged)
+                            .padding(10),
+                    )
+                    .width(Length::Fixed(220.0)),
+                ),
+        )
+        .push(
+            text("Events past the last month or day move onto it when the calendar gets shorter.")
+                .size(10)
+                .color(ui::muted_small()),
+        )
+        .into()
+}
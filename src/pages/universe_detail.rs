@@ -1,44 +1,53 @@
 use iced::{Alignment, Length};
-use iced::widget::{container, text, Column, Row};
+use iced::widget::{container, text, text_editor, text_input, Column, Row};
 
-use crate::app::{AppState, Message, Route};
+use crate::app::{AppState, Message, Route, UniverseEditor};
+use crate::model::validation::Field;
+use crate::model::Codex;
 use crate::{ui, pages::E};
 
 pub fn universe_detail<'a>(state: &'a AppState, t: ui::Tokens, universe_id: &'a str) -> E<'a> {
-    let u = state.universes.iter().find(|x| x.id == universe_id);
+    let u = state.project.universe(universe_id);
 
-    let (name, desc) = match u {
-        Some(u) => (u.name.clone(), u.description.clone()),
-        None => ("Unknown".to_string(), "".to_string()),
+    let (name, desc, lore, archived) = match u {
+        Some(u) => (u.name.as_str(), u.description.as_str(), u.lore.as_str(), u.archived),
+        None => ("Unknown", "", "", false),
     };
 
     let header_left = Column::new()
         .spacing(4)
-        .push(text(name.clone()).size(26).color(t.foreground))
-        .push(text(desc).size(12).color(t.muted_fg))
-        .push(text("Status: Active").size(12).color(t.muted_fg));
+        .push(text(name).size(26).color(t.foreground))
+        .push(ui::markdown_text(state, t, ui::first_paragraph(desc), 12))
+        .push(
+            text(if archived { "Status: Archived" } else { "Status: Active" })
+                .size(12)
+                .color(t.muted_fg),
+        );
+
+    let editing = state.universe_editor.as_ref().filter(|ed| ed.id == universe_id);
 
-    let header_right = Row::new()
+    let mut header_right = Row::new()
         .spacing(10)
         .push(ui::outline_button(t, "Back to universes".to_string(), Message::BackToUniverses))
         .push(ui::outline_button(t, "Go to PM Tools".to_string(), Message::Navigate(Route::PmTools)));
 
+    if u.is_some() && editing.is_none() {
+        header_right = header_right.push(ui::primary_button(
+            t,
+            "Edit universe".to_string(),
+            Message::UniverseEditorOpen(universe_id.to_string()),
+        ));
+    }
+
     let header = Row::new()
         .align_y(Alignment::Center)
         .push(container(header_left).width(Length::Fill))
         .push(header_right);
 
-    let info = ui::card(
-        t,
-        Column::new()
-            .spacing(6)
-            .push(text("This is the starting point for your universe workspace.").size(12).color(t.muted_fg))
-            .push(text("In future iterations, this page will host:").size(12).color(t.muted_fg))
-            .push(text("• Key lore overview (high-level notes, pitch, themes)").size(12).color(t.muted_fg))
-            .push(text("• Entry points into timelines, maps, bestiary, artifacts, etc.").size(12).color(t.muted_fg))
-            .push(text("• Quick links to PM boards, documents and assets related to this universe.").size(12).color(t.muted_fg))
-            .into(),
-    );
+    let info: E<'a> = match editing {
+        Some(editor) => editor_panel(state, t, editor),
+        None => lore_card(state, t, desc, lore),
+    };
 
     let tools = Column::new()
         .spacing(8)
@@ -46,22 +55,111 @@ pub fn universe_detail<'a>(state: &'a AppState, t: ui::Tokens, universe_id: &'a
         .push(
             Row::new()
                 .spacing(10)
-                .push(ui::outline_button(t, "Bestiary".to_string(), Message::OpenBestiary(universe_id.to_string())))
-                .push(ui::outline_button(t, "Timeline".to_string(), Message::OpenTimeline(universe_id.to_string()))),
+                .push(ui::outline_button(
+                    t,
+                    format!("Bestiary ({})", state.project.creatures_in(universe_id).count()),
+                    Message::OpenCodex(Codex::Creature, universe_id.to_string()),
+                ))
+                .push(ui::outline_button(
+                    t,
+                    format!("Locations ({})", state.project.locations_in(universe_id).count()),
+                    Message::OpenCodex(Codex::Location, universe_id.to_string()),
+                ))
+                .push(ui::outline_button(
+                    t,
+                    format!("Characters ({})", state.project.characters_in(universe_id).count()),
+                    Message::OpenCodex(Codex::Character, universe_id.to_string()),
+                ))
+                .push(ui::outline_button(
+                    t,
+                    format!("Factions ({})", state.project.factions_in(universe_id).count()),
+                    Message::OpenCodex(Codex::Faction, universe_id.to_string()),
+                ))
+                .push(ui::outline_button(
+                    t,
+                    format!("Runomicon ({})", state.project.terms_in(universe_id).count()),
+                    Message::OpenCodex(Codex::Term, universe_id.to_string()),
+                ))
+                .push(ui::outline_button(
+                    t,
+                    format!("Artifacts ({})", state.project.artifacts_in(universe_id).count()),
+                    Message::OpenCodex(Codex::Artifact, universe_id.to_string()),
+                ))
+                .push(ui::outline_button(
+                    t,
+                    format!("Timeline ({})", state.project.events_in(universe_id).len()),
+                    Message::OpenTimeline(universe_id.to_string()),
+                ))
+                .wrap(),
         );
 
+    let boards: Vec<_> = state.project.boards_linked_to(universe_id).collect();
+
+    let linked_list: E<'a> = if boards.is_empty() {
+        ui::card(
+            t,
+            container(text("No PM boards linked to this universe yet.").size(12).color(t.muted_fg))
+                .width(Length::Fill)
+                .padding([14, 14])
+                .into(),
+        )
+    } else {
+        let mut list = Column::new().spacing(10);
+        for b in boards {
+            let row = Row::new()
+                .align_y(Alignment::Center)
+                .push(
+                    container(
+                        Column::new()
+                            .spacing(4)
+                            .push(text(&b.name).size(14).color(t.foreground))
+                            .push(
+                                text(format!("{} column(s) · {} card(s)", b.columns.len(), b.card_count()))
+                                    .size(12)
+                                    .color(t.muted_fg),
+                            ),
+                    )
+                    .width(Length::Fill),
+                )
+                .push(ui::outline_button(t, "Open board".to_string(), Message::OpenBoard(b.id.clone())));
+
+            list = list.push(ui::card(t, row.into()));
+        }
+        list.into()
+    };
+
     let linked_pm = Column::new()
         .spacing(8)
         .push(text("Linked PM boards").size(12).color(t.muted_fg))
+        .push(linked_list);
+
+    let assets: Vec<_> = state.project.assets_for_universe(universe_id).collect();
+
+    let assets_list: E<'a> = if assets.is_empty() {
+        ui::card(
+            t,
+            container(text("No assets attached to this universe yet.").size(12).color(t.muted_fg))
+                .width(Length::Fill)
+                .padding([14, 14])
+                .into(),
+        )
+    } else {
+        let mut list = Column::new().spacing(6);
+        for a in assets {
+            list = list.push(text(format!("{} · {}", a.name, a.kind)).size(12).color(t.foreground));
+        }
+        ui::card(t, list.into())
+    };
+
+    let attached_assets = Column::new()
+        .spacing(8)
         .push(
-            ui::card(
-                t,
-                container(text("No PM boards linked to this universe yet.").size(12).color(t.muted_fg))
-                    .width(Length::Fill)
-                    .padding([14, 14])
-                    .into(),
-            ),
-        );
+            Row::new()
+                .align_y(Alignment::Center)
+                .push(container(text("Attached assets").size(12).color(t.muted_fg)).width(Length::Fill))
+                .push(ui::ghost_button(t, "Open Assets →".to_string(), Message::Navigate(Route::Assets))),
+        )
+        .push(assets_list);
 
     let body = Column::new()
         .spacing(14)
@@ -69,7 +167,82 @@ pub fn universe_detail<'a>(state: &'a AppState, t: ui::Tokens, universe_id: &'a
         .push(info)
         .push(tools)
         .push(linked_pm)
+        .push(attached_assets)
         .width(Length::Fill);
 
     ui::page_padding(body.into())
 }
+
+/// Full description and lore overview, rendered from Markdown.
+fn lore_card<'a>(state: &'a AppState, t: ui::Tokens, desc: &'a str, lore: &'a str) -> E<'a> {
+    let mut col = Column::new().spacing(10);
+
+    if !desc.is_empty() {
+        col = col
+            .push(text("About").size(14).color(t.foreground))
+            .push(ui::markdown_text(state, t, desc, 13));
+    }
+
+    col = col.push(text("Lore overview").size(14).color(t.foreground));
+    col = if lore.is_empty() {
+        col.push(
+            text("No lore yet. Use \"Edit universe\" to note the pitch, themes and high-level history.")
+                .size(12)
+                .color(t.muted_fg),
+        )
+    } else {
+        col.push(ui::markdown_text(state, t, lore, 13))
+    };
+
+    ui::card(t, col.into())
+}
+
+fn editor_panel<'a>(state: &'a AppState, t: ui::Tokens, editor: &'a UniverseEditor) -> E<'a> {
+    let errors = editor.errors(&state.project);
+
+    let actions = Row::new()
+        .spacing(10)
+        .push(ui::outline_button(t, "Cancel".to_string(), Message::UniverseEditorCancel))
+        .push(ui::primary_button_maybe(
+            t,
+            "Save".to_string(),
+            errors.is_empty().then_some(Message::UniverseEditorSave),
+        ));
+
+    let header = Row::new()
+        .align_y(Alignment::Center)
+        .push(container(text("Edit universe").size(16).color(t.foreground)).width(Length::Fill))
+        .push(actions);
+
+    let name = text_input("Name", &editor.name)
+        .on_input(Message::UniverseEditorNameChanged)
+        .padding(10);
+
+    let description = text_editor(&editor.description)
+        .placeholder("Description")
+        .on_action(Message::UniverseEditorDescriptionEdit)
+        .padding(10)
+        .height(Length::Fixed(120.0));
+
+    let lore = text_editor(&editor.lore)
+        .placeholder("Lore overview: pitch, themes, high-level history…")
+        .on_action(Message::UniverseEditorLoreEdit)
+        .padding(10)
+        .height(Length::Fixed(320.0));
+
+    let form = Column::new()
+        .spacing(10)
+        .push(header)
+        .push(text("Name").size(12).color(t.muted_fg))
+        .push(name)
+        .push(errors.get(Field::Name).map(ui::error_text))
+        .push(text("Description").size(12).color(t.muted_fg))
+        .push(description)
+        .push(errors.get(Field::Description).map(ui::error_text))
+        .push(text("Lore overview").size(12).color(t.muted_fg))
+        .push(lore)
+        .push(errors.get(Field::Lore).map(ui::error_text))
+        .push(ui::markdown_hint());
+
+    ui::card(t, form.into())
+}
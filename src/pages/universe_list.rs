@@ -1,15 +1,22 @@
 use iced::{Alignment, Length};
 use iced::widget::{container, text, text_input, Column, Row};
 
-use crate::app::{AppState, Message, Route};
+use crate::app::{AppState, Message};
 use crate::model::Universe;
+use crate::model::validation::Field;
 use crate::{ui, pages::E};
 
 pub fn universe_list<'a>(state: &'a AppState, t: ui::Tokens) -> E<'a> {
+    let active_count = state.project.workspace_universes().filter(|u| !u.archived).count();
+    let subtitle = match active_count {
+        1 => "1 universe in this workspace.".to_string(),
+        n => format!("{n} universes in this workspace."),
+    };
+
     let title = Column::new()
         .spacing(4)
         .push(text("Universe").size(26).color(t.foreground))
-        .push(text("1 universe in this workspace.").size(12).color(t.muted_fg));
+        .push(text(subtitle).size(12).color(t.muted_fg));
 
     let name_input = text_input("Universe name", &state.new_universe_name)
         .on_input(Message::UniverseNameChanged)
@@ -19,13 +26,33 @@ pub fn universe_list<'a>(state: &'a AppState, t: ui::Tokens) -> E<'a> {
         .on_input(Message::UniverseDescChanged)
         .padding(10);
 
-    let create_btn = ui::primary_button(t, "Create universe".to_string(), Message::CreateUniverse);
+    let errors = crate::app::new_universe_errors(state);
+    // An untouched form isn't an error yet; Create just stays disabled.
+    let name_error = errors.get(Field::Name).filter(|_| !state.new_universe_name.is_empty());
+
+    let create_btn = ui::primary_button_maybe(
+        t,
+        "Create universe".to_string(),
+        errors.is_empty().then_some(Message::CreateUniverse),
+    );
 
     let form = Row::new()
         .spacing(10)
-        .align_y(Alignment::Center)
-        .push(container(name_input).width(Length::Fixed(220.0)))
-        .push(container(desc_input).width(Length::Fixed(280.0)))
+        .align_y(Alignment::Start)
+        .push(
+            Column::new()
+                .spacing(4)
+                .width(Length::Fixed(220.0))
+                .push(name_input)
+                .push(name_error.map(ui::error_text)),
+        )
+        .push(
+            Column::new()
+                .spacing(4)
+                .width(Length::Fixed(280.0))
+                .push(desc_input)
+                .push(errors.get(Field::Description).map(ui::error_text)),
+        )
         .push(create_btn);
 
     let header = Row::new()
@@ -36,41 +63,77 @@ pub fn universe_list<'a>(state: &'a AppState, t: ui::Tokens) -> E<'a> {
     let active_header = text("Active universes").size(12).color(t.muted_fg);
 
     let mut active_list = Column::new().spacing(10);
-    for u in state.universes.iter().filter(|u| !u.archived) {
-        active_list = active_list.push(universe_card(t, u.clone()));
+    for u in state.project.workspace_universes().filter(|u| !u.archived) {
+        active_list = active_list.push(universe_card(t, state, u));
     }
 
-    let archived = ui::card(
-        t,
-        container(text("No archived universes.").size(12).color(t.muted_fg))
-            .width(Length::Fill)
-            .padding([14, 14])
-            .into(),
-    );
+    let archived_universes: Vec<&Universe> =
+        state.project.workspace_universes().filter(|u| u.archived).collect();
+
+    let archived_header = text(format!("Archived ({})", archived_universes.len()))
+        .size(12)
+        .color(t.muted_fg);
+
+    let archived: E<'a> = if archived_universes.is_empty() {
+        ui::card(
+            t,
+            container(text("No archived universes.").size(12).color(t.muted_fg))
+                .width(Length::Fill)
+                .padding([14, 14])
+                .into(),
+        )
+    } else {
+        let mut list = Column::new().spacing(10);
+        for u in archived_universes {
+            list = list.push(universe_card(t, state, u));
+        }
+        list.into()
+    };
 
     let body = Column::new()
         .spacing(14)
         .push(header)
         .push(active_header)
         .push(active_list)
-        .push(text("Archived").size(12).color(t.muted_fg))
+        .push(archived_header)
         .push(archived)
         .width(Length::Fill);
 
     ui::page_padding(body.into())
 }
 
-fn universe_card(t: ui::Tokens, u: Universe) -> iced::Element<'static, Message> {
+fn universe_card<'a>(t: ui::Tokens, state: &'a AppState, u: &'a Universe) -> E<'a> {
     let left = Column::new()
         .spacing(4)
-        .push(text(u.name.clone()).size(16).color(t.foreground))
-        .push(text(u.description.clone()).size(12).color(t.muted_fg));
-
-    let actions = Row::new()
-        .spacing(10)
-        .push(ui::outline_button(t, "Open".to_string(), Message::OpenUniverse(u.id.clone())))
-        .push(ui::outline_button(t, "Archive".to_string(), Message::Navigate(Route::UniverseList)))
-        .push(ui::danger_button(t, "Delete".to_string(), Message::Navigate(Route::UniverseList)));
+        .push(text(&u.name).size(16).color(t.foreground))
+        .push(ui::markdown_text(state, t, ui::first_paragraph(&u.description), 12));
+
+    let actions: E<'a> = if state.pending_universe_delete.as_deref() == Some(u.id.as_str()) {
+        let creatures = state.project.creatures_in(&u.id).count();
+
+        ui::confirm_delete(
+            t,
+            format!(
+                "Delete \"{}\" and everything in it, including {creatures} creature(s)? You can undo this.",
+                u.name
+            ),
+            Message::DeleteUniverseCancel,
+            Message::DeleteUniverseConfirm,
+        )
+    } else if u.archived {
+        Row::new()
+            .spacing(10)
+            .push(ui::outline_button(t, "Restore".to_string(), Message::RestoreUniverse(u.id.clone())))
+            .push(ui::danger_button(t, "Delete".to_string(), Message::DeleteUniverse(u.id.clone())))
+            .into()
+    } else {
+        Row::new()
+            .spacing(10)
+            .push(ui::outline_button(t, "Open".to_string(), Message::OpenUniverse(u.id.clone())))
+            .push(ui::outline_button(t, "Archive".to_string(), Message::ArchiveUniverse(u.id.clone())))
+            .push(ui::danger_button(t, "Delete".to_string(), Message::DeleteUniverse(u.id.clone())))
+            .into()
+    };
 
     let body = Column::new()
         .spacing(10)
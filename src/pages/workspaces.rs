@@ -0,0 +1,126 @@
+use iced::{Alignment, Length};
+use iced::widget::{container, text, text_input, Column, Row};
+
+use crate::app::{AppState, Message, TitleField};
+use crate::model::validation::{self, Field};
+use crate::model::Workspace;
+use crate::{pages::E, ui};
+
+pub fn workspaces<'a>(state: &'a AppState, t: ui::Tokens) -> E<'a> {
+    let subtitle = match state.project.workspaces.len() {
+        1 => "1 workspace. Each one keeps its own universes, manuscripts, boards and assets.".to_string(),
+        n => format!("{n} workspaces. Each one keeps its own universes, manuscripts, boards and assets."),
+    };
+
+    let title = Column::new()
+        .spacing(4)
+        .push(text("Workspaces").size(26).color(t.foreground))
+        .push(text(subtitle).size(12).color(t.muted_fg));
+
+    let name_input = text_input("Workspace name", &state.new_workspace_name)
+        .on_input(Message::WorkspaceNewNameChanged)
+        .on_submit(Message::CreateWorkspace)
+        .padding(10);
+
+    let errors = crate::app::new_workspace_errors(state);
+    // An untouched form isn't an error yet; Create just stays disabled.
+    let name_error = errors.get(Field::Name).filter(|_| !state.new_workspace_name.is_empty());
+
+    let form = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Start)
+        .push(
+            Column::new()
+                .spacing(4)
+                .width(Length::Fixed(240.0))
+                .push(name_input)
+                .push(name_error.map(ui::error_text)),
+        )
+        .push(ui::primary_button_maybe(
+            t,
+            "Create workspace".to_string(),
+            errors.is_empty().then_some(Message::CreateWorkspace),
+        ));
+
+    let header = Row::new()
+        .align_y(Alignment::Center)
+        .push(container(title).width(Length::Fill))
+        .push(form);
+
+    let mut list = Column::new().spacing(10);
+    for w in &state.project.workspaces {
+        list = list.push(workspace_card(state, t, w));
+    }
+
+    let body = Column::new()
+        .spacing(14)
+        .push(header)
+        .push(text("All workspaces").size(12).color(t.muted_fg))
+        .push(list)
+        .width(Length::Fill);
+
+    ui::page_padding(body.into())
+}
+
+fn workspace_card<'a>(state: &'a AppState, t: ui::Tokens, w: &'a Workspace) -> E<'a> {
+    let p = &state.project;
+    let active = p.active_workspace == w.id;
+
+    let universes = p.universes.iter().filter(|u| u.workspace_id == w.id).count();
+    let manuscripts = p.manuscripts.iter().filter(|m| m.workspace_id == w.id).count();
+    let boards = p.boards.iter().filter(|b| b.workspace_id == w.id).count();
+    let assets = p.assets.iter().filter(|a| a.workspace_id == w.id).count();
+
+    let name = state.title_text(&TitleField::Workspace(w.id.clone()), &w.name);
+    let errors = validation::workspace(p, Some(&w.id), name);
+
+    let id = w.id.clone();
+    let name_input = text_input("Workspace name", name)
+        .on_input(move |name| Message::WorkspaceNameChanged(id.clone(), name))
+        .on_submit(Message::TitleSubmit)
+        .padding(8);
+
+    let mut top = Row::new().spacing(10).align_y(Alignment::Center).push(
+        Column::new()
+            .spacing(4)
+            .width(Length::Fixed(260.0))
+            .push(name_input)
+            .push(errors.get(Field::Name).map(ui::error_text)),
+    );
+    if active {
+        top = top.push(text("Active").size(12).color(t.muted_fg));
+    }
+
+    let counts = text(format!(
+        "{universes} universe(s) · {manuscripts} manuscript(s) · {boards} board(s) · {assets} asset(s)"
+    ))
+    .size(12)
+    .color(t.muted_fg);
+
+    let actions: E<'a> = if state.pending_workspace_delete.as_deref() == Some(w.id.as_str()) {
+        ui::confirm_delete(
+            t,
+            format!("Delete \"{}\" and everything in it? This cannot be undone.", w.name),
+            Message::DeleteWorkspaceCancel,
+            Message::DeleteWorkspaceConfirm,
+        )
+    } else {
+        let mut row = Row::new().spacing(10);
+        if !active {
+            row = row.push(ui::outline_button(t, "Switch to".to_string(), Message::SwitchWorkspace(w.id.clone())));
+        }
+        // The last workspace stays: everything else needs somewhere to live.
+        if p.workspaces.len() > 1 {
+            row = row.push(ui::danger_button(t, "Delete".to_string(), Message::DeleteWorkspace(w.id.clone())));
+        }
+        row.into()
+    };
+
+    let body = Column::new()
+        .spacing(10)
+        .push(top)
+        .push(counts)
+        .push(actions);
+
+    ui::card(t, body.into())
+}
@@ -0,0 +1,168 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::model::Project;
+
+const DATA_DIR_ENV: &str = "TAS_DATA_DIR";
+const APP_DIR: &str = "tas";
+const PROJECT_FILE: &str = "project.json";
+const ASSETS_DIR: &str = "assets";
+
+/// File-backed store for the [`Project`].
+///
+/// The whole project is kept as a single JSON document. Writes go through a temporary
+/// file + rename so a crash mid-save never leaves a half-written project behind.
+#[derive(Debug)]
+pub struct Store {
+    path: PathBuf,
+    /// Last contents read from / written to disk, used to skip redundant writes.
+    last_written: Option<String>,
+}
+
+impl Store {
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            last_written: None,
+        }
+    }
+
+    /// Default location: `$TAS_DATA_DIR/project.json`, else the platform data dir.
+    pub fn default_path() -> PathBuf {
+        let dir = match std::env::var_os(DATA_DIR_ENV) {
+            Some(dir) => PathBuf::from(dir),
+            None => dirs::data_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join(APP_DIR),
+        };
+
+        dir.join(PROJECT_FILE)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Folder next to the project file where imported asset files live.
+    pub fn assets_dir(&self) -> PathBuf {
+        self.path
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join(ASSETS_DIR)
+    }
+
+    /// Copies `source` into the assets folder as `file_name`, returning the copied size.
+    pub fn import_file(&self, source: &Path, file_name: &str) -> io::Result<u64> {
+        let dir = self.assets_dir();
+        fs::create_dir_all(&dir)?;
+        fs::copy(source, dir.join(file_name))
+    }
+
+    /// Removes an imported file; a file that is already gone is not an error.
+    pub fn remove_file(&self, file_name: &str) -> io::Result<()> {
+        match fs::remove_file(self.assets_dir().join(file_name)) {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            other => other,
+        }
+    }
+
+    /// Reads the project from disk. `Ok(None)` means a brand-new store (no file yet).
+    pub fn load(&mut self) -> io::Result<Option<Project>> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+
+        let project = serde_json::from_str(&contents)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        self.last_written = Some(contents);
+        Ok(Some(project))
+    }
+
+    /// Copies a project file that failed to load next to it, e.g. `project.json.unreadable-1700000000`,
+    /// so saving the fresh project over it loses nothing.
+    pub fn keep_unreadable(&self) -> io::Result<PathBuf> {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let copy = self.path.with_extension(format!("json.unreadable-{secs}"));
+
+        fs::copy(&self.path, &copy)?;
+        Ok(copy)
+    }
+
+    /// Writes the project to disk, unless it is unchanged since the last load/save.
+    pub fn save(&mut self, project: &Project) -> io::Result<()> {
+        let contents = serde_json::to_string_pretty(project)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        if self.last_written.as_deref() == Some(contents.as_str()) {
+            return Ok(());
+        }
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, &contents)?;
+        fs::rename(&tmp, &self.path)?;
+
+        self.last_written = Some(contents);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::fixture::project;
+
+    /// Project file in a fresh temporary folder.
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("tas-storage-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join(PROJECT_FILE)
+    }
+
+    #[test]
+    fn missing_file_is_a_new_store() {
+        let mut store = Store::open(temp_path("missing"));
+        assert!(store.load().unwrap().is_none());
+    }
+
+    #[test]
+    fn saved_project_loads_back_unchanged() {
+        let path = temp_path("round-trip");
+        let project = project();
+
+        Store::open(&path).save(&project).unwrap();
+        let loaded = Store::open(&path).load().unwrap().unwrap();
+
+        assert_eq!(serde_json::to_value(&loaded).unwrap(), serde_json::to_value(&project).unwrap());
+        assert!(!path.with_extension("json.tmp").exists());
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn unreadable_file_is_an_error_and_can_be_kept() {
+        let path = temp_path("unreadable");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "{not json").unwrap();
+
+        let mut store = Store::open(&path);
+        let err = store.load().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let copy = store.keep_unreadable().unwrap();
+        assert_eq!(fs::read_to_string(copy).unwrap(), "{not json");
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
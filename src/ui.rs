@@ -1,19 +1,27 @@
 use iced::{
-    border, gradient, Alignment, Background, Border, Color, Element, Length, Padding, Radians,
-    Shadow, Theme, Vector,
+    border, gradient, padding, Alignment, Background, Border, Color, Element, Font, Length, Padding,
+    Radians, Shadow, Theme, Vector,
 };
-use iced::widget::{button, container, text, Column, Row, Space};
+use iced::widget::{
+    button, container, markdown, pick_list, rich_text, span, text, text_input, Column, Row, Space,
+};
+
+use std::ops::Range;
 
-use crate::app::{AppState, Message, Route, APP_NAME};
+use crate::app::{AppState, Message, Route, Toast, APP_NAME};
+use crate::model::search::{search, SearchHit};
 
 // Simplificamos el tipo para uso interno
 pub type E<'a> = Element<'a, Message>;
 
 const APP_SLOGAN: &str = "Where Reality Begins.";
 
+/// Id of the header search box, focused by the Ctrl/Cmd+K shortcut.
+pub const SEARCH_INPUT: &str = "global-search";
+
+/// Design palette shared by every widget.
 #[derive(Debug, Clone, Copy)]
 pub struct Tokens {
-    pub background: Color,
     pub foreground: Color,
     pub muted_fg: Color,
 
@@ -26,7 +34,6 @@ pub struct Tokens {
 
     // Lines & states
     pub border: Color,
-    pub input_border: Color,
     pub hover_bg: Color,
     pub active_bg: Color,
 
@@ -40,7 +47,6 @@ impl Tokens {
         let white = Color::from_rgba8(0xFF, 0xFF, 0xFF, 1.0);
 
         Self {
-            background: Color::from_rgba8(0x00, 0x00, 0x00, 1.0),
             foreground: Color::from_rgba8(0xF2, 0xF2, 0xF2, 1.0),
             muted_fg: Color::from_rgba8(0xA7, 0xA8, 0xAB, 0.82),
 
@@ -56,7 +62,6 @@ impl Tokens {
             sidebar_bg: Color::from_rgba8(0x1B, 0x1B, 0x1B, 1.0),
 
             border: alpha(white, 0.08),
-            input_border: alpha(white, 0.14),
 
             hover_bg: alpha(white, 0.05),
             active_bg: alpha(white, 0.07),
@@ -203,7 +208,14 @@ pub fn ghost_button(t: Tokens, label: String, on_press: Message) -> Element<'sta
 }
 
 pub fn primary_button(t: Tokens, label: String, on_press: Message) -> Element<'static, Message> {
-    button(text(label).size(12).color(t.foreground))
+    primary_button_maybe(t, label, Some(on_press))
+}
+
+/// [`primary_button`] that is shown disabled while `on_press` is `None`.
+pub fn primary_button_maybe(t: Tokens, label: String, on_press: Option<Message>) -> Element<'static, Message> {
+    let enabled = on_press.is_some();
+
+    button(text(label).size(12).color(if enabled { t.foreground } else { t.muted_fg }))
         .padding([8, 12])
         .style(move |_: &Theme, status| {
             let mut s = iced::widget::button::Style::default();
@@ -216,6 +228,7 @@ pub fn primary_button(t: Tokens, label: String, on_press: Message) -> Element<'s
             let bg = match status {
                 iced::widget::button::Status::Hovered => hover,
                 iced::widget::button::Status::Pressed => press,
+                iced::widget::button::Status::Disabled => Color::TRANSPARENT,
                 _ => base,
             };
 
@@ -225,13 +238,23 @@ pub fn primary_button(t: Tokens, label: String, on_press: Message) -> Element<'s
                 width: 1.0,
                 radius: border::Radius::from(999.0),
             };
-            s.text_color = t.foreground;
+            s.text_color = if enabled { t.foreground } else { t.muted_fg };
             s
         })
-        .on_press(on_press)
+        .on_press_maybe(on_press)
         .into()
 }
 
+/// Secondary text under a list item: counts, word totals, hints.
+pub fn muted_small() -> Color {
+    Color::from_rgba8(0xA1, 0xA1, 0xA1, 0.70)
+}
+
+/// Validation message shown under an input.
+pub fn error_text<'a>(message: impl ToString) -> E<'a> {
+    text(message.to_string()).size(11).color(Color::from_rgba8(0xFF, 0x5A, 0x5A, 1.0)).into()
+}
+
 pub fn danger_button(_t: Tokens, label: String, on_press: Message) -> Element<'static, Message> {
     let danger = Color::from_rgba8(0xFF, 0x5A, 0x5A, 1.0);
     button(text(label).size(12).color(danger))
@@ -256,6 +279,20 @@ pub fn danger_button(_t: Tokens, label: String, on_press: Message) -> Element<'s
         .into()
 }
 
+/// Inline delete prompt replacing an item's actions until it is answered.
+pub fn confirm_delete(t: Tokens, prompt: String, cancel: Message, confirm: Message) -> Element<'static, Message> {
+    Column::new()
+        .spacing(8)
+        .push(text(prompt).size(12).color(t.foreground))
+        .push(
+            Row::new()
+                .spacing(10)
+                .push(outline_button(t, "Cancel".to_string(), cancel))
+                .push(danger_button(t, "Delete permanently".to_string(), confirm)),
+        )
+        .into()
+}
+
 // Divisores
 
 pub fn v_divider(t: Tokens) -> Element<'static, Message> {
@@ -269,16 +306,6 @@ pub fn v_divider(t: Tokens) -> Element<'static, Message> {
         .into()
 }
 
-pub fn h_divider(t: Tokens) -> Element<'static, Message> {
-    container(Space::new())
-        .width(Length::Fill)
-        .height(Length::Fixed(1.0))
-        .style(move |_| {
-            container_style(alpha(Color::from_rgba8(0xFF, 0xFF, 0xFF, 1.0), 0.06), t.foreground)
-        })
-        .into()
-}
-
 /* ---------------- Sidebar ---------------- */
 
 #[derive(Debug, Clone, Copy)]
@@ -293,19 +320,22 @@ enum NavKey {
 }
 
 fn is_active(state: &AppState, key: NavKey) -> bool {
-    match (key, &state.route) {
-        (NavKey::Overview, Route::Overview) => true,
-        (NavKey::Workspaces, Route::Workspaces) => true,
-        (NavKey::Universe, Route::UniverseList)
-        | (NavKey::Universe, Route::UniverseDetail { .. })
-        | (NavKey::Universe, Route::Bestiary { .. })
-        | (NavKey::Universe, Route::Timeline { .. }) => true,
-        (NavKey::Forge, Route::Forge) => true,
-        (NavKey::PmTools, Route::PmTools) => true,
-        (NavKey::Assets, Route::Assets) => true,
-        (NavKey::Settings, Route::Account) => true,
-        _ => false,
-    }
+    matches!(
+        (key, &state.route),
+        (NavKey::Overview, Route::Overview)
+            | (NavKey::Workspaces, Route::Workspaces)
+            | (NavKey::Universe, Route::UniverseList)
+            | (NavKey::Universe, Route::UniverseDetail { .. })
+            | (NavKey::Universe, Route::Codex { .. })
+            | (NavKey::Universe, Route::CreatureDetail { .. })
+            | (NavKey::Universe, Route::Timeline { .. })
+            | (NavKey::Forge, Route::Forge)
+            | (NavKey::Forge, Route::Manuscript { .. })
+            | (NavKey::PmTools, Route::PmTools)
+            | (NavKey::PmTools, Route::Board { .. })
+            | (NavKey::Assets, Route::Assets)
+            | (NavKey::Settings, Route::Account)
+    )
 }
 
 fn icon_for(key: NavKey) -> &'static str {
@@ -442,17 +472,52 @@ pub fn sidebar<'a>(state: &'a AppState, t: Tokens) -> E<'a> {
 
 /* ---------------- Header ---------------- */
 
-fn workspace_pill(t: Tokens, label: String) -> Element<'static, Message> {
-    container(text(label).size(12).color(t.foreground))
+#[derive(Debug, Clone, PartialEq)]
+struct WorkspaceChoice {
+    id: String,
+    name: String,
+}
+
+impl std::fmt::Display for WorkspaceChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// Active workspace pill; clicking it opens the list of workspaces to switch to.
+fn workspace_pill<'a>(state: &'a AppState, t: Tokens) -> E<'a> {
+    let choices: Vec<WorkspaceChoice> = state
+        .project
+        .workspaces
+        .iter()
+        .map(|w| WorkspaceChoice {
+            id: w.id.clone(),
+            name: w.name.clone(),
+        })
+        .collect();
+
+    let selected = choices.iter().find(|c| c.id == state.project.active_workspace).cloned();
+
+    pick_list(choices, selected, |c: WorkspaceChoice| Message::SwitchWorkspace(c.id))
+        .text_size(12)
         .padding([6, 12])
-        .style(move |_| {
-            let mut s = container_style(alpha(Color::from_rgba8(0xFF, 0xFF, 0xFF, 1.0), 0.06), t.foreground);
-            s.border = Border {
-                color: alpha(Color::from_rgba8(0xFF, 0xFF, 0xFF, 1.0), 0.10),
-                width: 1.0,
-                radius: border::Radius::from(999.0),
+        .style(move |_, status| {
+            let fill = match status {
+                pick_list::Status::Hovered | pick_list::Status::Opened { .. } => 0.10,
+                pick_list::Status::Active => 0.06,
             };
-            s
+
+            pick_list::Style {
+                text_color: t.foreground,
+                placeholder_color: t.muted_fg,
+                handle_color: t.muted_fg,
+                background: Background::Color(alpha(Color::from_rgba8(0xFF, 0xFF, 0xFF, 1.0), fill)),
+                border: Border {
+                    color: alpha(Color::from_rgba8(0xFF, 0xFF, 0xFF, 1.0), 0.10),
+                    width: 1.0,
+                    radius: border::Radius::from(999.0),
+                },
+            }
         })
         .into()
 }
@@ -463,10 +528,18 @@ pub fn header<'a>(state: &'a AppState, t: Tokens) -> E<'a> {
         .push(text(APP_NAME).size(16).color(alpha(t.foreground, 0.92)))
         .push(text(APP_SLOGAN).size(12).color(alpha(t.muted_fg, 0.92)));
 
+    let search_box = text_input("Search  (Ctrl+K)", &state.search_query)
+        .id(SEARCH_INPUT)
+        .on_input(Message::SearchQueryChanged)
+        .on_submit(Message::SearchSubmit)
+        .size(12)
+        .padding([6, 12]);
+
     let right = Row::new()
         .spacing(10)
         .align_y(Alignment::Center)
-        .push(workspace_pill(t, state.active_workspace.clone()));
+        .push(container(search_box).width(Length::Fixed(260.0)))
+        .push(workspace_pill(state, t));
 
     let bar = Row::new()
         .align_y(Alignment::Center)
@@ -484,3 +557,182 @@ pub fn header<'a>(state: &'a AppState, t: Tokens) -> E<'a> {
         .style(move |_| container_style(Color::TRANSPARENT, t.foreground))
         .into()
 }
+
+/* ---------------- Search ---------------- */
+
+/// Results panel under the header while a query is typed; `None` when search is idle.
+pub fn search_results<'a>(state: &'a AppState, t: Tokens) -> Option<E<'a>> {
+    if state.search_query.trim().is_empty() {
+        return None;
+    }
+
+    let groups = search(&state.project, &state.search_query);
+
+    let mut body = Column::new().spacing(12);
+
+    if groups.is_empty() {
+        body = body.push(
+            text(format!("No matches for \"{}\" in this workspace.", state.search_query.trim()))
+                .size(12)
+                .color(t.muted_fg),
+        );
+    }
+
+    for (group, hits) in groups {
+        let mut list = Column::new().spacing(2).push(text(group.label()).size(11).color(t.muted_fg));
+        for hit in hits {
+            list = list.push(search_hit(t, hit));
+        }
+        body = body.push(list);
+    }
+
+    let panel = container(card(t, body.into())).padding(Padding {
+        top: 0.0,
+        right: 24.0,
+        bottom: 0.0,
+        left: 24.0,
+    });
+
+    Some(panel.into())
+}
+
+fn search_hit<'a>(t: Tokens, hit: SearchHit) -> E<'a> {
+    let mut title = highlighted(t, hit.title, hit.title_highlight, 13, t.foreground);
+    if hit.archived {
+        title = Row::new()
+            .spacing(8)
+            .align_y(Alignment::Center)
+            .push(title)
+            .push(text("Archived").size(10).color(t.muted_fg))
+            .into();
+    }
+
+    let mut content = Column::new().spacing(2).push(title);
+
+    if let Some(snippet) = hit.snippet {
+        content = content.push(
+            Row::new()
+                .spacing(6)
+                .push(text(format!("{}:", snippet.field)).size(11).color(t.muted_fg))
+                .push(highlighted(t, snippet.text, Some(snippet.highlight), 11, t.muted_fg)),
+        );
+    }
+
+    button(content)
+        .width(Length::Fill)
+        .padding([6, 10])
+        .style(move |_: &Theme, status| {
+            let mut s = iced::widget::button::Style::default();
+            let bg = match status {
+                iced::widget::button::Status::Hovered => t.hover_bg,
+                iced::widget::button::Status::Pressed => t.active_bg,
+                _ => Color::TRANSPARENT,
+            };
+            s.background = Some(Background::Color(bg));
+            s.border.radius = border::Radius::from(8.0);
+            s
+        })
+        .on_press(Message::SearchSelect(hit.target))
+        .into()
+}
+
+/// `content` with the `range` bytes marked like a text highlighter.
+pub fn highlighted<'a>(t: Tokens, content: String, range: Option<Range<usize>>, size: u32, color: Color) -> E<'a> {
+    let Some(range) = range else {
+        return text(content).size(size).color(color).into();
+    };
+
+    let mark: text::Span<'a> = span(content[range.clone()].to_string())
+        .color(t.foreground)
+        .background(alpha(Color::from_rgba8(0xF2, 0xC9, 0x4C, 1.0), 0.35))
+        .border(border::rounded(3));
+
+    rich_text([
+        span(content[..range.start].to_string()).color(color),
+        mark,
+        span(content[range.end..].to_string()).color(color),
+    ])
+    .size(size)
+    .into()
+}
+
+/// Text up to the first blank line, for card excerpts of longer Markdown.
+pub fn first_paragraph(source: &str) -> &str {
+    let source = source.trim();
+    source.split("\n\n").next().unwrap_or(source).trim_end()
+}
+
+/// `source` rendered as Markdown, or as plain text while it isn't parsed yet.
+pub fn markdown_text<'a>(state: &'a AppState, t: Tokens, source: &'a str, size: u32) -> E<'a> {
+    let Some(items) = state.markdown.get(source) else {
+        return text(source).size(size).color(t.muted_fg).into();
+    };
+
+    let style = markdown::Style {
+        font: Font::default(),
+        inline_code_highlight: markdown::Highlight {
+            background: Background::Color(t.active_bg),
+            border: border::rounded(4),
+        },
+        inline_code_padding: padding::left(1).right(1),
+        inline_code_color: t.foreground,
+        inline_code_font: Font::MONOSPACE,
+        code_block_font: Font::MONOSPACE,
+        link_color: Color::from_rgba8(0x8A, 0xB4, 0xF8, 1.0),
+    };
+
+    markdown::view(items, markdown::Settings::with_text_size(size, style)).map(Message::OpenLink)
+}
+
+/// Shown under Markdown-enabled editors.
+pub fn markdown_hint<'a>() -> E<'a> {
+    text("Markdown: **bold**, *italic*, # heading, - list, > quote, [link](https://…). Blank line = new paragraph.")
+        .size(10)
+        .color(muted_small())
+        .into()
+}
+
+/// Bar under the page with the last undoable action and its Undo/Redo button.
+pub fn toast<'a>(t: Tokens, toast: &'a Toast) -> E<'a> {
+    let (label, keys) = match toast.action {
+        Message::Redo => ("Redo", "Ctrl+Shift+Z"),
+        _ => ("Undo", "Ctrl+Z"),
+    };
+
+    let row = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(container(text(&toast.text).size(12).color(t.foreground)).width(Length::Fill))
+        .push(text(keys).size(10).color(t.muted_fg))
+        .push(primary_button(t, label.to_string(), toast.action.clone()))
+        .push(ghost_button(t, "×".to_string(), Message::DismissToast));
+
+    bar(t, row)
+}
+
+/// Bar above the page when the project file could not be read or written.
+pub fn storage_error<'a>(t: Tokens, message: &'a str) -> E<'a> {
+    let row = Row::new()
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .push(container(error_text(message)).width(Length::Fill))
+        .push(ghost_button(t, "×".to_string(), Message::DismissStorageError));
+
+    bar(t, row)
+}
+
+fn bar<'a>(t: Tokens, row: Row<'a, Message>) -> E<'a> {
+    container(row)
+        .padding([10, 16])
+        .width(Length::Fill)
+        .style(move |_| {
+            let mut s = container_style(t.popover, t.foreground);
+            s.border = Border {
+                color: t.border,
+                width: 1.0,
+                radius: border::Radius::from(t.radius_lg),
+            };
+            s
+        })
+        .into()
+}
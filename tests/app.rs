@@ -0,0 +1,730 @@
+//! Drives the app through `update`, the way the window does, without opening one.
+
+use std::path::PathBuf;
+
+use tas::app::{update, AppState, BestiaryFilter, BestiarySort, EventEditor, Message, Route, TimelineEntry};
+use tas::model::validation::Field;
+use tas::model::{
+    ActivityTarget, AssetKind, Calendar, Codex, Creature, DangerLevel, FantasyDate, FieldKind, FieldValue, Holder,
+    Rarity,
+};
+use tas::storage::Store;
+
+/// Seed project, kept in memory only.
+fn state() -> AppState {
+    AppState::default()
+}
+
+fn send(state: &mut AppState, messages: impl IntoIterator<Item = Message>) {
+    for message in messages {
+        let _ = update(state, message);
+    }
+}
+
+fn create_universe(state: &mut AppState, name: &str) -> String {
+    state.new_universe_name = name.to_string();
+    send(state, [Message::CreateUniverse]);
+    state.project.universes.last().expect("universe was created").id.clone()
+}
+
+/// Project file in a fresh temporary folder.
+fn temp_store(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("tas-test-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir.join("project.json")
+}
+
+#[test]
+fn every_codex_creates_archives_deletes_and_undoes() {
+    let mut state = state();
+    let universe_id = create_universe(&mut state, "Erebor");
+
+    for codex in Codex::ALL {
+        send(
+            &mut state,
+            [
+                Message::OpenCodex(codex, universe_id.clone()),
+                Message::EditorOpenCreate(codex, universe_id.clone()),
+                Message::EditorLineChanged(codex, Field::Name, format!("Zyx {}", codex.noun())),
+                Message::EditorSave(codex),
+            ],
+        );
+
+        let id = state
+            .project
+            .universe_entries(&universe_id)
+            .find(|e| e.codex() == codex)
+            .map(|e| e.id().to_string())
+            .unwrap_or_else(|| panic!("{codex:?} entry was created"));
+
+        send(&mut state, [Message::ArchiveEntry(codex, id.clone())]);
+        assert!(state.project.find_entry(codex, &id).is_some_and(|e| e.archived()));
+
+        send(
+            &mut state,
+            [
+                Message::RestoreEntry(codex, id.clone()),
+                Message::DeleteEntry(codex, id.clone()),
+                Message::DeleteEntryConfirm,
+            ],
+        );
+        assert!(state.project.find_entry(codex, &id).is_none());
+
+        send(&mut state, [Message::Undo]);
+        assert!(state.project.find_entry(codex, &id).is_some_and(|e| !e.archived()));
+    }
+}
+
+#[test]
+fn undo_does_not_bring_back_universes_of_a_deleted_workspace() {
+    let mut state = state();
+    state.new_workspace_name = "Second".to_string();
+    send(&mut state, [Message::CreateWorkspace]);
+    let workspace_id = state.project.active_workspace.clone();
+
+    let universe_id = create_universe(&mut state, "Doomed");
+    send(
+        &mut state,
+        [
+            Message::DeleteUniverse(universe_id.clone()),
+            Message::DeleteUniverseConfirm,
+            Message::DeleteWorkspace(workspace_id),
+            Message::DeleteWorkspaceConfirm,
+            Message::Undo,
+        ],
+    );
+
+    assert!(state.project.universe(&universe_id).is_none());
+}
+
+#[test]
+fn undo_then_redo_of_a_new_universe_keeps_its_timeline() {
+    let mut state = state();
+    let universe_id = create_universe(&mut state, "Fleeting");
+    send(
+        &mut state,
+        [
+            Message::TimelineEraEditorOpenCreate(universe_id.clone()),
+            Message::TimelineEraNameChanged("First Age".to_string()),
+            Message::TimelineEraStartChanged("1".to_string()),
+            Message::TimelineEditorSave,
+        ],
+    );
+    let eras = state.project.eras.clone();
+    assert!(eras.iter().any(|e| e.universe_id == universe_id));
+
+    send(&mut state, [Message::Undo]);
+    assert!(state.project.universe(&universe_id).is_none());
+    assert!(!state.project.eras.iter().any(|e| e.universe_id == universe_id));
+
+    send(&mut state, [Message::Redo]);
+    assert!(state.project.universe(&universe_id).is_some());
+    assert_eq!(state.project.eras, eras);
+}
+
+#[test]
+fn changes_are_saved_and_load_back() {
+    let path = temp_store("save");
+    let mut state = state();
+    state.store = Some(Store::open(&path));
+
+    let universe_id = create_universe(&mut state, "Kept");
+    assert!(!state.unsaved);
+
+    let loaded = Store::open(&path).load().expect("project file is readable").expect("project file exists");
+    assert_eq!(loaded.universe(&universe_id).map(|u| u.name.as_str()), Some("Kept"));
+
+    let _ = std::fs::remove_dir_all(path.parent().unwrap());
+}
+
+#[test]
+fn imported_files_are_copied_into_the_store_and_removed_with_their_asset() {
+    let path = temp_store("assets");
+    let mut state = state();
+    state.store = Some(Store::open(&path));
+
+    let source = path.with_file_name("Map.PNG");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&source, "pixels").unwrap();
+
+    send(
+        &mut state,
+        [
+            Message::AssetImportPathChanged(path.with_file_name("missing.png").display().to_string()),
+            Message::ImportAsset,
+        ],
+    );
+    assert!(state.asset_import_error.is_some());
+
+    // Paths pasted from a file manager often come quoted.
+    send(
+        &mut state,
+        [Message::AssetImportPathChanged(format!("'{}'", source.display())), Message::ImportAsset],
+    );
+    assert!(state.asset_import_error.is_none() && state.asset_import_path.is_empty());
+
+    let asset = state.project.assets.last().expect("asset was imported").clone();
+    assert_eq!((asset.name.as_str(), asset.kind, asset.size_bytes), ("Map.PNG", AssetKind::Image, 6));
+    assert_eq!(asset.workspace_id, state.project.active_workspace);
+    let copy = state.asset_path(&asset).unwrap();
+    assert_eq!(std::fs::read_to_string(&copy).unwrap(), "pixels");
+
+    send(&mut state, [Message::DeleteAsset(asset.id.clone()), Message::DeleteAssetConfirm]);
+    assert!(state.project.asset(&asset.id).is_none());
+    assert!(!copy.exists());
+    assert!(source.exists());
+
+    let _ = std::fs::remove_dir_all(path.parent().unwrap());
+}
+
+#[test]
+fn only_changes_to_the_project_leave_it_unsaved() {
+    let mut state = state();
+    let universe_id = state.project.universes[0].id.clone();
+
+    send(
+        &mut state,
+        [
+            Message::Navigate(Route::UniverseList),
+            Message::SearchQueryChanged("fog".into()),
+            Message::SearchClose,
+            Message::UniverseEditorOpen(universe_id.clone()),
+            Message::UniverseEditorCancel,
+            Message::DeleteUniverse(universe_id.clone()),
+            Message::DeleteUniverseCancel,
+            Message::Undo,
+            // Rejected: the name is required.
+            Message::CreateUniverse,
+        ],
+    );
+    assert!(!state.unsaved);
+
+    send(&mut state, [Message::ArchiveUniverse(universe_id)]);
+    assert!(state.unsaved);
+}
+
+#[test]
+fn leaving_the_timeline_drops_its_delete_prompt() {
+    let mut state = state();
+    let universe_id = state.project.universes[0].id.clone();
+
+    send(
+        &mut state,
+        [
+            Message::OpenTimeline(universe_id.clone()),
+            Message::DeleteTimelineEntry(TimelineEntry::Era("dawn".into())),
+            Message::Navigate(Route::Overview),
+            Message::Navigate(Route::Timeline { universe_id }),
+        ],
+    );
+    assert!(state.pending_timeline_delete.is_none());
+}
+
+#[test]
+fn events_stay_inside_their_calendar() {
+    let calendar = Calendar {
+        months: vec!["Thaw".to_string(), "Frostmoon".to_string()],
+        days_per_month: 20,
+        ..Calendar::default_for("arhelis")
+    };
+    let editor = |month: u32, day: &str| EventEditor {
+        title: "Sundering".to_string(),
+        year: "340".to_string(),
+        month,
+        day: day.to_string(),
+        ..EventEditor::create_new("arhelis".to_string())
+    };
+    let date = |ed: EventEditor| ed.to_event(&calendar).map(|e| e.date);
+
+    assert_eq!(date(editor(2, "20")), Some(FantasyDate { year: 340, month: 2, day: 20 }));
+    assert_eq!(date(editor(0, "")), Some(FantasyDate { year: 340, month: 0, day: 0 }));
+    // A day without a month is dropped rather than rejected.
+    assert_eq!(date(editor(0, "5")), Some(FantasyDate { year: 340, month: 0, day: 0 }));
+    assert_eq!(date(editor(3, "")), None);
+    assert_eq!(date(editor(1, "21")), None);
+    assert_eq!(date(editor(1, "x")), None);
+    assert_eq!(date(EventEditor { year: String::new(), ..editor(1, "") }), None);
+    assert_eq!(date(EventEditor { title: " ".to_string(), ..editor(1, "") }), None);
+}
+
+#[test]
+fn a_shorter_calendar_moves_events_onto_its_last_month_and_day() {
+    let mut state = state();
+    let universe_id = create_universe(&mut state, "Shrinking");
+    send(
+        &mut state,
+        [
+            Message::TimelineEventEditorOpenCreate(universe_id.clone()),
+            Message::TimelineEventTitleChanged("Long Night".to_string()),
+            Message::TimelineEventYearChanged("12".to_string()),
+            Message::TimelineEventMonthChanged(11),
+            Message::TimelineEventDayChanged("30".to_string()),
+            Message::TimelineEditorSave,
+            Message::TimelineCalendarEdit(universe_id.clone()),
+            Message::TimelineCalendarMonthsChanged("Thaw, Frostmoon".to_string()),
+            Message::TimelineCalendarDaysChanged("20".to_string()),
+            Message::TimelineEditorSave,
+        ],
+    );
+
+    let event = state.project.events_in(&universe_id)[0];
+    assert_eq!(event.date, FantasyDate { year: 12, month: 2, day: 20 });
+}
+
+#[test]
+fn recent_activity_lists_the_latest_change_first_and_opens_it() {
+    let mut state = state();
+    let universe_id = create_universe(&mut state, "Recent");
+    state.new_manuscript_title = "Chronicle".to_string();
+    send(&mut state, [Message::CreateManuscript, Message::ForgeAddChapter]);
+    let manuscript_id = state.project.manuscripts.last().unwrap().id.clone();
+    let chapter_id = state.project.manuscripts.last().unwrap().chapters[0].id.clone();
+    send(&mut state, [Message::ForgeAddScene(chapter_id)]);
+
+    let latest: Vec<ActivityTarget> = state.project.workspace_activity().map(|a| a.target.clone()).take(2).collect();
+    assert_eq!(latest[1], ActivityTarget::Universe(universe_id.clone()));
+
+    let ActivityTarget::Manuscript(id) = &latest[0] else {
+        panic!("the manuscript was edited last, got {:?}", latest[0]);
+    };
+    assert_eq!(*id, manuscript_id);
+
+    send(&mut state, [Message::Navigate(Route::Overview), Message::OpenActivity(latest[1].clone())]);
+    assert!(matches!(&state.route, Route::UniverseDetail { universe_id: id } if *id == universe_id));
+
+    send(&mut state, [Message::OpenActivity(latest[0].clone())]);
+    assert!(matches!(&state.route, Route::Manuscript { manuscript_id: id } if *id == manuscript_id));
+    assert!(state.scene_editor.is_some());
+}
+
+#[test]
+fn danger_sort_puts_the_most_dangerous_first_and_unrated_last() {
+    let mut state = state();
+    let universe_id = create_universe(&mut state, "Ranked");
+    let universe = state.project.universe_mut(&universe_id).unwrap();
+    universe.danger_levels = vec!["Cataclysmic".to_string(), "Apocalyptic".to_string()];
+
+    let template = state.project.creatures[0].clone();
+    let creatures: Vec<Creature> = [
+        ("Unknown", DangerLevel::Unrated),
+        ("Wisp", DangerLevel::Low),
+        ("Wyrm", DangerLevel::Extreme),
+        ("Ender", DangerLevel::Custom("Apocalyptic".to_string())),
+        ("Breaker", DangerLevel::Custom("Cataclysmic".to_string())),
+    ]
+    .into_iter()
+    .map(|(name, danger)| Creature {
+        name: name.to_string(),
+        danger,
+        ..template.clone()
+    })
+    .collect();
+
+    let filter = BestiaryFilter {
+        sort: BestiarySort::Danger,
+        ..BestiaryFilter::default()
+    };
+    let mut sorted: Vec<&Creature> = creatures.iter().collect();
+    filter.sort(&mut sorted, state.project.universe(&universe_id));
+
+    let names: Vec<&str> = sorted.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["Ender", "Breaker", "Wyrm", "Wisp", "Unknown"]);
+}
+
+#[test]
+fn bestiary_filters_combine_and_are_kept_per_universe() {
+    let mut state = state();
+    let first = create_universe(&mut state, "Marsh");
+    let second = create_universe(&mut state, "Dune");
+
+    let template = state.project.creatures[0].clone();
+    let creature = |name: &str, danger: DangerLevel, tags: &[&str], habitat: &str| Creature {
+        name: name.to_string(),
+        danger,
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        habitat: habitat.to_string(),
+        ..template.clone()
+    };
+    let bog = creature("Bog Wight", DangerLevel::High, &["Undead"], "Salt Marshes");
+    let eel = creature("Reed Eel", DangerLevel::Low, &["Beast"], "Marshes");
+
+    send(
+        &mut state,
+        [
+            Message::OpenCodex(Codex::Creature, first.clone()),
+            Message::BestiaryTagFilterChanged(Some("undead".to_string())),
+            Message::BestiaryHabitatFilterChanged(" marsh ".to_string()),
+            Message::BestiarySortChanged(BestiarySort::Danger),
+        ],
+    );
+    let filter = state.bestiary_filters[&first].clone();
+    assert!(filter.is_active());
+    assert!(filter.matches(&bog) && !filter.matches(&eel));
+
+    let danger_only = BestiaryFilter {
+        danger: Some(DangerLevel::Low),
+        ..BestiaryFilter::default()
+    };
+    assert!(danger_only.matches(&eel) && !danger_only.matches(&bog));
+
+    send(&mut state, [Message::OpenCodex(Codex::Creature, second.clone())]);
+    assert!(!state.bestiary_filters.get(&second).is_some_and(BestiaryFilter::is_active));
+
+    send(&mut state, [Message::OpenCodex(Codex::Creature, first.clone()), Message::BestiaryClearFilters]);
+    let filter = &state.bestiary_filters[&first];
+    assert!(!filter.is_active());
+    assert_eq!(filter.sort, BestiarySort::Danger);
+}
+
+#[test]
+fn template_fields_are_validated_on_creatures_and_keep_their_values_when_renamed() {
+    let mut state = state();
+    let universe_id = create_universe(&mut state, "Templated");
+    send(
+        &mut state,
+        [
+            Message::OpenCodex(Codex::Creature, universe_id.clone()),
+            Message::TemplateEditorOpen(universe_id.clone()),
+            Message::TemplateFieldAdd,
+            Message::TemplateFieldKindChanged(0, FieldKind::Enum),
+            Message::TemplateEditorSave,
+        ],
+    );
+    // A choice field without a label or options can't be saved.
+    assert!(state.template_editor.is_some());
+
+    send(
+        &mut state,
+        [
+            Message::TemplateFieldLabelChanged(0, "Diet".to_string()),
+            Message::TemplateFieldOptionsChanged(0, "Meat, Plants".to_string()),
+            Message::TemplateFieldAdd,
+            Message::TemplateFieldLabelChanged(1, "Wingspan".to_string()),
+            Message::TemplateFieldKindChanged(1, FieldKind::Number),
+            Message::TemplateFieldMove { index: 1, up: true },
+            Message::TemplateEditorSave,
+        ],
+    );
+    assert!(state.template_editor.is_none());
+    let fields = state.project.creature_fields(&universe_id).to_vec();
+    let labels: Vec<&str> = fields.iter().map(|f| f.label.as_str()).collect();
+    assert_eq!(labels, ["Wingspan", "Diet"]);
+    let (wingspan, diet) = (fields[0].id.clone(), fields[1].id.clone());
+
+    send(
+        &mut state,
+        [
+            Message::EditorOpenCreate(Codex::Creature, universe_id.clone()),
+            Message::EditorLineChanged(Codex::Creature, Field::Name, "Gull".to_string()),
+            Message::CreatureEditorFieldChanged(wingspan.clone(), "wide".to_string()),
+            Message::CreatureEditorFieldChanged(diet.clone(), "Fish".to_string()),
+            Message::EditorSave(Codex::Creature),
+        ],
+    );
+    assert!(state.creature_editor.is_some(), "a bad number or choice blocks saving");
+
+    send(
+        &mut state,
+        [
+            Message::CreatureEditorFieldChanged(wingspan.clone(), "1.5".to_string()),
+            Message::CreatureEditorFieldChanged(diet.clone(), "Meat".to_string()),
+            Message::EditorSave(Codex::Creature),
+            Message::TemplateEditorOpen(universe_id.clone()),
+            Message::TemplateFieldLabelChanged(0, "Span".to_string()),
+            Message::TemplateEditorSave,
+        ],
+    );
+
+    let gull = state.project.creatures.iter().find(|c| c.name == "Gull").expect("creature was saved");
+    assert_eq!(gull.fields.get(&wingspan), Some(&FieldValue::Number(1.5)));
+    assert_eq!(gull.fields.get(&diet), Some(&FieldValue::Text("Meat".to_string())));
+    assert_eq!(state.project.creature_fields(&universe_id)[0].id, wingspan);
+}
+
+#[test]
+fn places_added_inside_another_nest_and_move_up_when_it_goes() {
+    let mut state = state();
+    let universe_id = create_universe(&mut state, "Nested");
+    let add = |state: &mut AppState, name: &str, first: Message| {
+        send(
+            state,
+            [
+                first,
+                Message::EditorLineChanged(Codex::Location, Field::Name, name.to_string()),
+                Message::EditorSave(Codex::Location),
+            ],
+        );
+        state.project.locations.iter().find(|l| l.name == name).expect("location was saved").id.clone()
+    };
+
+    send(&mut state, [Message::OpenCodex(Codex::Location, universe_id.clone())]);
+    let vale = add(&mut state, "Ashen Vale", Message::EditorOpenCreate(Codex::Location, universe_id));
+    let hold = add(&mut state, "Brumehold", Message::LocationEditorAddInside(vale.clone()));
+    assert_eq!(state.project.location_label(&hold), "Ashen Vale › Brumehold");
+
+    send(&mut state, [Message::DeleteEntry(Codex::Location, vale), Message::DeleteEntryConfirm]);
+    assert_eq!(state.project.location(&hold).and_then(|l| l.parent_id.clone()), None);
+}
+
+#[test]
+fn characters_join_factions_once_and_need_a_kind_for_each_relationship() {
+    let mut state = state();
+    let universe_id = create_universe(&mut state, "Courts");
+    let save = |state: &mut AppState, codex: Codex, fields: &[(Field, &str)]| {
+        send(
+            state,
+            [Message::OpenCodex(codex, universe_id.clone()), Message::EditorOpenCreate(codex, universe_id.clone())],
+        );
+        for (field, value) in fields {
+            send(state, [Message::EditorLineChanged(codex, *field, value.to_string())]);
+        }
+        send(state, [Message::EditorSave(codex)]);
+    };
+
+    save(&mut state, Codex::Faction, &[(Field::Name, "Order"), (Field::Ranks, "Grandmaster, Knight")]);
+    save(&mut state, Codex::Character, &[(Field::Name, "Aldric")]);
+    let order = state.project.factions.last().unwrap().clone();
+    let aldric = state.project.characters.last().unwrap().id.clone();
+    assert_eq!(order.ranks, ["Grandmaster", "Knight"]);
+
+    send(
+        &mut state,
+        [
+            Message::EditorOpenCreate(Codex::Character, universe_id.clone()),
+            Message::EditorLineChanged(Codex::Character, Field::Name, "Brenna".to_string()),
+            Message::CharacterEditorAddMembership(order.id.clone()),
+            Message::CharacterEditorAddMembership(order.id.clone()),
+            Message::CharacterEditorRankChanged(0, "Knight".to_string()),
+            Message::CharacterEditorAddRelationship(aldric.clone()),
+            Message::EditorSave(Codex::Character),
+        ],
+    );
+    assert!(state.character_editor.is_some(), "a relationship without a kind blocks saving");
+
+    send(
+        &mut state,
+        [
+            Message::CharacterEditorRelationshipKindChanged(0, "Squire of".to_string()),
+            Message::EditorSave(Codex::Character),
+        ],
+    );
+    assert!(state.character_editor.is_none());
+
+    let members: Vec<(String, String)> =
+        state.project.faction_members(&order.id).map(|(c, m)| (c.name.clone(), m.rank.clone())).collect();
+    assert_eq!(members, [("Brenna".to_string(), "Knight".to_string())]);
+    let towards: Vec<&str> = state.project.relationships_towards(&aldric).map(|(c, _)| c.name.as_str()).collect();
+    assert_eq!(towards, ["Brenna"]);
+}
+
+#[test]
+fn new_terms_start_in_the_browsed_category_and_link_other_terms_once() {
+    let mut state = state();
+    let universe_id = create_universe(&mut state, "Runes");
+    let add = |state: &mut AppState, name: &str, see_also: &[String]| {
+        send(
+            state,
+            [
+                Message::EditorOpenCreate(Codex::Term, universe_id.clone()),
+                Message::EditorLineChanged(Codex::Term, Field::Name, name.to_string()),
+            ],
+        );
+        send(state, see_also.iter().map(|id| Message::TermEditorAddSeeAlso(id.clone())));
+        send(state, [Message::EditorSave(Codex::Term)]);
+        state.project.terms.iter().find(|t| t.name == name).expect("term was saved").clone()
+    };
+
+    send(
+        &mut state,
+        [
+            Message::OpenCodex(Codex::Term, universe_id.clone()),
+            Message::RunomiconCategoryChanged(Some("Spell".to_string())),
+        ],
+    );
+    let ember = add(&mut state, "Ember", &[]);
+    assert_eq!(ember.category, "Spell");
+
+    let flare = add(&mut state, "Flare", &[ember.id.clone(), ember.id.clone()]);
+    assert_eq!(flare.see_also, [ember.id.as_str()]);
+
+    // A term can't list itself.
+    send(
+        &mut state,
+        [
+            Message::EditorOpenEdit(Codex::Term, flare.id.clone()),
+            Message::TermEditorAddSeeAlso(flare.id.clone()),
+        ],
+    );
+    assert_eq!(state.term_editor.as_ref().unwrap().see_also, [ember.id.as_str()]);
+
+    // Deleting a linked term drops it from the open editor too.
+    send(&mut state, [Message::DeleteEntry(Codex::Term, ember.id.clone()), Message::DeleteEntryConfirm]);
+    assert!(state.term_editor.as_ref().unwrap().see_also.is_empty());
+}
+
+#[test]
+fn artifacts_keep_trimmed_properties_and_lose_a_deleted_holder() {
+    let mut state = state();
+    let universe_id = create_universe(&mut state, "Relics");
+    send(
+        &mut state,
+        [
+            Message::OpenCodex(Codex::Creature, universe_id.clone()),
+            Message::EditorOpenCreate(Codex::Creature, universe_id.clone()),
+            Message::EditorLineChanged(Codex::Creature, Field::Name, "Magpie".to_string()),
+            Message::EditorSave(Codex::Creature),
+        ],
+    );
+    let magpie = state.project.creatures.last().unwrap().id.clone();
+    let holder = Holder::Creature(magpie.clone());
+
+    send(
+        &mut state,
+        [
+            Message::OpenCodex(Codex::Artifact, universe_id.clone()),
+            Message::EditorOpenCreate(Codex::Artifact, universe_id.clone()),
+            Message::EditorLineChanged(Codex::Artifact, Field::Name, "Glass Key".to_string()),
+            Message::ArtifactEditorRarityChanged(Rarity::Legendary),
+            Message::ArtifactEditorHolderChanged(Some(holder.clone())),
+            Message::ArtifactEditorAddProperty,
+            Message::ArtifactEditorPropertyNameChanged(0, " Opens ".to_string()),
+            Message::ArtifactEditorPropertyValueChanged(0, " any door once ".to_string()),
+            Message::EditorSave(Codex::Artifact),
+        ],
+    );
+    let key = state.project.artifacts.last().expect("artifact was saved").clone();
+    assert_eq!((key.rarity, key.holder.as_ref()), (Rarity::Legendary, Some(&holder)));
+    assert_eq!((key.properties[0].name.as_str(), key.properties[0].value.as_str()), ("Opens", "any door once"));
+
+    send(
+        &mut state,
+        [
+            Message::EditorOpenEdit(Codex::Artifact, key.id.clone()),
+            Message::DeleteEntry(Codex::Creature, magpie),
+            Message::DeleteEntryConfirm,
+        ],
+    );
+    assert_eq!(state.project.artifact(&key.id).unwrap().holder, None);
+    assert_eq!(state.artifact_editor.as_ref().unwrap().holder, None);
+}
+
+#[test]
+fn manuscript_titles_apply_once_typing_is_done_and_no_op_edits_leave_activity_alone() {
+    let mut state = state();
+    state.new_manuscript_title = "Saga".to_string();
+    send(&mut state, [Message::CreateManuscript, Message::ForgeAddChapter]);
+    let manuscript_id = state.project.manuscripts.last().unwrap().id.clone();
+    let chapter_id = state.project.manuscript(&manuscript_id).unwrap().chapters[0].id.clone();
+    let chapter_title = |state: &AppState| state.project.manuscript(&manuscript_id).unwrap().chapters[0].title.clone();
+
+    send(&mut state, [Message::ForgeChapterTitleChanged(chapter_id.clone(), "Prologue".to_string())]);
+    assert_eq!(chapter_title(&state), "Chapter 1");
+
+    send(&mut state, [Message::TitleSubmit]);
+    assert_eq!(chapter_title(&state), "Prologue");
+
+    // Blank titles are dropped once the user moves on.
+    send(
+        &mut state,
+        [
+            Message::ForgeChapterTitleChanged(chapter_id.clone(), " ".to_string()),
+            Message::ForgeMoveChapter { chapter_id, up: true },
+        ],
+    );
+    assert_eq!(chapter_title(&state), "Prologue");
+
+    // Moving the only chapter up changes nothing, so the board stays the latest activity.
+    state.new_board_name = "Plot".to_string();
+    send(&mut state, [Message::CreateBoard, Message::OpenManuscript(manuscript_id.clone())]);
+    let latest = |state: &AppState| state.project.activity.recent().next().map(|a| a.target.clone());
+    let board = latest(&state);
+    assert!(matches!(board, Some(ActivityTarget::Board(_))));
+
+    let chapter_id = state.project.manuscript(&manuscript_id).unwrap().chapters[0].id.clone();
+    send(&mut state, [Message::ForgeMoveChapter { chapter_id, up: true }]);
+    assert_eq!(latest(&state), board);
+}
+
+#[test]
+fn board_titles_apply_once_typing_is_done_and_no_op_moves_leave_activity_alone() {
+    let mut state = state();
+    state.new_board_name = "Plot".to_string();
+    send(&mut state, [Message::CreateBoard]);
+    let board_id = state.project.boards.last().unwrap().id.clone();
+    let column_id = state.project.board(&board_id).unwrap().columns[0].id.clone();
+    let board = |state: &AppState| state.project.board(&board_id).unwrap().clone();
+
+    send(
+        &mut state,
+        [
+            Message::BoardNameChanged("Plot threads".to_string()),
+            Message::BoardColumnTitleChanged(column_id.clone(), "Backlog".to_string()),
+        ],
+    );
+    // The name was applied once typing moved to the column; the column title is still a draft.
+    assert_eq!(board(&state).name, "Plot threads");
+    assert_eq!(board(&state).columns[0].title, "To do");
+
+    send(&mut state, [Message::TitleSubmit]);
+    assert_eq!(board(&state).columns[0].title, "Backlog");
+
+    // Moving the first column left changes nothing, so the manuscript stays the latest activity.
+    state.new_manuscript_title = "Saga".to_string();
+    send(&mut state, [Message::CreateManuscript, Message::OpenBoard(board_id.clone())]);
+    let latest = |state: &AppState| state.project.activity.recent().next().map(|a| a.target.clone());
+    let manuscript = latest(&state);
+    assert!(matches!(manuscript, Some(ActivityTarget::Manuscript(_))));
+
+    send(&mut state, [Message::BoardMoveColumn { column_id, left: true }]);
+    assert_eq!(latest(&state), manuscript);
+}
+
+#[test]
+fn switching_workspaces_leaves_pages_of_the_previous_one() {
+    let mut state = state();
+    let home = state.project.active_workspace.clone();
+    state.new_workspace_name = "Second".to_string();
+    send(&mut state, [Message::CreateWorkspace]);
+    let second = state.project.active_workspace.clone();
+    assert_ne!(second, home);
+
+    let universe_id = create_universe(&mut state, "Far");
+    assert_eq!(state.project.universe(&universe_id).unwrap().workspace_id, second);
+    assert_eq!(state.project.workspace_universes().count(), 1);
+
+    send(
+        &mut state,
+        [
+            Message::OpenUniverse(universe_id.clone()),
+            Message::UniverseEditorOpen(universe_id),
+            Message::SwitchWorkspace(home.clone()),
+        ],
+    );
+    assert_eq!(state.project.active_workspace, home);
+    assert!(matches!(state.route, Route::UniverseList));
+    assert!(state.universe_editor.is_none());
+
+    // Unknown ids are ignored.
+    send(&mut state, [Message::SwitchWorkspace("nowhere".to_string())]);
+    assert_eq!(state.project.active_workspace, home);
+}
+
+#[test]
+fn workspace_renames_apply_only_valid_names() {
+    let mut state = state();
+    let id = state.project.active_workspace.clone();
+    let name = |state: &AppState| state.project.workspace(&id).unwrap().name.clone();
+    let original = name(&state);
+
+    send(&mut state, [Message::WorkspaceNameChanged(id.clone(), " ".to_string()), Message::TitleSubmit]);
+    assert_eq!(name(&state), original);
+
+    send(
+        &mut state,
+        [
+            Message::WorkspaceNameChanged(id.clone(), "Studio".to_string()),
+            Message::Navigate(Route::Overview),
+        ],
+    );
+    assert_eq!(name(&state), "Studio");
+}